@@ -0,0 +1,268 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Extract the Gardiner sign inventory from the JSesh font catalog.
+//!
+//! The input is the `pdftotext -layout` rendering of the catalog PDF:
+//! a "<X> family" line, the family's descriptive name on the next
+//! non-blank line, then one "<n> <code>" line per sign.
+//!
+//! ```no_run
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use gardiner_signlist_extractor::parse_jsesh_text;
+//!
+//! let file = File::open("generated/jsesh.txt").unwrap();
+//! for sign in parse_jsesh_text(BufReader::new(file)) {
+//!     let sign = sign.unwrap();
+//!     println!("{} {}", sign.family, sign.jsesh_code);
+//! }
+//! ```
+
+use std::fmt;
+use std::io::{self, BufRead, Lines};
+
+use serde::{Deserialize, Serialize};
+
+use signlist_core::gardiner::canonical_code;
+use signlist_core::importer::{ImportResult, SignListImporter, SignRecord};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JseshSign {
+    pub family: String,
+    pub family_name: String,
+    pub jsesh_code: String,
+}
+
+/// Everything that can go wrong while reading the catalog text.
+/// Line numbers are 1-based.
+#[derive(Debug)]
+pub enum ParseError {
+    Io { line: usize, source: io::Error },
+    /// A family header was followed by another header, or by the end of
+    /// the text, instead of a name; `line` is the unnamed header's. Not
+    /// fatal: the iterator carries on with the next family, and the
+    /// unnamed one has no signs to lose.
+    MissingFamilyName { line: usize, family: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io { line, source } => {
+                write!(f, "line {}: read failed: {}", line, source)
+            }
+            ParseError::MissingFamilyName { line, family } => {
+                write!(f, "line {}: family {} has no descriptive name", line, family)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Io { source, .. } => Some(source),
+            ParseError::MissingFamilyName { .. } => None,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/// Stream sign records out of the JSesh catalog text.
+pub fn parse_jsesh_text<R: BufRead>(reader: R) -> impl Iterator<Item = Result<JseshSign>> {
+    JseshSigns {
+        lines: reader.lines(),
+        line_no: 0,
+        current_family: None,
+        family_line: 0,
+        current_family_name: None,
+        awaiting_family_name: false,
+    }
+}
+
+struct JseshSigns<R> {
+    lines: Lines<R>,
+    line_no: usize,
+    current_family: Option<String>,
+    /// Line of the current family's header.
+    family_line: usize,
+    current_family_name: Option<String>,
+    awaiting_family_name: bool,
+}
+
+impl<R: BufRead> Iterator for JseshSigns<R> {
+    type Item = Result<JseshSign>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(line) = self.lines.next() else {
+                return self.unnamed_family().map(Err);
+            };
+            self.line_no += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(source) => {
+                    return Some(Err(ParseError::Io { line: self.line_no, source }));
+                }
+            };
+
+            let line = line.trim();
+            if line.is_empty() {
+                continue;
+            }
+
+            if let Some(fam) = line.strip_suffix(" family") {
+                let unnamed = self.unnamed_family();
+                self.current_family = Some(fam.to_string());
+                self.family_line = self.line_no;
+                self.current_family_name = None;
+                self.awaiting_family_name = true;
+
+                if let Some(e) = unnamed {
+                    return Some(Err(e));
+                }
+                continue;
+            }
+
+            if self.awaiting_family_name {
+                self.current_family_name = Some(line.to_string());
+                self.awaiting_family_name = false;
+                continue;
+            }
+
+            if let Some((_, code)) = line.split_once(' ')
+                && let (Some(f), Some(name)) = (&self.current_family, &self.current_family_name)
+                && code
+                    .chars()
+                    .next()
+                    .map(|c| c.is_alphanumeric())
+                    .unwrap_or(false)
+            {
+                return Some(Ok(JseshSign {
+                    family: f.clone(),
+                    family_name: name.clone(),
+                    jsesh_code: code.to_string(),
+                }));
+            }
+        }
+    }
+}
+
+impl<R> JseshSigns<R> {
+    /// The current family, if its header never got a name; reported once.
+    fn unnamed_family(&mut self) -> Option<ParseError> {
+        if !self.awaiting_family_name {
+            return None;
+        }
+        self.awaiting_family_name = false;
+        Some(ParseError::MissingFamilyName {
+            line: self.family_line,
+            family: self.current_family.clone()?,
+        })
+    }
+}
+
+/// The extractor's own output (`jsesh_inventory.json`) as a
+/// [`SignListImporter`]. A sign is described by its family's name.
+pub struct JseshImporter;
+
+impl SignListImporter for JseshImporter {
+    fn list_id(&self) -> &str {
+        "jsesh"
+    }
+
+    fn import(&self, reader: &mut dyn BufRead) -> ImportResult<Vec<SignRecord>> {
+        let signs: Vec<JseshSign> = serde_json::from_reader(reader)?;
+        Ok(signs
+            .into_iter()
+            .map(|sign| SignRecord {
+                list: self.list_id().to_string(),
+                gardiner: canonical_code(&sign.jsesh_code).into_iter().collect(),
+                code: sign.jsesh_code,
+                description: sign.family_name,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(family: &str, family_name: &str, jsesh_code: &str) -> JseshSign {
+        JseshSign {
+            family: family.to_string(),
+            family_name: family_name.to_string(),
+            jsesh_code: jsesh_code.to_string(),
+        }
+    }
+
+    #[test]
+    fn signs_take_their_family_and_its_name() {
+        let text = "\
+JSesh sign catalog
+1 X1
+
+A family
+Man and his occupations
+1 A1
+2 A2
+
+    B family
+    Woman and her occupations
+1 B1
+2 -
+";
+        let signs: Vec<JseshSign> = parse_jsesh_text(text.as_bytes())
+            .collect::<Result<_>>()
+            .unwrap();
+        assert_eq!(
+            signs,
+            [
+                sign("A", "Man and his occupations", "A1"),
+                sign("A", "Man and his occupations", "A2"),
+                sign("B", "Woman and her occupations", "B1"),
+            ]
+        );
+    }
+
+    #[test]
+    fn unnamed_families_are_reported_and_skipped() {
+        let text = "\
+A family
+Man and his occupations
+1 A1
+B family
+
+C family
+Anthropomorphic deities
+1 C1
+D family
+";
+        let results: Vec<Result<JseshSign>> = parse_jsesh_text(text.as_bytes()).collect();
+        assert_eq!(results.len(), 4);
+        assert_eq!(
+            results[0].as_ref().unwrap(),
+            &sign("A", "Man and his occupations", "A1")
+        );
+        assert!(matches!(
+            &results[1],
+            Err(ParseError::MissingFamilyName { line: 4, family }) if family == "B"
+        ));
+        assert_eq!(
+            results[2].as_ref().unwrap(),
+            &sign("C", "Anthropomorphic deities", "C1")
+        );
+        assert!(matches!(
+            &results[3],
+            Err(ParseError::MissingFamilyName { line: 9, family }) if family == "D"
+        ));
+        assert_eq!(
+            results[1].as_ref().unwrap_err().to_string(),
+            "line 4: family B has no descriptive name"
+        );
+    }
+}
@@ -1,23 +1,18 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
 
-use serde::Serialize;
-
 // Pull in shared infrastructure
 use signlist_core::VERBOSE;
 use signlist_core::verbose;
 use signlist_core::stderr_is_tty;
 use signlist_core::fail;
+use signlist_core::warn;
 
-#[derive(Serialize)]
-struct JseshSign {
-    family: String,
-    family_name: String,
-    jsesh_code: String,
-}
+use gardiner_signlist_extractor::{parse_jsesh_text, ParseError};
 
 fn main() {
-    use std::fs::read_to_string;
+    use std::fs::File;
+    use std::io::BufReader;
     use std::sync::atomic::Ordering;
 
     let mut args = std::env::args().skip(1);
@@ -72,8 +67,8 @@ fn main() {
 
     verbose!("reading input file: {}", input_path);
 
-    let text = match read_to_string(&input_path) {
-        Ok(s) => s,
+    let file = match File::open(&input_path) {
+        Ok(f) => f,
         Err(e) => {
             use std::io::ErrorKind::*;
             match e.kind() {
@@ -85,61 +80,20 @@ fn main() {
     };
 
     let mut results = Vec::new();
-    let mut current_family: Option<String> = None;
-    let mut current_family_name: Option<String> = None;
-    let mut awaiting_family_name = false;
-
-    for (line_no, line) in text.lines().enumerate() {
-        let line = line.trim();
-        if line.is_empty() {
-            continue;
-        }
 
-        if let Some(fam) = line.strip_suffix(" family") {
-            verbose!(
-                "line {}: \u{203B} Found family {}",
-                line_no + 1,
-                fam
-            );
-            current_family = Some(fam.to_string());
-            current_family_name = None;
-            awaiting_family_name = true;
-            continue;
-        }
+    for sign in parse_jsesh_text(BufReader::new(file)) {
+        let sign = match sign {
+            Ok(s) => s,
+            Err(e @ ParseError::MissingFamilyName { .. }) => {
+                warn!("{}: {}; skipping it", input_path, e);
+                continue;
+            }
+            Err(e) => fail!(1, "{}: {}", input_path, e),
+        };
 
-        if awaiting_family_name {
-            verbose!(
-                "line {}:   family name = {}",
-                line_no + 1,
-                line
-            );
-            current_family_name = Some(line.to_string());
-            awaiting_family_name = false;
-            continue;
-        }
+        verbose!("  sign {} (family {})", sign.jsesh_code, sign.family);
 
-        if let Some((_, code)) = line.split_once(' ') {
-            if let (Some(f), Some(name)) = (&current_family, &current_family_name) {
-                if code
-                    .chars()
-                    .next()
-                    .map(|c| c.is_alphanumeric())
-                    .unwrap_or(false)
-                {
-                    verbose!(
-                        "line {}:   sign {} (family {})",
-                        line_no + 1,
-                        code,
-                        f
-                    );
-                    results.push(JseshSign {
-                        family: f.clone(),
-                        family_name: name.clone(),
-                        jsesh_code: code.to_string(),
-                    });
-                }
-            }
-        }
+        results.push(sign);
     }
 
     verbose!(" ");
@@ -0,0 +1,122 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Helpers for reasoning about Gardiner-style sign codes.
+//!
+//! Both JSesh ("A1", "Aa27", "US1A6BEXTU") and Unicode ("A001", "NL005A")
+//! spell a sign as a family prefix followed by a number and optional
+//! variant letters. These helpers pull the family back out of that shape.
+
+/// Codepoints of the Egyptian Hieroglyph Format Controls block.
+///
+/// Joiners, insertion and enclosure controls are not signs and have no
+/// Gardiner code, so joins and lints leave them out.
+pub fn is_format_control_codepoint(cp: u32) -> bool {
+    (0x13430..=0x1345F).contains(&cp)
+}
+
+/// Family prefix of a JSesh or Unicode sign code.
+///
+/// JSesh's Unicode-derived codes carry a `US<digits>` marker in front of
+/// the Gardiner part ("US1A6BEXTU" is family "A"), so that is skipped.
+/// Returns `None` when the code does not start with a family.
+pub fn family_of_code(code: &str) -> Option<&str> {
+    let code = strip_us_marker(code);
+    let end = code
+        .find(|c: char| !c.is_ascii_alphabetic())
+        .unwrap_or(code.len());
+
+    if end == 0 || end == code.len() {
+        return None;
+    }
+
+    Some(&code[..end])
+}
+
+/// Family named by a NamesList subheader.
+///
+/// Subheaders look like "A. Man and his occupations" or, in Extended-A,
+/// "A01. Man seated or kneeling empty handed". Subheaders that are not
+/// about a Gardiner family ("Joiners", "Addition to group V") give `None`.
+pub fn family_of_subheader(subheader: &str) -> Option<&str> {
+    let head = subheader.split_whitespace().next()?;
+    let head = head.strip_suffix('.')?;
+    let family = head.trim_end_matches(|c: char| c.is_ascii_digit());
+
+    let valid = !family.is_empty()
+        && family.len() <= 2
+        && family.chars().all(|c| c.is_ascii_alphabetic())
+        && family.starts_with(|c: char| c.is_ascii_uppercase());
+
+    valid.then_some(family)
+}
+
+/// Canonical join key for a sign code.
+///
+/// Unicode pads the number ("A001", "NL005A") and writes "AA" for the
+/// Aa family; JSesh does neither ("A1", "NL5A", "Aa1"). The key drops the
+/// padding and uppercases letters so both spell "A1", "NL5A", "AA1".
+/// Returns `None` for names that are not family + number + variant.
+pub fn canonical_code(code: &str) -> Option<String> {
+    let code = strip_us_marker(code);
+    let family = family_of_code(code)?;
+    let rest = &code[family.len()..];
+
+    let digits_end = rest
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(rest.len());
+    let (digits, variant) = rest.split_at(digits_end);
+
+    if digits.is_empty() || !variant.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return None;
+    }
+
+    let number: u32 = digits.parse().ok()?;
+
+    Some(format!(
+        "{}{}{}",
+        family.to_ascii_uppercase(),
+        number,
+        variant.to_ascii_uppercase()
+    ))
+}
+
+/// Compare two family labels the way the sources spell them.
+///
+/// Unicode Extended-A writes "AA" where Gardiner and JSesh write "Aa".
+pub fn same_family(a: &str, b: &str) -> bool {
+    a.eq_ignore_ascii_case(b)
+}
+
+fn strip_us_marker(code: &str) -> &str {
+    if let Some(rest) = code.strip_prefix("US") {
+        let digits = rest.len() - rest.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+        if digits > 0 {
+            return &rest[digits..];
+        }
+    }
+    code
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_code_joins_unicode_and_jsesh_spellings() {
+        assert_eq!(canonical_code("A001").as_deref(), Some("A1"));
+        assert_eq!(canonical_code("A1").as_deref(), Some("A1"));
+        assert_eq!(canonical_code("AA001").as_deref(), Some("AA1"));
+        assert_eq!(canonical_code("Aa1").as_deref(), Some("AA1"));
+        assert_eq!(canonical_code("NL005A").as_deref(), Some("NL5A"));
+        assert_eq!(canonical_code("NL5a").as_deref(), Some("NL5A"));
+        assert_eq!(canonical_code("US1A6BEXTU").as_deref(), Some("A6BEXTU"));
+    }
+
+    #[test]
+    fn canonical_code_rejects_names_that_are_not_codes() {
+        for name in ["", "A", "001", "HIEROGLYPH-13460", "JOINER", "A1-2", "A1 B"] {
+            assert_eq!(canonical_code(name), None, "{name:?}");
+        }
+    }
+}
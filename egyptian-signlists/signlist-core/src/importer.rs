@@ -0,0 +1,113 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! The common shape every sign list is imported into.
+//!
+//! Each numbering (Unicode, JSesh, a hieratic palaeography, a team CSV)
+//! lives in its own extractor crate that implements [`SignListImporter`]
+//! and writes its records as a JSON array of [`SignRecord`]. The
+//! correlator reads any number of those files and joins them through
+//! Gardiner codes into a many-to-many [`ConcordanceRow`] table.
+
+use std::collections::BTreeSet;
+use std::io::BufRead;
+
+use serde::{Deserialize, Serialize};
+
+/// Importers keep their own typed errors; callers only need to report them.
+pub type ImportResult<T> = Result<T, Box<dyn std::error::Error + Send + Sync>>;
+
+/// One sign as a particular list numbers it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SignRecord {
+    /// Identifier of the list the sign comes from ("jsesh", "moller").
+    pub list: String,
+    /// The sign's number in that list, spelled as the list spells it.
+    pub code: String,
+    /// Canonical Gardiner codes the sign corresponds to (see
+    /// [`crate::gardiner::canonical_code`]). Empty when unknown, several
+    /// when one sign covers a group of Gardiner signs.
+    pub gardiner: Vec<String>,
+    #[serde(default)]
+    pub description: String,
+}
+
+pub trait SignListImporter {
+    /// Stable identifier written into every record's `list` field.
+    fn list_id(&self) -> &str;
+
+    /// Read a whole source into records.
+    fn import(&self, reader: &mut dyn BufRead) -> ImportResult<Vec<SignRecord>>;
+}
+
+/// One link between a Gardiner code and a sign in some list.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize, Deserialize)]
+pub struct ConcordanceRow {
+    pub gardiner_code: String,
+    pub list: String,
+    pub code: String,
+}
+
+/// Flatten records into a sorted, de-duplicated concordance.
+///
+/// A sign mapped to several Gardiner codes gets a row for each, and a
+/// Gardiner code several signs map to gets a row per sign, so the table
+/// is many-to-many. Signs with no Gardiner code do not appear.
+pub fn concordance<'a>(records: impl IntoIterator<Item = &'a SignRecord>) -> Vec<ConcordanceRow> {
+    let rows: BTreeSet<ConcordanceRow> = records
+        .into_iter()
+        .flat_map(|r| {
+            r.gardiner.iter().map(|g| ConcordanceRow {
+                gardiner_code: g.clone(),
+                list: r.list.clone(),
+                code: r.code.clone(),
+            })
+        })
+        .collect();
+
+    rows.into_iter().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(list: &str, code: &str, gardiner: &[&str]) -> SignRecord {
+        SignRecord {
+            list: list.to_string(),
+            code: code.to_string(),
+            gardiner: gardiner.iter().map(|g| g.to_string()).collect(),
+            description: String::new(),
+        }
+    }
+
+    fn row(gardiner_code: &str, list: &str, code: &str) -> ConcordanceRow {
+        ConcordanceRow {
+            gardiner_code: gardiner_code.to_string(),
+            list: list.to_string(),
+            code: code.to_string(),
+        }
+    }
+
+    #[test]
+    fn concordance_is_many_to_many() {
+        let records = [
+            record("moller", "HG A1a", &["A2", "A1"]),
+            record("jsesh", "A1", &["A1"]),
+            record("unicode", "U+13000", &["A1"]),
+            record("moller", "Möller 99", &[]),
+            // The same link twice yields one row.
+            record("jsesh", "A1", &["A1"]),
+        ];
+
+        assert_eq!(
+            concordance(&records),
+            vec![
+                row("A1", "jsesh", "A1"),
+                row("A1", "moller", "HG A1a"),
+                row("A1", "unicode", "U+13000"),
+                row("A2", "moller", "HG A1a"),
+            ]
+        );
+    }
+}
@@ -1,6 +1,11 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
 
+pub mod gardiner;
+pub mod importer;
+pub mod names_list;
+pub mod ucd;
+
 use std::io::IsTerminal;
 use std::sync::OnceLock;
 use std::sync::atomic::AtomicBool;
@@ -51,6 +56,24 @@ macro_rules! error {
     };
 }
 
+#[macro_export]
+#[allow(unused)]
+macro_rules! warn {
+    ($fmt:expr $(, $arg:tt)*) => {
+        if stderr_is_tty() {
+            eprintln!(
+                concat!("\x1b[33m[WARN]\x1b[0m ", $fmt)
+                $(, $arg)*
+            );
+         } else {
+            eprintln!(
+                concat!("[WARN] ", $fmt)
+                $(, $arg)*
+            );
+         }
+    };
+}
+
 #[macro_export]
 #[allow(unused)]
 macro_rules! fail {
@@ -0,0 +1,686 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Parser for Unicode's NamesList.txt.
+//!
+//! The file is a human-oriented listing, but its line syntax is regular:
+//!
+//! ```text
+//! @@<tab>13000<tab>Egyptian Hieroglyphs<tab>1342F      block header
+//! @<tab><tab>A. Man and his occupations                subheader
+//! @+<tab><tab>Annotations use a colon ...              note
+//! 13000<tab>EGYPTIAN HIEROGLYPH A001                   entry
+//! <tab>= alias                                         alias
+//! <tab>% FORMAL ALIAS                                  formal alias
+//! <tab>* classifier human being                        comment
+//! <tab>x (name - 13001)  or  <tab>x 13001              cross-reference
+//! <tab>: 0041 0300                                     canonical decomposition
+//! <tab># <noBreak> 0020                                compatibility mapping
+//! <tab>~ 13012 FE03 rotated approximately 30 degrees   variation sequence
+//! ```
+//!
+//! Notes attach to whatever precedes them: an entry, a subheader or a block.
+//! Nothing here is specific to one script; [`Filter`] narrows the stream to
+//! the blocks or name prefixes a caller cares about.
+
+use std::fmt;
+use std::io::{self, BufRead, Lines};
+
+use serde::{Deserialize, Serialize};
+
+/* ============================================================
+ * Data model
+ * ============================================================
+ */
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Block {
+    pub first: u32,
+    pub last: u32,
+    pub name: String,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Subheader {
+    pub text: String,
+    pub notes: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CrossRef {
+    pub codepoint: u32,
+    /// Lowercased character name, when the reference spells it out.
+    pub name: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompatibilityMapping {
+    /// Formatting tag such as `noBreak` or `super`, without brackets.
+    pub tag: Option<String>,
+    pub codepoints: Vec<u32>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VariationSequence {
+    pub base: u32,
+    pub selector: u32,
+    pub description: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub codepoint: u32,
+    /// Character name; `<control>`, `<reserved>` and friends kept verbatim.
+    pub name: String,
+    pub block: String,
+    pub subheader: String,
+    pub notes: Vec<String>,
+    pub aliases: Vec<String>,
+    pub formal_aliases: Vec<String>,
+    pub comments: Vec<String>,
+    pub cross_refs: Vec<CrossRef>,
+    pub canonical_decomposition: Vec<u32>,
+    pub compatibility_mapping: Option<CompatibilityMapping>,
+    pub variation_sequences: Vec<VariationSequence>,
+}
+
+/// One structural element of the file, yielded in file order.
+///
+/// Entries make up nearly the whole stream, so boxing them to shrink the
+/// rare block and subheader variants would only add allocations.
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Item {
+    Block(Block),
+    Subheader(Subheader),
+    Entry(Entry),
+}
+
+/* ============================================================
+ * Errors
+ * ============================================================
+ */
+
+/// Everything that can go wrong while reading NamesList.txt.
+/// Line numbers are 1-based.
+#[derive(Debug)]
+pub enum ParseError {
+    Io { line: usize, source: io::Error },
+    InvalidCodepoint { line: usize, hex: String },
+    Malformed { line: usize, text: String },
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseError::Io { line, source } => {
+                write!(f, "line {}: read failed: {}", line, source)
+            }
+            ParseError::InvalidCodepoint { line, hex } => {
+                write!(f, "line {}: invalid hex codepoint: {}", line, hex)
+            }
+            ParseError::Malformed { line, text } => {
+                write!(f, "line {}: malformed line: {:?}", line, text)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            ParseError::Io { source, .. } => Some(source),
+            _ => None,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, ParseError>;
+
+/* ============================================================
+ * Filter
+ * ============================================================
+ */
+
+/// Which entries a caller wants. Empty lists match everything.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    /// Block names, compared case-insensitively ("Runic", "Ogham").
+    pub blocks: Vec<String>,
+    /// Character-name prefixes ("EGYPTIAN HIEROGLYPH", "CUNEIFORM SIGN").
+    pub name_prefixes: Vec<String>,
+}
+
+impl Filter {
+    pub fn matches(&self, entry: &Entry) -> bool {
+        let block_ok = self.blocks.is_empty()
+            || self.blocks.iter().any(|b| b.eq_ignore_ascii_case(&entry.block));
+        let name_ok = self.name_prefixes.is_empty()
+            || self.name_prefixes.iter().any(|p| entry.name.starts_with(p.as_str()));
+        block_ok && name_ok
+    }
+}
+
+/* ============================================================
+ * Parser
+ * ============================================================
+ */
+
+/// Stream every block, subheader and entry out of a NamesList.txt reader.
+///
+/// An item is yielded once the lines that annotate it have been read, so
+/// the iterator runs one item behind the input.
+pub fn parse<R: BufRead>(reader: R) -> Parser<R> {
+    Parser {
+        lines: reader.lines(),
+        line_no: 0,
+        block: String::new(),
+        subheader: String::new(),
+        pending: None,
+        last_text: LastText::None,
+    }
+}
+
+/// Stream only the entries that pass `filter`.
+pub fn entries<R: BufRead>(reader: R, filter: Filter) -> impl Iterator<Item = Result<Entry>> {
+    parse(reader).filter_map(move |item| match item {
+        Ok(Item::Entry(entry)) => filter.matches(&entry).then_some(Ok(entry)),
+        Ok(_) => None,
+        Err(e) => Some(Err(e)),
+    })
+}
+
+/// Which free-text field a continuation line extends.
+#[derive(Debug, Clone, Copy)]
+enum LastText {
+    None,
+    Note,
+    Comment,
+}
+
+pub struct Parser<R> {
+    lines: Lines<R>,
+    line_no: usize,
+    block: String,
+    subheader: String,
+    pending: Option<Result<Item>>,
+    last_text: LastText,
+}
+
+impl<R: BufRead> Iterator for Parser<R> {
+    type Item = Result<Item>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let Some(line) = self.lines.next() else {
+                return self.pending.take();
+            };
+            self.line_no += 1;
+
+            let line = match line {
+                Ok(line) => line,
+                Err(source) => {
+                    return Some(Err(ParseError::Io { line: self.line_no, source }));
+                }
+            };
+
+            if let Some(rest) = line.strip_prefix('\t') {
+                if let Err(e) = self.detail_line(rest) {
+                    return Some(Err(e));
+                }
+                continue;
+            }
+
+            if let Some(text) = line.strip_prefix("@+") {
+                self.note(text);
+                continue;
+            }
+
+            // File title, sidebar comments and "@~" summary markers carry
+            // nothing we model.
+            if line.starts_with("@@@") || line.starts_with(';') || line.starts_with("@~") {
+                self.last_text = LastText::None;
+                continue;
+            }
+            if line.starts_with("@@+") {
+                continue;
+            }
+
+            let done = self.pending.take();
+            self.last_text = LastText::None;
+
+            if let Some(rest) = line.strip_prefix("@@\t") {
+                self.pending = Some(self.block_header(rest));
+            } else if let Some(rest) = line.strip_prefix('@') {
+                self.subheader = rest.trim().to_string();
+                self.pending = Some(Ok(Item::Subheader(Subheader {
+                    text: self.subheader.clone(),
+                    notes: Vec::new(),
+                })));
+            } else if !line.trim().is_empty() {
+                self.pending = Some(self.entry_line(&line));
+            }
+
+            if done.is_some() {
+                return done;
+            }
+        }
+    }
+}
+
+impl<R: BufRead> Parser<R> {
+    fn malformed(&self, text: &str) -> ParseError {
+        ParseError::Malformed { line: self.line_no, text: text.to_string() }
+    }
+
+    fn codepoint(&self, hex: &str) -> Result<u32> {
+        u32::from_str_radix(hex, 16).map_err(|_| ParseError::InvalidCodepoint {
+            line: self.line_no,
+            hex: hex.to_string(),
+        })
+    }
+
+    fn block_header(&mut self, rest: &str) -> Result<Item> {
+        let fields: Vec<&str> = rest.split('\t').collect();
+        let [first, name, last] = fields[..] else {
+            return Err(self.malformed(rest));
+        };
+
+        self.block = name.trim().to_string();
+        self.subheader.clear();
+
+        Ok(Item::Block(Block {
+            first: self.codepoint(first.trim())?,
+            last: self.codepoint(last.trim())?,
+            name: self.block.clone(),
+            notes: Vec::new(),
+        }))
+    }
+
+    fn entry_line(&self, line: &str) -> Result<Item> {
+        let Some((hex, name)) = line.split_once('\t') else {
+            return Err(self.malformed(line));
+        };
+
+        Ok(Item::Entry(Entry {
+            codepoint: self.codepoint(hex.trim())?,
+            name: name.trim().to_string(),
+            block: self.block.clone(),
+            subheader: self.subheader.clone(),
+            notes: Vec::new(),
+            aliases: Vec::new(),
+            formal_aliases: Vec::new(),
+            comments: Vec::new(),
+            cross_refs: Vec::new(),
+            canonical_decomposition: Vec::new(),
+            compatibility_mapping: None,
+            variation_sequences: Vec::new(),
+        }))
+    }
+
+    fn note(&mut self, text: &str) {
+        let text = text.trim();
+        let text = text.strip_prefix("* ").unwrap_or(text).to_string();
+
+        let notes = match &mut self.pending {
+            Some(Ok(Item::Block(b))) => &mut b.notes,
+            Some(Ok(Item::Subheader(s))) => &mut s.notes,
+            Some(Ok(Item::Entry(e))) => &mut e.notes,
+            _ => return,
+        };
+        notes.push(text);
+        self.last_text = LastText::Note;
+    }
+
+    /// A tab-indented line: an entry detail, or the continuation of the
+    /// note or comment above it.
+    fn detail_line(&mut self, rest: &str) -> Result<()> {
+        let marker = rest.chars().next().unwrap_or(' ');
+        let payload = rest.get(1..).and_then(|p| p.strip_prefix(' '));
+
+        let (Some(payload), Some(Ok(Item::Entry(_)))) = (payload, &self.pending) else {
+            self.continuation(rest);
+            return Ok(());
+        };
+        if !"=%*x:#~".contains(marker) {
+            self.continuation(rest);
+            return Ok(());
+        }
+
+        let detail = self.detail(marker, payload.trim())?;
+        let Some(Ok(Item::Entry(entry))) = &mut self.pending else {
+            unreachable!("checked above");
+        };
+
+        self.last_text = LastText::None;
+        match detail {
+            Detail::Alias(a) => entry.aliases.push(a),
+            Detail::FormalAlias(a) => entry.formal_aliases.push(a),
+            Detail::Comment(c) => {
+                entry.comments.push(c);
+                self.last_text = LastText::Comment;
+            }
+            Detail::CrossRef(x) => entry.cross_refs.push(x),
+            Detail::Canonical(cps) => entry.canonical_decomposition = cps,
+            Detail::Compatibility(m) => entry.compatibility_mapping = Some(m),
+            Detail::Variation(v) => entry.variation_sequences.push(v),
+        }
+        Ok(())
+    }
+
+    fn detail(&self, marker: char, payload: &str) -> Result<Detail> {
+        Ok(match marker {
+            '=' => Detail::Alias(payload.to_string()),
+            '%' => Detail::FormalAlias(payload.to_string()),
+            '*' => Detail::Comment(payload.to_string()),
+            'x' => Detail::CrossRef(self.cross_ref(payload)?),
+            ':' => Detail::Canonical(self.hex_run(payload)?.0),
+            '#' => {
+                let (tag, rest) = match payload.strip_prefix('<').and_then(|p| p.split_once('>')) {
+                    Some((tag, rest)) => (Some(tag.to_string()), rest.trim()),
+                    None => (None, payload),
+                };
+                Detail::Compatibility(CompatibilityMapping {
+                    tag,
+                    codepoints: self.hex_run(rest)?.0,
+                })
+            }
+            '~' => {
+                let (cps, description) = self.hex_run(payload)?;
+                let [base, selector] = cps[..] else {
+                    return Err(self.malformed(payload));
+                };
+                Detail::Variation(VariationSequence {
+                    base,
+                    selector,
+                    description,
+                })
+            }
+            _ => unreachable!("marker checked by caller"),
+        })
+    }
+
+    /// "(latin small letter ae - 00E6)" or a bare "00E6".
+    fn cross_ref(&self, payload: &str) -> Result<CrossRef> {
+        if let Some(inner) = payload.strip_prefix('(').and_then(|p| p.strip_suffix(')')) {
+            let Some((name, hex)) = inner.rsplit_once(" - ") else {
+                return Err(self.malformed(payload));
+            };
+            return Ok(CrossRef {
+                codepoint: self.codepoint(hex.trim())?,
+                name: Some(name.trim().to_string()),
+            });
+        }
+
+        Ok(CrossRef {
+            codepoint: self.codepoint(payload)?,
+            name: None,
+        })
+    }
+
+    /// Leading codepoints of a detail line, plus whatever text follows
+    /// them. Codepoints are uppercase hex; the trailing text is the
+    /// lowercase name or description, so "face" is never read as hex.
+    fn hex_run(&self, payload: &str) -> Result<(Vec<u32>, String)> {
+        let mut cps = Vec::new();
+        let mut rest = payload;
+
+        while let Some(token) = rest.split_whitespace().next() {
+            let is_hex = (4..=6).contains(&token.len())
+                && token
+                    .chars()
+                    .all(|c| c.is_ascii_digit() || (c.is_ascii_uppercase() && c.is_ascii_hexdigit()));
+            if !is_hex {
+                break;
+            }
+            cps.push(self.codepoint(token)?);
+            rest = rest.trim_start()[token.len()..].trim_start();
+        }
+
+        if cps.is_empty() {
+            return Err(self.malformed(payload));
+        }
+
+        Ok((cps, rest.trim().to_string()))
+    }
+
+    fn continuation(&mut self, text: &str) {
+        let text = text.trim();
+        let target = match (&mut self.pending, self.last_text) {
+            (Some(Ok(Item::Block(b))), LastText::Note) => b.notes.last_mut(),
+            (Some(Ok(Item::Subheader(s))), LastText::Note) => s.notes.last_mut(),
+            (Some(Ok(Item::Entry(e))), LastText::Note) => e.notes.last_mut(),
+            (Some(Ok(Item::Entry(e))), LastText::Comment) => e.comments.last_mut(),
+            _ => None,
+        };
+        if let Some(last) = target {
+            last.push('\n');
+            last.push_str(text);
+        }
+    }
+}
+
+enum Detail {
+    Alias(String),
+    FormalAlias(String),
+    Comment(String),
+    CrossRef(CrossRef),
+    Canonical(Vec<u32>),
+    Compatibility(CompatibilityMapping),
+    Variation(VariationSequence),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES_LIST: &str = "\
+@@@\tThe Unicode Standard 15.1
+; sidebar comment
+@@\t13000\tEgyptian Hieroglyphs\t1342F
+@+\t\tThe block note,
+\t\tcontinued on a second line.
+@\t\tA. Man and his occupations
+@+\t\t* A subheader note
+13000\tEGYPTIAN HIEROGLYPH A001
+\t= seated man
+\t% EGYPTIAN HIEROGLYPH A001 MAN
+\t* classifier human being
+\t  and his occupations
+\tx (egyptian hieroglyph a002 - 13001)
+\tx 13002
+\t~ 13000 FE00 rotated 90 degrees
+@+\t\tAn entry note
+13001\tEGYPTIAN HIEROGLYPH A002
+\t: 0041 0300
+\t# <noBreak> 0020
+13002\tEGYPTIAN HIEROGLYPH A003
+\t# 0061 0062
+@@\t16A0\tRunic\t16FF
+16A0\tRUNIC LETTER FEHU FEOH FE F
+";
+
+    fn items(text: &str) -> Vec<Item> {
+        parse(text.as_bytes()).collect::<Result<_>>().unwrap()
+    }
+
+    fn entry(items: &[Item], codepoint: u32) -> &Entry {
+        items
+            .iter()
+            .find_map(|item| match item {
+                Item::Entry(e) if e.codepoint == codepoint => Some(e),
+                _ => None,
+            })
+            .unwrap()
+    }
+
+    #[test]
+    fn headers_open_blocks_and_subheaders() {
+        let items = items(NAMES_LIST);
+        assert_eq!(items.len(), 7);
+        assert_eq!(
+            items[0],
+            Item::Block(Block {
+                first: 0x13000,
+                last: 0x1342F,
+                name: "Egyptian Hieroglyphs".to_string(),
+                notes: vec!["The block note,\ncontinued on a second line.".to_string()],
+            })
+        );
+        assert_eq!(
+            items[1],
+            Item::Subheader(Subheader {
+                text: "A. Man and his occupations".to_string(),
+                notes: vec!["A subheader note".to_string()],
+            })
+        );
+
+        let a001 = entry(&items, 0x13000);
+        assert_eq!(a001.name, "EGYPTIAN HIEROGLYPH A001");
+        assert_eq!(a001.block, "Egyptian Hieroglyphs");
+        assert_eq!(a001.subheader, "A. Man and his occupations");
+
+        // A new block resets the subheader.
+        let fehu = entry(&items, 0x16A0);
+        assert_eq!(fehu.block, "Runic");
+        assert_eq!(fehu.subheader, "");
+    }
+
+    #[test]
+    fn entries_collect_their_detail_lines() {
+        let items = items(NAMES_LIST);
+        let a001 = entry(&items, 0x13000);
+        assert_eq!(a001.aliases, ["seated man"]);
+        assert_eq!(a001.formal_aliases, ["EGYPTIAN HIEROGLYPH A001 MAN"]);
+        assert_eq!(
+            a001.comments,
+            ["classifier human being\nand his occupations"]
+        );
+        assert_eq!(a001.notes, ["An entry note"]);
+        assert_eq!(
+            a001.cross_refs,
+            [
+                CrossRef {
+                    codepoint: 0x13001,
+                    name: Some("egyptian hieroglyph a002".to_string()),
+                },
+                CrossRef {
+                    codepoint: 0x13002,
+                    name: None,
+                },
+            ]
+        );
+        assert_eq!(
+            a001.variation_sequences,
+            [VariationSequence {
+                base: 0x13000,
+                selector: 0xFE00,
+                description: "rotated 90 degrees".to_string(),
+            }]
+        );
+        assert!(a001.canonical_decomposition.is_empty());
+        assert_eq!(a001.compatibility_mapping, None);
+    }
+
+    #[test]
+    fn decompositions_are_read_as_codepoints() {
+        let items = items(NAMES_LIST);
+        let a002 = entry(&items, 0x13001);
+        assert_eq!(a002.canonical_decomposition, [0x41, 0x300]);
+        assert_eq!(
+            a002.compatibility_mapping,
+            Some(CompatibilityMapping {
+                tag: Some("noBreak".to_string()),
+                codepoints: vec![0x20],
+            })
+        );
+
+        let a003 = entry(&items, 0x13002);
+        assert_eq!(
+            a003.compatibility_mapping,
+            Some(CompatibilityMapping {
+                tag: None,
+                codepoints: vec![0x61, 0x62],
+            })
+        );
+    }
+
+    #[test]
+    fn filter_narrows_by_block_and_name_prefix() {
+        let codepoints = |filter: Filter| -> Vec<u32> {
+            entries(NAMES_LIST.as_bytes(), filter)
+                .map(|e| e.unwrap().codepoint)
+                .collect()
+        };
+
+        assert_eq!(
+            codepoints(Filter::default()),
+            [0x13000, 0x13001, 0x13002, 0x16A0]
+        );
+        assert_eq!(
+            codepoints(Filter {
+                blocks: vec!["runic".to_string()],
+                ..Filter::default()
+            }),
+            [0x16A0]
+        );
+        assert_eq!(
+            codepoints(Filter {
+                name_prefixes: vec!["EGYPTIAN HIEROGLYPH A00".to_string()],
+                ..Filter::default()
+            }),
+            [0x13000, 0x13001, 0x13002]
+        );
+        assert_eq!(
+            codepoints(Filter {
+                blocks: vec!["Runic".to_string()],
+                name_prefixes: vec!["EGYPTIAN".to_string()],
+            }),
+            []
+        );
+    }
+
+    #[test]
+    fn errors_carry_the_line_they_were_found_on() {
+        let error = |text: &str| {
+            parse(text.as_bytes())
+                .find_map(|item| item.err())
+                .expect("an error")
+                .to_string()
+        };
+
+        assert_eq!(
+            error("@@\t13000\tEgyptian Hieroglyphs\t1342F\n1300G\tBAD\n"),
+            "line 2: invalid hex codepoint: 1300G"
+        );
+        assert_eq!(
+            error("@@\t13000\tEgyptian Hieroglyphs\n"),
+            "line 1: malformed line: \"13000\\tEgyptian Hieroglyphs\""
+        );
+        assert_eq!(
+            error("13000\tA001\n\t= alias\n\tx (no codepoint)\n"),
+            "line 3: malformed line: \"(no codepoint)\""
+        );
+        assert_eq!(
+            error("13000\tA001\n\t~ 13000 rotated\n"),
+            "line 2: malformed line: \"13000 rotated\""
+        );
+        assert_eq!(
+            error("13000 A001\n"),
+            "line 1: malformed line: \"13000 A001\""
+        );
+    }
+
+    #[test]
+    fn an_error_does_not_end_the_stream() {
+        let text = "13000\tA001\n\tx zz\n13001\tA002\n";
+        let results: Vec<_> = parse(text.as_bytes()).collect();
+        assert!(matches!(
+            results[0],
+            Err(ParseError::InvalidCodepoint { line: 2, .. })
+        ));
+        assert!(matches!(&results[1], Ok(Item::Entry(e)) if e.codepoint == 0x13000));
+        assert!(matches!(&results[2], Ok(Item::Entry(e)) if e.codepoint == 0x13001));
+    }
+}
@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Normative property lookups from a local Unicode Character Database.
+//!
+//! NamesList.txt is explicitly not a normative file. The UCD directory it
+//! ships alongside is: `UnicodeData.txt` gives each codepoint's name and
+//! General_Category, `Scripts.txt` its Script, `Blocks.txt` its block.
+
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// One `UnicodeData.txt` record.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CharData {
+    /// `None` for codepoints inside a `<..., First>`/`<..., Last>` range,
+    /// whose names are derived rather than listed.
+    pub name: Option<String>,
+    pub general_category: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Range {
+    first: u32,
+    last: u32,
+    value: String,
+}
+
+#[derive(Debug, Default)]
+pub struct Ucd {
+    chars: HashMap<u32, CharData>,
+    /// `<..., First>`..`<..., Last>` spans, valued by General_Category.
+    char_ranges: Vec<Range>,
+    scripts: Vec<Range>,
+    blocks: Vec<Range>,
+}
+
+impl Ucd {
+    /// Load `UnicodeData.txt`, `Scripts.txt` and `Blocks.txt` from `dir`.
+    pub fn load(dir: &Path) -> io::Result<Self> {
+        let mut ucd = Ucd::default();
+
+        let text = read(dir, "UnicodeData.txt")?;
+        let mut range_start: Option<u32> = None;
+        for (line_no, line) in text.lines().enumerate() {
+            let fields: Vec<&str> = line.split(';').collect();
+            if fields.len() < 3 {
+                continue;
+            }
+            let cp = hex(fields[0], "UnicodeData.txt", line_no)?;
+            let name = fields[1];
+            let gc = fields[2].to_string();
+
+            if name.starts_with('<') && name.ends_with(", First>") {
+                range_start = Some(cp);
+            } else if name.starts_with('<') && name.ends_with(", Last>") {
+                if let Some(first) = range_start.take() {
+                    ucd.char_ranges.push(Range { first, last: cp, value: gc });
+                }
+            } else {
+                let name = (!name.starts_with('<')).then(|| name.to_string());
+                ucd.chars.insert(cp, CharData { name, general_category: gc });
+            }
+        }
+
+        ucd.scripts = ranges(&read(dir, "Scripts.txt")?, "Scripts.txt")?;
+        ucd.blocks = ranges(&read(dir, "Blocks.txt")?, "Blocks.txt")?;
+
+        Ok(ucd)
+    }
+
+    pub fn char_data(&self, cp: u32) -> Option<CharData> {
+        if let Some(data) = self.chars.get(&cp) {
+            return Some(data.clone());
+        }
+        lookup(&self.char_ranges, cp).map(|gc| CharData {
+            name: None,
+            general_category: gc.to_string(),
+        })
+    }
+
+    pub fn script(&self, cp: u32) -> Option<&str> {
+        lookup(&self.scripts, cp)
+    }
+
+    pub fn block(&self, cp: u32) -> Option<&str> {
+        lookup(&self.blocks, cp)
+    }
+}
+
+fn read(dir: &Path, file: &str) -> io::Result<String> {
+    let path = dir.join(file);
+    fs::read_to_string(&path)
+        .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))
+}
+
+fn hex(text: &str, file: &str, line_no: usize) -> io::Result<u32> {
+    u32::from_str_radix(text.trim(), 16).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("{} line {}: invalid hex codepoint: {}", file, line_no + 1, text),
+        )
+    })
+}
+
+/// Parse the `XXXX..YYYY ; Value # comment` shape shared by Scripts.txt,
+/// Blocks.txt and most other UCD property files.
+fn ranges(text: &str, file: &str) -> io::Result<Vec<Range>> {
+    let mut out = Vec::new();
+    for (line_no, line) in text.lines().enumerate() {
+        let line = line.split('#').next().unwrap_or_default().trim();
+        let Some((span, value)) = line.split_once(';') else {
+            continue;
+        };
+        let (first, last) = match span.trim().split_once("..") {
+            Some((a, b)) => (hex(a, file, line_no)?, hex(b, file, line_no)?),
+            None => {
+                let cp = hex(span, file, line_no)?;
+                (cp, cp)
+            }
+        };
+        out.push(Range { first, last, value: value.trim().to_string() });
+    }
+    out.sort_by_key(|r| r.first);
+    Ok(out)
+}
+
+fn lookup(ranges: &[Range], cp: u32) -> Option<&str> {
+    let idx = ranges.partition_point(|r| r.first <= cp).checked_sub(1)?;
+    let r = &ranges[idx];
+    (cp <= r.last).then_some(r.value.as_str())
+}
@@ -1,3 +1,350 @@
-fn main() {
-    println!("Hello, world!");
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+use std::collections::BTreeMap;
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::atomic::Ordering;
+
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+
+// Pull in shared infrastructure
+use signlist_core::VERBOSE;
+use signlist_core::verbose;
+use signlist_core::warn;
+use signlist_core::stderr_is_tty;
+use signlist_core::gardiner::{canonical_code, is_format_control_codepoint};
+use signlist_core::importer::{concordance, SignListImporter, SignRecord};
+
+use gardiner_signlist_extractor::JseshImporter;
+use unicode_hieroglyphs_extractor::UnicodeImporter;
+
+const USAGE: &str =
+    "usage: signlist-correlator --unicode <file> --jsesh <file> --output <file> \
+     --report <file> [--list <file>]... [--concordance <file>] [--verbose]";
+
+/* ============================================================
+ * Data model
+ * ============================================================
+ */
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct UnicodeSign {
+    unicode_point: String,
+    codepoint_dec: u32,
+    char: String,
+    unicode_name: String,
+    unicode_id: String,
+    is_format_control: bool,
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+struct JseshSign {
+    family: String,
+    family_name: String,
+    jsesh_code: String,
+}
+
+/// One Gardiner code with whatever each source knows about it.
+#[derive(Debug, Serialize)]
+struct MergedSign {
+    gardiner_code: String,
+    unicode: Option<UnicodeSign>,
+    jsesh: Option<JseshSign>,
+}
+
+#[derive(Debug, Serialize)]
+struct OrphanReport {
+    unicode_records: usize,
+    jsesh_records: usize,
+    matched: usize,
+    unicode_only: Vec<UnicodeSign>,
+    jsesh_only: Vec<JseshSign>,
+    /// Records whose Gardiner code an earlier record of the same source
+    /// already holds; the merged sign keeps the first one.
+    unicode_collisions: Vec<UnicodeSign>,
+    jsesh_collisions: Vec<JseshSign>,
+}
+
+/* ============================================================
+ * Main
+ * ============================================================
+ */
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &str) -> Result<T> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path))?;
+    serde_json::from_str(&text).with_context(|| format!("failed to parse {}", path))
+}
+
+fn import(importer: &dyn SignListImporter, path: &str) -> Result<Vec<SignRecord>> {
+    let file = File::open(path).with_context(|| format!("failed to read {}", path))?;
+    importer
+        .import(&mut BufReader::new(file))
+        .map_err(|e| anyhow!("{}: {}", path, e))
+}
+
+fn write_json<T: Serialize>(path: &str, value: &T) -> Result<()> {
+    let json = serde_json::to_string_pretty(value)?;
+    std::fs::write(path, json).with_context(|| format!("failed to write output file: {}", path))
+}
+
+/// Join both sources on the canonical Gardiner code.
+///
+/// Records without one (format controls, Extended-A's numeric names) stay
+/// unmatched, and a record whose code its own source already used is
+/// reported as a collision rather than dropped.
+fn correlate(unicode: &[UnicodeSign], jsesh: &[JseshSign]) -> (Vec<MergedSign>, OrphanReport) {
+    let mut merged: BTreeMap<String, MergedSign> = BTreeMap::new();
+    let mut unicode_only = Vec::new();
+    let mut jsesh_only = Vec::new();
+    let mut unicode_collisions = Vec::new();
+    let mut jsesh_collisions = Vec::new();
+
+    fn entry(merged: &mut BTreeMap<String, MergedSign>, code: String) -> &mut MergedSign {
+        merged.entry(code.clone()).or_insert_with(|| MergedSign {
+            gardiner_code: code,
+            unicode: None,
+            jsesh: None,
+        })
+    }
+
+    let signs = unicode
+        .iter()
+        .filter(|s| !s.is_format_control && !is_format_control_codepoint(s.codepoint_dec));
+
+    for sign in signs {
+        match canonical_code(&sign.unicode_id) {
+            Some(code) => match &mut entry(&mut merged, code).unicode {
+                Some(_) => unicode_collisions.push(sign.clone()),
+                slot => *slot = Some(sign.clone()),
+            },
+            None => unicode_only.push(sign.clone()),
+        }
+    }
+
+    for sign in jsesh {
+        match canonical_code(&sign.jsesh_code) {
+            Some(code) => match &mut entry(&mut merged, code).jsesh {
+                Some(_) => jsesh_collisions.push(sign.clone()),
+                slot => *slot = Some(sign.clone()),
+            },
+            None => jsesh_only.push(sign.clone()),
+        }
+    }
+
+    let mut matched = 0;
+    for m in merged.values() {
+        match (&m.unicode, &m.jsesh) {
+            (Some(_), Some(_)) => matched += 1,
+            (Some(u), None) => unicode_only.push(u.clone()),
+            (None, Some(j)) => jsesh_only.push(j.clone()),
+            (None, None) => {}
+        }
+    }
+
+    let report = OrphanReport {
+        unicode_records: unicode.len(),
+        jsesh_records: jsesh.len(),
+        matched,
+        unicode_only,
+        jsesh_only,
+        unicode_collisions,
+        jsesh_collisions,
+    };
+    (merged.into_values().collect(), report)
+}
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+
+    let mut unicode_path: Option<String> = None;
+    let mut jsesh_path: Option<String> = None;
+    let mut output: Option<String> = None;
+    let mut report: Option<String> = None;
+    let mut lists: Vec<String> = Vec::new();
+    let mut concordance_path: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--unicode" => {
+                unicode_path = Some(args.next().context("missing value for --unicode")?);
+            }
+            "--jsesh" => {
+                jsesh_path = Some(args.next().context("missing value for --jsesh")?);
+            }
+            "--output" => {
+                output = Some(args.next().context("missing value for --output")?);
+            }
+            "--report" => {
+                report = Some(args.next().context("missing value for --report")?);
+            }
+            "--list" => {
+                lists.push(args.next().context("missing value for --list")?);
+            }
+            "--concordance" => {
+                concordance_path = Some(args.next().context("missing value for --concordance")?);
+            }
+            "--verbose" | "-v" => {
+                VERBOSE.store(true, Ordering::Relaxed);
+            }
+            _ => {
+                anyhow::bail!("unknown argument: {}", arg);
+            }
+        }
+    }
+
+    let unicode_path = unicode_path.context(USAGE)?;
+    let jsesh_path = jsesh_path.context(USAGE)?;
+    let output = output.context(USAGE)?;
+    let report = report.context(USAGE)?;
+
+    if !lists.is_empty() && concordance_path.is_none() {
+        anyhow::bail!("--list only feeds the concordance; pass --concordance <file> too");
+    }
+
+    verbose!("reading unicode inventory: {}", unicode_path);
+    let unicode: Vec<UnicodeSign> = read_json(&unicode_path)?;
+
+    verbose!("reading jsesh inventory: {}", jsesh_path);
+    let jsesh: Vec<JseshSign> = read_json(&jsesh_path)?;
+
+    let (merged, orphans) = correlate(&unicode, &jsesh);
+
+    verbose!(
+        "matched {} codes; {} unicode-only, {} jsesh-only",
+        orphans.matched,
+        orphans.unicode_only.len(),
+        orphans.jsesh_only.len()
+    );
+    for sign in &orphans.unicode_collisions {
+        let code = &sign.unicode_id;
+        warn!("{}: Gardiner code already taken; kept in the report only", code);
+    }
+    for sign in &orphans.jsesh_collisions {
+        let code = &sign.jsesh_code;
+        warn!("{}: Gardiner code already taken; kept in the report only", code);
+    }
+
+    write_json(&output, &merged)?;
+
+    write_json(&report, &orphans)?;
+
+    // Every list, the two built-in ones included, joins the many-to-many
+    // concordance through its Gardiner codes.
+    if let Some(path) = &concordance_path {
+        let mut records = import(&UnicodeImporter, &unicode_path)?;
+        records.extend(import(&JseshImporter, &jsesh_path)?);
+
+        for list in &lists {
+            verbose!("reading sign list: {}", list);
+            let extra: Vec<SignRecord> = read_json(list)?;
+            records.extend(extra);
+        }
+
+        let rows = concordance(&records);
+        verbose!("concordance: {} rows from {} records", rows.len(), records.len());
+        write_json(path, &rows)?;
+    }
+
+    println!(
+        "Correlated {} signs: {} matched, {} Unicode-only, {} JSesh-only, {} collisions.",
+        merged.len(),
+        orphans.matched,
+        orphans.unicode_only.len(),
+        orphans.jsesh_only.len(),
+        orphans.unicode_collisions.len() + orphans.jsesh_collisions.len()
+    );
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn unicode(unicode_id: &str, codepoint: u32) -> UnicodeSign {
+        UnicodeSign {
+            unicode_point: format!("U+{:04X}", codepoint),
+            codepoint_dec: codepoint,
+            char: char::from_u32(codepoint).unwrap().to_string(),
+            unicode_name: format!("EGYPTIAN HIEROGLYPH {}", unicode_id),
+            unicode_id: unicode_id.to_string(),
+            is_format_control: false,
+        }
+    }
+
+    fn jsesh(family: &str, jsesh_code: &str) -> JseshSign {
+        JseshSign {
+            family: family.to_string(),
+            family_name: String::new(),
+            jsesh_code: jsesh_code.to_string(),
+        }
+    }
+
+    fn codes(merged: &[MergedSign]) -> Vec<&str> {
+        merged.iter().map(|m| m.gardiner_code.as_str()).collect()
+    }
+
+    #[test]
+    fn joins_on_the_canonical_code() {
+        let unicode = [
+            unicode("A001", 0x13000),
+            unicode("AA001", 0x1340D),
+            unicode("NL005A", 0x13339),
+            unicode("B001", 0x13050),
+        ];
+        let jsesh = [jsesh("A", "A1"), jsesh("Aa", "Aa1"), jsesh("NL", "NL5A"), jsesh("C", "C1")];
+
+        let (merged, report) = correlate(&unicode, &jsesh);
+        assert_eq!(codes(&merged), ["A1", "AA1", "B1", "C1", "NL5A"]);
+        assert_eq!(report.unicode_records, 4);
+        assert_eq!(report.jsesh_records, 4);
+        assert_eq!(report.matched, 3);
+        assert_eq!(merged[0].unicode.as_ref().unwrap().unicode_id, "A001");
+        assert_eq!(merged[0].jsesh.as_ref().unwrap().jsesh_code, "A1");
+
+        let unicode_only: Vec<_> = report.unicode_only.iter().map(|s| &s.unicode_id).collect();
+        let jsesh_only: Vec<_> = report.jsesh_only.iter().map(|s| &s.jsesh_code).collect();
+        assert_eq!(unicode_only, ["B001"]);
+        assert_eq!(jsesh_only, ["C1"]);
+        assert!(report.unicode_collisions.is_empty());
+        assert!(report.jsesh_collisions.is_empty());
+    }
+
+    #[test]
+    fn format_controls_and_uncoded_names_stay_unmatched() {
+        let mut control = unicode("J", 0x13430);
+        control.is_format_control = true;
+        let unicode = [control, unicode("Z", 0x13437), unicode("HIEROGLYPH-13460", 0x13460)];
+        let jsesh = [jsesh("R", "nTr")];
+
+        let (merged, report) = correlate(&unicode, &jsesh);
+        assert!(merged.is_empty());
+        assert_eq!(report.matched, 0);
+        // Format controls are left out of the join and the orphan lists.
+        let unicode_only: Vec<_> = report.unicode_only.iter().map(|s| &s.unicode_id).collect();
+        assert_eq!(unicode_only, ["HIEROGLYPH-13460"]);
+        assert_eq!(report.jsesh_only.len(), 1);
+    }
+
+    #[test]
+    fn records_sharing_a_code_are_reported_as_collisions() {
+        let unicode = [unicode("A001", 0x13000), unicode("US1A001", 0x13461)];
+        let jsesh = [jsesh("A", "A1"), jsesh("A", "A01"), jsesh("A", "A2")];
+
+        let (merged, report) = correlate(&unicode, &jsesh);
+        assert_eq!(codes(&merged), ["A1", "A2"]);
+        assert_eq!(report.matched, 1);
+
+        // The first record keeps the code; the others are not lost.
+        assert_eq!(merged[0].unicode.as_ref().unwrap().unicode_id, "A001");
+        assert_eq!(merged[0].jsesh.as_ref().unwrap().jsesh_code, "A1");
+        assert_eq!(report.unicode_collisions[0].unicode_id, "US1A001");
+        assert_eq!(report.jsesh_collisions[0].jsesh_code, "A01");
+        assert_eq!(report.unicode_collisions.len() + report.jsesh_collisions.len(), 2);
+        assert_eq!(report.jsesh_only[0].jsesh_code, "A2");
+    }
 }
@@ -0,0 +1,129 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Import a hand-maintained sign list kept as CSV.
+//!
+//! The file needs a header row with `code` and `gardiner` columns and may
+//! carry a `description` column; other columns are ignored:
+//!
+//! ```text
+//! code,gardiner,description
+//! Möller 1,A1,seated man
+//! HG A1a,A1 A2,seated man (conflated)
+//! ```
+//!
+//! `gardiner` holds zero or more Gardiner codes separated by spaces or
+//! semicolons, in any spelling `canonical_code` accepts ("A1", "A001").
+
+use std::fmt;
+use std::io::BufRead;
+
+use serde::Deserialize;
+
+use signlist_core::gardiner::canonical_code;
+use signlist_core::importer::{ImportResult, SignListImporter, SignRecord};
+
+#[derive(Debug, Deserialize)]
+struct Row {
+    code: String,
+    #[serde(default)]
+    gardiner: String,
+    #[serde(default)]
+    description: String,
+}
+
+/// A CSV row named something that is not a Gardiner code.
+/// Line numbers are 1-based and count the header.
+#[derive(Debug)]
+pub struct InvalidGardinerCode {
+    pub line: u64,
+    pub value: String,
+}
+
+impl fmt::Display for InvalidGardinerCode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: not a Gardiner code: {}", self.line, self.value)
+    }
+}
+
+impl std::error::Error for InvalidGardinerCode {}
+
+/// A CSV sign list under a caller-chosen list identifier.
+pub struct CsvImporter {
+    pub list_id: String,
+}
+
+impl SignListImporter for CsvImporter {
+    fn list_id(&self) -> &str {
+        &self.list_id
+    }
+
+    fn import(&self, reader: &mut dyn BufRead) -> ImportResult<Vec<SignRecord>> {
+        let mut csv = csv::ReaderBuilder::new().trim(csv::Trim::All).from_reader(reader);
+        let headers = csv.headers()?.clone();
+        let mut out = Vec::new();
+
+        for record in csv.records() {
+            let record = record?;
+            let line = record.position().map(|p| p.line()).unwrap_or_default();
+            let row: Row = record.deserialize(Some(&headers))?;
+
+            let mut gardiner = Vec::new();
+            for value in row.gardiner.split([' ', ';']).filter(|v| !v.is_empty()) {
+                let code = canonical_code(value).ok_or_else(|| InvalidGardinerCode {
+                    line,
+                    value: value.to_string(),
+                })?;
+                gardiner.push(code);
+            }
+
+            out.push(SignRecord {
+                list: self.list_id.clone(),
+                code: row.code,
+                gardiner,
+                description: row.description,
+            });
+        }
+
+        Ok(out)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn import(csv: &str) -> ImportResult<Vec<SignRecord>> {
+        let importer = CsvImporter { list_id: "moller".to_string() };
+        importer.import(&mut csv.as_bytes())
+    }
+
+    #[test]
+    fn imports_rows_with_any_number_of_codes() {
+        let records = import(
+            "code, gardiner, description, page\n\
+             Möller 1, A001, seated man, 3\n\
+             HG A1a, A1 A2;Aa1, seated man (conflated), 4\n\
+             Möller 99, , unidentified, 9\n",
+        )
+        .unwrap();
+
+        let gardiner: Vec<&[String]> = records.iter().map(|r| r.gardiner.as_slice()).collect();
+        assert_eq!(gardiner, [&["A1"][..], &["A1", "A2", "AA1"], &[]]);
+        assert!(records.iter().all(|r| r.list == "moller"));
+        assert_eq!(records[1].code, "HG A1a");
+        assert_eq!(records[1].description, "seated man (conflated)");
+    }
+
+    #[test]
+    fn description_is_optional() {
+        let records = import("code,gardiner\nMöller 1,A1\n").unwrap();
+        assert_eq!(records[0].description, "");
+    }
+
+    #[test]
+    fn rejects_values_that_are_not_gardiner_codes() {
+        let err = import("code,gardiner\nMöller 1,A1\nMöller 2,A1 seated\n").unwrap_err();
+        assert_eq!(err.to_string(), "line 3: not a Gardiner code: seated");
+    }
+}
@@ -0,0 +1,74 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+use std::env;
+use std::fs::File;
+use std::io::BufReader;
+use std::sync::atomic::Ordering;
+
+use anyhow::{anyhow, Context, Result};
+
+// Pull in shared infrastructure
+use signlist_core::VERBOSE;
+use signlist_core::verbose;
+use signlist_core::stderr_is_tty;
+use signlist_core::importer::SignListImporter;
+
+use signlist_csv_importer::CsvImporter;
+
+const USAGE: &str =
+    "usage: signlist-csv-importer --list-id <id> --input <file> --output <file> [--verbose]";
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+
+    let mut list_id: Option<String> = None;
+    let mut input: Option<String> = None;
+    let mut output: Option<String> = None;
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--list-id" => {
+                list_id = Some(args.next().context("missing value for --list-id")?);
+            }
+            "--input" => {
+                input = Some(args.next().context("missing value for --input")?);
+            }
+            "--output" => {
+                output = Some(args.next().context("missing value for --output")?);
+            }
+            "--verbose" | "-v" => {
+                VERBOSE.store(true, Ordering::Relaxed);
+            }
+            _ => {
+                anyhow::bail!("unknown argument: {}", arg);
+            }
+        }
+    }
+
+    let list_id = list_id.context(USAGE)?;
+    let input = input.context(USAGE)?;
+    let output = output.context(USAGE)?;
+
+    verbose!("reading {} sign list: {}", list_id, input);
+
+    let file = File::open(&input)
+        .with_context(|| format!("failed to open input file: {}", input))?;
+
+    let importer = CsvImporter { list_id };
+    let records = importer
+        .import(&mut BufReader::new(file))
+        .map_err(|e| anyhow!("{}: {}", input, e))?;
+
+    let json = serde_json::to_string_pretty(&records)?;
+    std::fs::write(&output, json)
+        .with_context(|| format!("failed to write output file: {}", output))?;
+
+    println!(
+        "Imported {} {} signs.",
+        records.len(),
+        importer.list_id()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,416 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+use std::collections::{BTreeMap, BTreeSet};
+use std::env;
+use std::sync::atomic::Ordering;
+
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+
+// Pull in shared infrastructure
+use signlist_core::VERBOSE;
+use signlist_core::verbose;
+use signlist_core::stderr_is_tty;
+use signlist_core::gardiner::{
+    canonical_code, family_of_code, family_of_subheader, is_format_control_codepoint, same_family,
+};
+
+const USAGE: &str =
+    "usage: signlist-lint --unicode <file> --jsesh <file> [--output <file>] \
+     [--fail-on error|warning|info|none] [--verbose]";
+
+/// Codepoint ranges of the Unicode blocks that hold Egyptian hieroglyphs.
+const HIEROGLYPH_BLOCKS: &[(&str, u32, u32)] = &[
+    ("Egyptian Hieroglyphs", 0x13000, 0x1342F),
+    ("Egyptian Hieroglyph Format Controls", 0x13430, 0x1345F),
+    ("Egyptian Hieroglyphs Extended-A", 0x13460, 0x143FF),
+];
+
+/* ============================================================
+ * Data model
+ * ============================================================
+ */
+
+/// The fields of `hieroglyphs_unicode.json` the rules look at.
+#[derive(Debug, Deserialize)]
+struct UnicodeRecord {
+    unicode_point: String,
+    codepoint_dec: u32,
+    unicode_id: String,
+    is_format_control: bool,
+    #[serde(default)]
+    subheader: String,
+    #[serde(default)]
+    annotations: Vec<String>,
+}
+
+/// The fields of `jsesh_inventory.json` the rules look at.
+#[derive(Debug, Deserialize)]
+struct JseshRecord {
+    family: String,
+    family_name: String,
+    jsesh_code: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+#[derive(Debug, Serialize)]
+struct Finding {
+    rule: &'static str,
+    severity: Severity,
+    source: &'static str,
+    subject: String,
+    message: String,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Summary {
+    error: usize,
+    warning: usize,
+    info: usize,
+}
+
+#[derive(Debug, Serialize)]
+struct Report {
+    summary: Summary,
+    findings: Vec<Finding>,
+}
+
+impl Report {
+    fn new(findings: Vec<Finding>) -> Self {
+        let mut summary = Summary::default();
+        for f in &findings {
+            match f.severity {
+                Severity::Error => summary.error += 1,
+                Severity::Warning => summary.warning += 1,
+                Severity::Info => summary.info += 1,
+            }
+        }
+        Self { summary, findings }
+    }
+
+    fn has_at_least(&self, threshold: Severity) -> bool {
+        self.findings.iter().any(|f| f.severity >= threshold)
+    }
+}
+
+impl UnicodeRecord {
+    /// Format controls are not signs, whatever the extractor flagged.
+    fn is_sign(&self) -> bool {
+        !self.is_format_control && !is_format_control_codepoint(self.codepoint_dec)
+    }
+
+    /// Family of the sign, read from its whole code prefix.
+    ///
+    /// The extractor's `family` field is the first letter of the id, which
+    /// files "AA001" under A and "NL001" / "NU001" under N. Signs without
+    /// a Gardiner code ("HIEROGLYPH-13460") have no family.
+    fn family(&self) -> Option<&str> {
+        canonical_code(&self.unicode_id)?;
+        family_of_code(&self.unicode_id)
+    }
+}
+
+/* ============================================================
+ * Rules
+ * ============================================================
+ */
+
+/// The same Gardiner code recorded more than once in a source.
+fn duplicate_codes(unicode: &[UnicodeRecord], jsesh: &[JseshRecord], out: &mut Vec<Finding>) {
+    let mut seen: BTreeMap<&str, usize> = BTreeMap::new();
+    for r in jsesh {
+        *seen.entry(&r.jsesh_code).or_default() += 1;
+    }
+    for (code, count) in seen.into_iter().filter(|(_, n)| *n > 1) {
+        out.push(Finding {
+            rule: "duplicate-gardiner-code",
+            severity: Severity::Error,
+            source: "jsesh",
+            subject: code.to_string(),
+            message: format!("JSesh code {} appears {} times", code, count),
+        });
+    }
+
+    let mut seen: BTreeMap<&str, usize> = BTreeMap::new();
+    for r in unicode.iter().filter(|r| r.is_sign()) {
+        *seen.entry(&r.unicode_id).or_default() += 1;
+    }
+    for (id, count) in seen.into_iter().filter(|(_, n)| *n > 1) {
+        out.push(Finding {
+            rule: "duplicate-gardiner-code",
+            severity: Severity::Error,
+            source: "unicode",
+            subject: id.to_string(),
+            message: format!("Unicode id {} appears {} times", id, count),
+        });
+    }
+}
+
+/// JSesh codes whose family prefix is not the family they were filed under.
+fn jsesh_family_prefix(jsesh: &[JseshRecord], out: &mut Vec<Finding>) {
+    for r in jsesh {
+        match family_of_code(&r.jsesh_code) {
+            Some(prefix) if same_family(prefix, &r.family) => {}
+            prefix => out.push(Finding {
+                rule: "jsesh-family-prefix",
+                severity: Severity::Error,
+                source: "jsesh",
+                subject: r.jsesh_code.clone(),
+                message: format!(
+                    "code prefix {:?} disagrees with recorded family {}",
+                    prefix.unwrap_or(""),
+                    r.family
+                ),
+            }),
+        }
+    }
+}
+
+/// Unicode records whose `family` is not the family of their subheader.
+fn unicode_family_subheader(unicode: &[UnicodeRecord], out: &mut Vec<Finding>) {
+    for r in unicode.iter().filter(|r| r.is_sign()) {
+        let (Some(expected), Some(family)) = (family_of_subheader(&r.subheader), r.family())
+        else {
+            continue;
+        };
+        if !same_family(expected, family) {
+            out.push(Finding {
+                rule: "unicode-family-subheader",
+                severity: Severity::Warning,
+                source: "unicode",
+                subject: r.unicode_point.clone(),
+                message: format!(
+                    "{} has family {} but sits under subheader {:?}",
+                    r.unicode_id, family, r.subheader
+                ),
+            });
+        }
+    }
+}
+
+/// Codepoints that fall outside every known hieroglyph block.
+fn codepoint_block(unicode: &[UnicodeRecord], out: &mut Vec<Finding>) {
+    for r in unicode {
+        let inside = HIEROGLYPH_BLOCKS
+            .iter()
+            .any(|(_, start, end)| (*start..=*end).contains(&r.codepoint_dec));
+        if !inside {
+            out.push(Finding {
+                rule: "codepoint-outside-blocks",
+                severity: Severity::Error,
+                source: "unicode",
+                subject: r.unicode_point.clone(),
+                message: format!("{} is outside the Egyptian hieroglyph blocks", r.unicode_id),
+            });
+        }
+    }
+}
+
+/// Signs that carry no descriptive text.
+fn empty_annotations(unicode: &[UnicodeRecord], jsesh: &[JseshRecord], out: &mut Vec<Finding>) {
+    for r in unicode.iter().filter(|r| r.is_sign()) {
+        if r.annotations.iter().all(|a| a.trim().is_empty()) {
+            out.push(Finding {
+                rule: "empty-annotation",
+                severity: Severity::Info,
+                source: "unicode",
+                subject: r.unicode_point.clone(),
+                message: format!("{} has no annotations", r.unicode_id),
+            });
+        }
+    }
+
+    for r in jsesh.iter().filter(|r| r.family_name.trim().is_empty()) {
+        out.push(Finding {
+            rule: "empty-annotation",
+            severity: Severity::Warning,
+            source: "jsesh",
+            subject: r.jsesh_code.clone(),
+            message: format!("{} has an empty family name", r.jsesh_code),
+        });
+    }
+}
+
+/// Families present in one source but not the other.
+fn family_coverage(unicode: &[UnicodeRecord], jsesh: &[JseshRecord], out: &mut Vec<Finding>) {
+    let unicode_families: BTreeSet<String> = unicode
+        .iter()
+        .filter(|r| r.is_sign())
+        .filter_map(|r| r.family())
+        .map(|f| f.to_ascii_uppercase())
+        .collect();
+    let jsesh_families: BTreeSet<String> =
+        jsesh.iter().map(|r| r.family.to_ascii_uppercase()).collect();
+
+    for family in unicode_families.difference(&jsesh_families) {
+        out.push(Finding {
+            rule: "family-coverage",
+            severity: Severity::Warning,
+            source: "unicode",
+            subject: family.clone(),
+            message: format!("family {} exists in Unicode but not in JSesh", family),
+        });
+    }
+    for family in jsesh_families.difference(&unicode_families) {
+        out.push(Finding {
+            rule: "family-coverage",
+            severity: Severity::Warning,
+            source: "jsesh",
+            subject: family.clone(),
+            message: format!("family {} exists in JSesh but not in Unicode", family),
+        });
+    }
+}
+
+/* ============================================================
+ * Main
+ * ============================================================
+ */
+
+fn parse_fail_on(value: &str) -> Result<Option<Severity>> {
+    match value {
+        "error" => Ok(Some(Severity::Error)),
+        "warning" => Ok(Some(Severity::Warning)),
+        "info" => Ok(Some(Severity::Info)),
+        "none" => Ok(None),
+        _ => anyhow::bail!("invalid value for --fail-on: {}", value),
+    }
+}
+
+fn read_json<T: for<'de> Deserialize<'de>>(path: &str) -> Result<T> {
+    let text = std::fs::read_to_string(path)
+        .with_context(|| format!("failed to read {}", path))?;
+    serde_json::from_str(&text).with_context(|| format!("failed to parse {}", path))
+}
+
+fn main() -> Result<()> {
+    let mut args = env::args().skip(1);
+
+    let mut unicode_path: Option<String> = None;
+    let mut jsesh_path: Option<String> = None;
+    let mut output: Option<String> = None;
+    let mut fail_on = Some(Severity::Error);
+
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--unicode" => {
+                unicode_path = Some(args.next().context("missing value for --unicode")?);
+            }
+            "--jsesh" => {
+                jsesh_path = Some(args.next().context("missing value for --jsesh")?);
+            }
+            "--output" => {
+                output = Some(args.next().context("missing value for --output")?);
+            }
+            "--fail-on" => {
+                fail_on = parse_fail_on(&args.next().context("missing value for --fail-on")?)?;
+            }
+            "--verbose" | "-v" => {
+                VERBOSE.store(true, Ordering::Relaxed);
+            }
+            _ => {
+                anyhow::bail!("unknown argument: {}", arg);
+            }
+        }
+    }
+
+    let unicode_path = unicode_path.context(USAGE)?;
+    let jsesh_path = jsesh_path.context(USAGE)?;
+
+    verbose!("reading unicode inventory: {}", unicode_path);
+    let unicode: Vec<UnicodeRecord> = read_json(&unicode_path)?;
+
+    verbose!("reading jsesh inventory: {}", jsesh_path);
+    let jsesh: Vec<JseshRecord> = read_json(&jsesh_path)?;
+
+    let mut findings = Vec::new();
+    duplicate_codes(&unicode, &jsesh, &mut findings);
+    jsesh_family_prefix(&jsesh, &mut findings);
+    unicode_family_subheader(&unicode, &mut findings);
+    codepoint_block(&unicode, &mut findings);
+    empty_annotations(&unicode, &jsesh, &mut findings);
+    family_coverage(&unicode, &jsesh, &mut findings);
+
+    let report = Report::new(findings);
+    let json = serde_json::to_string_pretty(&report)?;
+
+    match &output {
+        Some(path) => {
+            verbose!("writing report: {}", path);
+            std::fs::write(path, json)
+                .with_context(|| format!("failed to write output file: {}", path))?;
+        }
+        None => println!("{}", json),
+    }
+
+    eprintln!(
+        "Lint: {} errors, {} warnings, {} info.",
+        report.summary.error, report.summary.warning, report.summary.info
+    );
+
+    if fail_on.is_some_and(|threshold| report.has_at_least(threshold)) {
+        std::process::exit(1);
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sign(unicode_id: &str, codepoint: u32, subheader: &str) -> UnicodeRecord {
+        UnicodeRecord {
+            unicode_point: format!("U+{:04X}", codepoint),
+            codepoint_dec: codepoint,
+            unicode_id: unicode_id.to_string(),
+            is_format_control: false,
+            subheader: subheader.to_string(),
+            annotations: Vec::new(),
+        }
+    }
+
+    fn jsesh(family: &str, jsesh_code: &str) -> JseshRecord {
+        JseshRecord {
+            family: family.to_string(),
+            family_name: String::new(),
+            jsesh_code: jsesh_code.to_string(),
+        }
+    }
+
+    #[test]
+    fn families_come_from_the_whole_code_prefix() {
+        let unicode = [
+            sign("AA001", 0x1340D, "Aa. Unclassified"),
+            sign("NL001", 0x13331, "NL. Nomes of Lower Egypt"),
+            sign("NU001", 0x1335B, "NU. Nomes of Upper Egypt"),
+            sign("N001", 0x131EF, "N. Sky, earth, water"),
+            sign("HIEROGLYPH-13460", 0x13460, "A01. Man seated or kneeling empty handed"),
+        ];
+        let jsesh = [
+            jsesh("Aa", "Aa1"),
+            jsesh("NL", "NL1"),
+            jsesh("NU", "NU1"),
+            jsesh("N", "N1"),
+        ];
+
+        let mut findings = Vec::new();
+        unicode_family_subheader(&unicode, &mut findings);
+        family_coverage(&unicode, &jsesh, &mut findings);
+        assert!(findings.is_empty(), "{findings:#?}");
+
+        // A sign filed under the wrong subheader is still reported.
+        let misfiled = [sign("NL001", 0x13331, "N. Sky, earth, water")];
+        unicode_family_subheader(&misfiled, &mut findings);
+        assert_eq!(findings.len(), 1);
+        assert_eq!(findings[0].rule, "unicode-family-subheader");
+    }
+}
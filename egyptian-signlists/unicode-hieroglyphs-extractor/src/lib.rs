@@ -0,0 +1,336 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Extract Egyptian hieroglyph records from Unicode's NamesList.txt.
+//!
+//! ```no_run
+//! use std::fs::File;
+//! use std::io::BufReader;
+//! use unicode_hieroglyphs_extractor::parse_names_list;
+//!
+//! let file = File::open("data/unicode/NamesList.txt").unwrap();
+//! for record in parse_names_list(BufReader::new(file)) {
+//!     let record = record.unwrap();
+//!     println!("{} {}", record.unicode_point, record.unicode_id);
+//! }
+//! ```
+
+use std::io::BufRead;
+
+use serde::{Deserialize, Serialize};
+
+use signlist_core::gardiner::{canonical_code, is_format_control_codepoint};
+use signlist_core::importer::{ImportResult, SignListImporter, SignRecord};
+use signlist_core::names_list::{self, Entry, Filter};
+use signlist_core::ucd::Ucd;
+
+pub use signlist_core::names_list::{ParseError, Result};
+
+/// Name prefix shared by every Egyptian hieroglyph and format control.
+pub const NAME_PREFIX: &str = "EGYPTIAN HIEROGLYPH";
+
+/* ============================================================
+ * Data model
+ * ============================================================
+ */
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct HieroglyphUnicode {
+    pub unicode_point: String,
+    pub codepoint_hex: String,
+    pub codepoint_dec: u32,
+    pub char: String,
+    pub unicode_name: String,
+    pub unicode_id: String,
+    pub family: String,
+    pub is_format_control: bool,
+    pub subheader: String,
+    pub annotations: Vec<String>,
+    /// Filled in by [`cross_check`] when a UCD directory is available.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub general_category: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+}
+
+/// A property on which a record and the normative UCD files disagree.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Discrepancy {
+    pub unicode_point: String,
+    pub property: &'static str,
+    /// What the record implies: its NamesList.txt name, "format control"
+    /// or "sign" for the general category, and the Egyptian Hieroglyphs
+    /// script and block for those two.
+    pub expected: String,
+    pub ucd: String,
+}
+
+/// The format controls proper: joiners and the insertion, overlay, segment
+/// and enclosure controls (General_Category Cf). The rest of their block
+/// (mirroring, blanks, lost signs, damage modifiers) are not controls.
+///
+/// Their ids are the last word of the name ("JOINER", "START", "TOP"), so
+/// neither the id nor its family says what they are; the codepoint does.
+fn is_format_control(codepoint: u32) -> bool {
+    (0x13430..=0x1343F).contains(&codepoint)
+}
+
+impl From<Entry> for HieroglyphUnicode {
+    fn from(entry: Entry) -> Self {
+        let hex = format!("{:04X}", entry.codepoint);
+
+        let unicode_id = entry
+            .name
+            .split_whitespace()
+            .last()
+            .unwrap_or_default()
+            .to_string();
+
+        let family = unicode_id
+            .chars()
+            .next()
+            .map(String::from)
+            .unwrap_or_default();
+
+        let ch = char::from_u32(entry.codepoint)
+            .unwrap_or('\u{FFFD}')
+            .to_string();
+
+        let is_format_control = is_format_control(entry.codepoint);
+
+        HieroglyphUnicode {
+            unicode_point: format!("U+{}", hex),
+            codepoint_hex: hex,
+            codepoint_dec: entry.codepoint,
+            char: ch,
+            unicode_name: entry.name,
+            unicode_id,
+            family,
+            is_format_control,
+            subheader: entry.subheader,
+            annotations: entry.comments,
+            general_category: None,
+            script: None,
+        }
+    }
+}
+
+/* ============================================================
+ * Parser
+ * ============================================================
+ */
+
+/// Stream hieroglyph records out of a NamesList.txt reader.
+///
+/// This is [`signlist_core::names_list`] filtered to [`NAME_PREFIX`].
+pub fn parse_names_list<R: BufRead>(reader: R) -> impl Iterator<Item = Result<HieroglyphUnicode>> {
+    let filter = Filter {
+        name_prefixes: vec![NAME_PREFIX.to_string()],
+        ..Filter::default()
+    };
+
+    names_list::entries(reader, filter).map(|entry| entry.map(HieroglyphUnicode::from))
+}
+
+/// The extractor's own output (`hieroglyphs_unicode.json`) as a
+/// [`SignListImporter`], keyed by codepoint. Format controls are not signs
+/// and are left out.
+pub struct UnicodeImporter;
+
+impl UnicodeImporter {
+    /// The sign as a record of the "unicode" list. A sign is described by
+    /// its annotations, or by its character name when it has none.
+    fn sign_record(&self, record: &HieroglyphUnicode) -> SignRecord {
+        let description = if record.annotations.is_empty() {
+            record.unicode_name.clone()
+        } else {
+            record.annotations.join("; ")
+        };
+
+        SignRecord {
+            list: self.list_id().to_string(),
+            code: record.unicode_point.clone(),
+            gardiner: canonical_code(&record.unicode_id).into_iter().collect(),
+            description,
+        }
+    }
+}
+
+impl SignListImporter for UnicodeImporter {
+    fn list_id(&self) -> &str {
+        "unicode"
+    }
+
+    fn import(&self, reader: &mut dyn BufRead) -> ImportResult<Vec<SignRecord>> {
+        let records: Vec<HieroglyphUnicode> = serde_json::from_reader(reader)?;
+        Ok(records
+            .iter()
+            .filter(|r| !r.is_format_control && !is_format_control_codepoint(r.codepoint_dec))
+            .map(|r| self.sign_record(r))
+            .collect())
+    }
+}
+
+/* ============================================================
+ * UCD cross-check
+ * ============================================================
+ */
+
+/// Fill in `general_category` and `script` from the UCD and report every
+/// property on which the record disagrees with it.
+pub fn cross_check(record: &mut HieroglyphUnicode, ucd: &Ucd) -> Vec<Discrepancy> {
+    let cp = record.codepoint_dec;
+    let point = record.unicode_point.clone();
+    let mut found = Vec::new();
+    let mut flag = |property, expected: &str, ucd: &str| {
+        found.push(Discrepancy {
+            unicode_point: point.clone(),
+            property,
+            expected: expected.to_string(),
+            ucd: ucd.to_string(),
+        });
+    };
+
+    match ucd.char_data(cp) {
+        Some(data) => {
+            if let Some(name) = &data.name
+                && *name != record.unicode_name
+            {
+                flag("name", &record.unicode_name, name);
+            }
+
+            // The extractor's format-control flag comes from a codepoint
+            // list; General_Category Cf is the normative answer.
+            let is_cf = data.general_category == "Cf";
+            if is_cf != record.is_format_control {
+                let ours = if record.is_format_control { "format control" } else { "sign" };
+                flag("general_category", ours, &data.general_category);
+            }
+
+            record.general_category = Some(data.general_category);
+        }
+        None => flag("name", &record.unicode_name, "<not in UnicodeData.txt>"),
+    }
+
+    let script = ucd.script(cp).unwrap_or("Unknown");
+    if script != "Egyptian_Hieroglyphs" {
+        flag("script", "Egyptian_Hieroglyphs", script);
+    }
+    record.script = Some(script.to_string());
+
+    let block = ucd.block(cp).unwrap_or("No_Block");
+    if !block.starts_with("Egyptian Hieroglyph") {
+        flag("block", "Egyptian Hieroglyphs", block);
+    }
+
+    found
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NAMES_LIST: &str = "\
+@@\t13000\tEgyptian Hieroglyphs\t1342F
+@\t\tA. Man and his occupations
+13000\tEGYPTIAN HIEROGLYPH A001
+\t* classifier human being
+\t* phonogram : j
+13001\tEGYPTIAN HIEROGLYPH A002
+@@\t13430\tEgyptian Hieroglyph Format Controls\t1345F
+13430\tEGYPTIAN HIEROGLYPH VERTICAL JOINER
+13443\tEGYPTIAN HIEROGLYPH LOST SIGN
+";
+
+    fn records(names_list: &str) -> Vec<HieroglyphUnicode> {
+        parse_names_list(names_list.as_bytes())
+            .collect::<Result<_>>()
+            .unwrap()
+    }
+
+    #[test]
+    fn importer_describes_signs_by_annotations_or_name() {
+        let json = serde_json::to_string(&records(NAMES_LIST)).unwrap();
+        let signs = UnicodeImporter.import(&mut json.as_bytes()).unwrap();
+
+        let described: Vec<(&str, &str)> = signs
+            .iter()
+            .map(|s| (s.code.as_str(), s.description.as_str()))
+            .collect();
+        assert_eq!(
+            described,
+            [
+                ("U+13000", "classifier human being; phonogram : j"),
+                ("U+13001", "EGYPTIAN HIEROGLYPH A002"),
+            ]
+        );
+        assert_eq!(signs[0].gardiner, ["A1"]);
+    }
+
+    /// A UCD directory holding `unicode_data` and fixed Scripts/Blocks.
+    fn load_ucd(unicode_data: &str) -> Ucd {
+        let dir = std::env::temp_dir().join(format!("ucd-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("UnicodeData.txt"), unicode_data).unwrap();
+        std::fs::write(dir.join("Scripts.txt"), "13000..13455 ; Egyptian_Hieroglyphs\n").unwrap();
+        std::fs::write(
+            dir.join("Blocks.txt"),
+            "13000..1342F; Egyptian Hieroglyphs\n\
+             13430..1345F; Egyptian Hieroglyph Format Controls\n",
+        )
+        .unwrap();
+        let ucd = Ucd::load(&dir).unwrap();
+        std::fs::remove_dir_all(&dir).unwrap();
+        ucd
+    }
+
+    #[test]
+    fn cross_check_agrees_on_controls_and_reports_discrepancies() {
+        let mut records = records(NAMES_LIST);
+        let controls: Vec<bool> = records.iter().map(|r| r.is_format_control).collect();
+        assert_eq!(controls, [false, false, true, false]);
+
+        let ucd = load_ucd("\
+13000;EGYPTIAN HIEROGLYPH A001;Lo;0;L;;;;;N;;;;;
+13001;EGYPTIAN HIEROGLYPH A002;Lo;0;L;;;;;N;;;;;
+13430;EGYPTIAN HIEROGLYPH VERTICAL JOINER;Cf;0;L;;;;;N;;;;;
+13443;EGYPTIAN HIEROGLYPH LOST SIGN;Lo;0;L;;;;;N;;;;;
+");
+        for record in &mut records {
+            assert_eq!(cross_check(record, &ucd), [], "{}", record.unicode_point);
+        }
+        assert_eq!(records[2].general_category.as_deref(), Some("Cf"));
+
+        let ucd = load_ucd("\
+13000;EGYPTIAN HIEROGLYPH A001;Lo;0;L;;;;;N;;;;;
+13430;EGYPTIAN HIEROGLYPH VERTICAL JOINER;Lo;0;L;;;;;N;;;;;
+");
+        assert_eq!(
+            cross_check(&mut records[2], &ucd),
+            [Discrepancy {
+                unicode_point: "U+13430".to_string(),
+                property: "general_category",
+                expected: "format control".to_string(),
+                ucd: "Lo".to_string(),
+            }]
+        );
+        assert_eq!(cross_check(&mut records[1], &ucd)[0].ucd, "<not in UnicodeData.txt>");
+
+        // Outside the fixture's Scripts and Blocks ranges, `expected` is
+        // what the extractor assumes for every record.
+        let mut stray = records[1].clone();
+        stray.codepoint_dec = 0x13460;
+        let found: Vec<(&str, String, String)> = cross_check(&mut stray, &ucd)
+            .into_iter()
+            .map(|d| (d.property, d.expected, d.ucd))
+            .collect();
+        assert_eq!(
+            found[1..],
+            [
+                ("script", "Egyptian_Hieroglyphs".to_string(), "Unknown".to_string()),
+                ("block", "Egyptian Hieroglyphs".to_string(), "No_Block".to_string()),
+            ]
+        );
+        assert_eq!(stray.script.as_deref(), Some("Unknown"));
+    }
+}
@@ -1,46 +1,23 @@
 use std::env;
 use std::fs::File;
-use std::io::{BufRead, BufReader};
+use std::io::BufReader;
+use std::path::Path;
 use std::sync::atomic::Ordering;
 
 use anyhow::{Context, Result};
-use serde::Serialize;
 
 // Pull in shared infrastructure
 use signlist_core::{VERBOSE};
 use signlist_core::verbose;
 use signlist_core::stderr_is_tty;
+use signlist_core::warn;
+use signlist_core::ucd::Ucd;
 
-/* ============================================================
- * Data model
- * ============================================================
- */
+use unicode_hieroglyphs_extractor::{cross_check, parse_names_list, Discrepancy, HieroglyphUnicode};
 
-#[derive(Debug, Serialize)]
-struct HieroglyphUnicode {
-    unicode_point: String,
-    codepoint_hex: String,
-    codepoint_dec: u32,
-    char: String,
-    unicode_name: String,
-    unicode_id: String,
-    family: String,
-    is_format_control: bool,
-}
-
-fn is_format_control(name: &str, family: &str) -> bool {
-    family == "H" && (
-        name.contains("JOINER")
-            || name.contains("INSERT")
-            || name.contains("BEGIN")
-            || name.contains("END")
-            || name.contains("MIRROR")
-            || name.contains("OVERLAY")
-            || name.contains("ENCLOSURE")
-            || name.contains("SEGMENT")
-            || name.contains("BLANK")
-    )
-}
+const USAGE: &str =
+    "usage: unicode-hieroglyphs-extractor --input <file> --output <file> \
+     [--ucd <dir> [--report <file>] [--strict]] [--verbose]";
 
 /* ============================================================
  * Main
@@ -52,6 +29,9 @@ fn main() -> Result<()> {
 
     let mut input: Option<String> = None;
     let mut output: Option<String> = None;
+    let mut ucd_dir: Option<String> = None;
+    let mut report: Option<String> = None;
+    let mut strict = false;
 
     while let Some(arg) = args.next() {
         match arg.as_str() {
@@ -65,6 +45,19 @@ fn main() -> Result<()> {
                     args.next().context("missing value for --output")?
                 );
             }
+            "--ucd" => {
+                ucd_dir = Some(
+                    args.next().context("missing value for --ucd")?
+                );
+            }
+            "--report" => {
+                report = Some(
+                    args.next().context("missing value for --report")?
+                );
+            }
+            "--strict" => {
+                strict = true;
+            }
             "--verbose" | "-v" => {
                 VERBOSE.store(true, Ordering::Relaxed);
             }
@@ -74,13 +67,12 @@ fn main() -> Result<()> {
         }
     }
 
-    let input = input.context(
-        "usage: unicode-hieroglyphs-extractor --input <file> --output <file> [--verbose]"
-    )?;
+    let input = input.context(USAGE)?;
+    let output = output.context(USAGE)?;
 
-    let output = output.context(
-        "usage: unicode-hieroglyphs-extractor --input <file> --output <file> [--verbose]"
-    )?;
+    if ucd_dir.is_none() && (report.is_some() || strict) {
+        anyhow::bail!("--report and --strict need --ucd <dir> to check against");
+    }
 
     verbose!("reading input file: {}", input);
     verbose!("writing output file: {}", output);
@@ -91,58 +83,47 @@ fn main() -> Result<()> {
     let reader = BufReader::new(file);
     let mut records: Vec<HieroglyphUnicode> = Vec::new();
 
-    for (line_no, line) in reader.lines().enumerate() {
-        let line = line
-            .with_context(|| format!("failed reading line {}", line_no + 1))?;
+    for record in parse_names_list(reader) {
+        let record = record.with_context(|| format!("failed to parse {}", input))?;
 
-        if !line.contains("EGYPTIAN HIEROGLYPH") {
-            continue;
+        verbose!("{} {}", record.unicode_point, record.unicode_name);
+
+        records.push(record);
+    }
+
+    let mut discrepancies: Vec<Discrepancy> = Vec::new();
+
+    if let Some(dir) = &ucd_dir {
+        verbose!("cross-checking against UCD in: {}", dir);
+
+        let ucd = Ucd::load(Path::new(dir))
+            .with_context(|| format!("failed to load UCD from {}", dir))?;
+
+        for record in &mut records {
+            for d in cross_check(record, &ucd) {
+                warn!(
+                    "{} {}: expected {:?}, UCD says {:?}",
+                    (d.unicode_point),
+                    (d.property),
+                    (d.expected),
+                    (d.ucd)
+                );
+                discrepancies.push(d);
+            }
         }
 
-        let mut parts = line.split_whitespace();
-        let hex = parts.next().unwrap();
-        let unicode_name = parts.collect::<Vec<_>>().join(" ");
-
-        let unicode_id = unicode_name
-            .split_whitespace()
-            .last()
-            .unwrap()
-            .to_string();
-
-        let family = unicode_id
-            .chars()
-            .next()
-            .unwrap()
-            .to_string();
-
-        let codepoint_dec =
-            u32::from_str_radix(hex, 16)
-                .with_context(|| format!("invalid hex codepoint: {}", hex))?;
-
-        let ch = char::from_u32(codepoint_dec)
-            .unwrap_or('\u{FFFD}')
-            .to_string();
-
-        let is_format_control =
-            is_format_control(&unicode_name, &family);
-
-        verbose!(
-            "line {}: {} {}",
-            line_no + 1,
-            unicode_id,
-            unicode_name
+        println!(
+            "Cross-checked {} records against the UCD: {} discrepancies.",
+            records.len(),
+            discrepancies.len()
         );
 
-        records.push(HieroglyphUnicode {
-            unicode_point: format!("U+{}", hex),
-            codepoint_hex: hex.to_string(),
-            codepoint_dec,
-            char: ch,
-            unicode_name,
-            unicode_id,
-            family,
-            is_format_control,
-        });
+        if let Some(path) = &report {
+            verbose!("writing discrepancy report: {}", path);
+            let json = serde_json::to_string_pretty(&discrepancies)?;
+            std::fs::write(path, json)
+                .with_context(|| format!("failed to write report file: {}", path))?;
+        }
     }
 
     let json = serde_json::to_string_pretty(&records)?;
@@ -154,6 +135,11 @@ fn main() -> Result<()> {
         records.len()
     );
 
+    // The inventory is still written: --strict only gates the exit status.
+    if strict && !discrepancies.is_empty() {
+        std::process::exit(1);
+    }
+
     Ok(())
 }
 
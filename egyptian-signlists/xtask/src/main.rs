@@ -1,15 +1,24 @@
 use anyhow::{bail, Context, Result};
 use std::fs;
+use std::path::Path;
 use std::process::Command;
 
+mod watch;
+
 fn main() -> Result<()> {
     let mut args = std::env::args().skip(1);
 
     match args.next().as_deref() {
         Some("setup") => setup(),
+        Some("lint") => lint(),
+        Some("verify") => verify(),
+        Some("watch") => watch::watch(),
         _ => {
             eprintln!("Usage:");
             eprintln!("  cargo xtask setup");
+            eprintln!("  cargo xtask lint");
+            eprintln!("  cargo xtask verify");
+            eprintln!("  cargo xtask watch");
             bail!("unknown xtask command");
         }
     }
@@ -28,6 +37,29 @@ fn setup() -> Result<()> {
     Ok(())
 }
 
+/// Run the consistency lints and write `reports/lint.json` without gating.
+fn lint() -> Result<()> {
+    println!("== egyptian-signlists lint ==");
+
+    ensure_dirs()?;
+    build_binaries()?;
+    run_lint_inputs()?;
+    run_lint("none")
+}
+
+/// Run the consistency lints and fail if any error-severity finding exists.
+fn verify() -> Result<()> {
+    println!("== egyptian-signlists verify ==");
+
+    ensure_dirs()?;
+    build_binaries()?;
+    run_lint_inputs()?;
+    run_lint("error")?;
+
+    println!("== verify passed ==");
+    Ok(())
+}
+
 fn ensure_dirs() -> Result<()> {
     for dir in ["generated", "reports", "dist"] {
         fs::create_dir_all(dir)
@@ -46,6 +78,8 @@ fn build_binaries() -> Result<()> {
             "-p", "unicode-hieroglyphs-extractor",
             "-p", "gardiner-signlist-extractor",
             "-p", "signlist-correlator",
+            "-p", "signlist-csv-importer",
+            "-p", "signlist-lint",
         ])
         .status()
         .context("failed to run cargo build")?;
@@ -58,26 +92,86 @@ fn build_binaries() -> Result<()> {
 }
 
 fn run_extractors() -> Result<()> {
+    run_unicode_extractor()?;
+    run_gardiner_extractor()?;
+    run_importers()
+}
+
+fn run_unicode_extractor() -> Result<()> {
     println!("running unicode extractor");
 
-    run_bin(
-        "unicode-hieroglyphs-extractor",
-        &[
-            "--input", "data/unicode/NamesList.txt",
-            "--output", "generated/hieroglyphs_unicode.json",
-        ],
-    )?;
+    let mut args = vec![
+        "--input", "data/unicode/NamesList.txt",
+        "--output", "generated/hieroglyphs_unicode.json",
+    ];
+
+    // Cross-check against the normative UCD files when they have been
+    // downloaded next to NamesList.txt.
+    if Path::new("data/unicode/UnicodeData.txt").exists() {
+        args.extend(["--ucd", "data/unicode", "--report", "reports/ucd.json"]);
+    }
+
+    run_bin("unicode-hieroglyphs-extractor", &args)
+}
 
+fn run_gardiner_extractor() -> Result<()> {
     println!("running gardiner extractor");
 
+    // The one input that is not checked in: it is the pdftotext output of
+    // the JSesh catalogue (see docs/README.md).
+    if !Path::new("generated/jsesh.txt").exists() {
+        bail!(
+            "generated/jsesh.txt is missing; create it with \
+             `pdftotext -layout jsesh-catalog-7.5.5.pdf generated/jsesh.txt`"
+        );
+    }
+
     run_bin(
         "gardiner-signlist-extractor",
         &[
             "--input", "generated/jsesh.txt",
             "--output", "generated/jsesh_inventory.json",
         ],
-    )?;
+    )
+}
+
+/// Extra sign lists: every `data/signlists/<id>.csv` is imported as list
+/// `<id>` into `generated/signs_<id>.json`.
+fn sign_lists() -> Result<Vec<String>> {
+    let dir = Path::new("data/signlists");
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut ids = Vec::new();
+    for entry in fs::read_dir(dir).context("failed to read data/signlists")? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "csv")
+            && let Some(stem) = path.file_stem().and_then(|s| s.to_str())
+        {
+            ids.push(stem.to_string());
+        }
+    }
+    ids.sort();
+    Ok(ids)
+}
+
+fn run_importers() -> Result<()> {
+    for id in sign_lists()? {
+        println!("importing sign list {}", id);
+
+        let input = format!("data/signlists/{}.csv", id);
+        let output = format!("generated/signs_{}.json", id);
 
+        run_bin(
+            "signlist-csv-importer",
+            &[
+                "--list-id", &id,
+                "--input", &input,
+                "--output", &output,
+            ],
+        )?;
+    }
     Ok(())
 }
 
@@ -100,13 +194,43 @@ fn run_bin(name: &str, args: &[&str]) -> Result<()> {
 fn run_correlator() -> Result<()> {
     println!("running correlator");
 
+    let lists: Vec<String> = sign_lists()?
+        .iter()
+        .map(|id| format!("generated/signs_{}.json", id))
+        .collect();
+
+    let mut args = vec![
+        "--unicode", "generated/hieroglyphs_unicode.json",
+        "--jsesh", "generated/jsesh_inventory.json",
+        "--output", "generated/signlist_merged.json",
+        "--report", "reports/orphans.json",
+        "--concordance", "generated/concordance.json",
+    ];
+    for list in &lists {
+        args.extend(["--list", list.as_str()]);
+    }
+
+    run_bin("signlist-correlator", &args)
+}
+
+
+/// Regenerate the inventories the lint reads, so it never checks files
+/// older than `data/` or left over from an earlier layout.
+fn run_lint_inputs() -> Result<()> {
+    run_unicode_extractor()?;
+    run_gardiner_extractor()
+}
+
+fn run_lint(fail_on: &str) -> Result<()> {
+    println!("running lint");
+
     run_bin(
-        "signlist-correlator",
+        "signlist-lint",
         &[
             "--unicode", "generated/hieroglyphs_unicode.json",
             "--jsesh", "generated/jsesh_inventory.json",
-            "--output", "generated/signlist_merged.json",
-            "--report", "reports/orphans.json",
+            "--output", "reports/lint.json",
+            "--fail-on", fail_on,
         ],
     )
 }
@@ -119,6 +243,8 @@ fn copy_binaries() -> Result<()> {
         "unicode-hieroglyphs-extractor",
         "gardiner-signlist-extractor",
         "signlist-correlator",
+        "signlist-csv-importer",
+        "signlist-lint",
     ] {
         let src = format!("target/release/{}", bin);
         let dst = format!("dist/{}", bin);
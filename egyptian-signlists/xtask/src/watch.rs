@@ -0,0 +1,323 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! `cargo xtask watch`: rerun the parts of the pipeline whose inputs changed.
+//!
+//! Watches `data/` (recursively) and `generated/jsesh.txt` with inotify.
+//! A change under `data/unicode/` reruns the Unicode extractor, a change to
+//! `generated/jsesh.txt` reruns the Gardiner extractor, a change under
+//! `data/signlists/` reruns the CSV importers, and anything else under
+//! `data/` reruns all of them. The correlator always runs afterwards.
+//! Directories created under `data/` while watching are watched too.
+
+use anyhow::{Context, Result};
+use inotify::{Event, EventMask, Inotify, WatchDescriptor, WatchMask, Watches};
+use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::Duration;
+
+/// Editors write in bursts; wait this long for the burst to settle.
+const SETTLE: Duration = Duration::from_millis(250);
+
+const MASK: WatchMask = WatchMask::CLOSE_WRITE
+    .union(WatchMask::MOVED_TO)
+    .union(WatchMask::CREATE)
+    .union(WatchMask::DELETE);
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct Affected {
+    unicode: bool,
+    gardiner: bool,
+    importers: bool,
+}
+
+/// Record and orphan counts read back from the generated artifacts.
+#[derive(Debug, Default, Clone, Copy)]
+struct Counts {
+    unicode_records: usize,
+    jsesh_records: usize,
+    unicode_orphans: usize,
+    jsesh_orphans: usize,
+}
+
+pub fn watch() -> Result<()> {
+    println!("== egyptian-signlists watch ==");
+
+    super::ensure_dirs()?;
+    super::build_binaries()?;
+    super::run_extractors()?;
+    super::run_correlator()?;
+
+    let mut counts = read_counts();
+    print_summary(&counts, &counts);
+
+    let mut inotify = Inotify::init().context("failed to initialise inotify")?;
+
+    let mut dirs: HashMap<WatchDescriptor, PathBuf> = HashMap::new();
+    watch_tree(&mut inotify.watches(), Path::new("data"), &mut dirs)?;
+
+    // Watch the directory rather than the file: editors that save by
+    // rename would otherwise silently drop the watch.
+    let wd = inotify
+        .watches()
+        .add("generated", MASK)
+        .context("failed to watch generated/")?;
+    dirs.insert(wd, PathBuf::from("generated"));
+
+    println!("watching data/ and generated/jsesh.txt (Ctrl-C to stop)");
+
+    let mut buffer = [0u8; 4096];
+    loop {
+        let mut affected = Affected::default();
+
+        let events = inotify
+            .read_events_blocking(&mut buffer)
+            .context("failed to read inotify events")?;
+        for event in events {
+            handle(event, &mut inotify.watches(), &mut dirs, &mut affected);
+        }
+
+        // Drain the rest of the burst before rebuilding anything.
+        thread::sleep(SETTLE);
+        loop {
+            match inotify.read_events(&mut buffer) {
+                Ok(events) => {
+                    for event in events {
+                        handle(event, &mut inotify.watches(), &mut dirs, &mut affected);
+                    }
+                }
+                Err(e) if e.kind() == ErrorKind::WouldBlock => break,
+                Err(e) => return Err(e).context("failed to read inotify events"),
+            }
+        }
+
+        if !affected.unicode && !affected.gardiner && !affected.importers {
+            continue;
+        }
+
+        if let Err(e) = rerun(affected) {
+            eprintln!("[watch] run failed: {:#}", e);
+            continue;
+        }
+
+        let latest = read_counts();
+        print_summary(&counts, &latest);
+        counts = latest;
+    }
+}
+
+/// Watch `root` and every directory below it.
+fn watch_tree(
+    watches: &mut Watches,
+    root: &Path,
+    dirs: &mut HashMap<WatchDescriptor, PathBuf>,
+) -> Result<()> {
+    for dir in data_dirs(root)? {
+        let wd = watches
+            .add(&dir, MASK)
+            .with_context(|| format!("failed to watch {}", dir.display()))?;
+        dirs.insert(wd, dir);
+    }
+    Ok(())
+}
+
+/// Note what an event affects. A directory created or moved in under
+/// `data/` gets watched, with whatever it already holds.
+fn handle(
+    event: Event<&OsStr>,
+    watches: &mut Watches,
+    dirs: &mut HashMap<WatchDescriptor, PathBuf>,
+    affected: &mut Affected,
+) {
+    let (Some(dir), Some(name)) = (dirs.get(&event.wd), event.name) else {
+        return;
+    };
+    let path = dir.join(name);
+
+    let new_dir = event.mask.contains(EventMask::ISDIR)
+        && event.mask.intersects(EventMask::CREATE | EventMask::MOVED_TO);
+    if new_dir && path.starts_with("data") {
+        // It may already be gone again; that is not worth stopping for.
+        if let Err(e) = watch_tree(watches, &path, dirs) {
+            eprintln!("[watch] {:#}", e);
+        }
+    }
+
+    classify(&path, affected);
+}
+
+/// `root` and every directory below it.
+fn data_dirs(root: &Path) -> Result<Vec<PathBuf>> {
+    let mut out = vec![root.to_path_buf()];
+    let mut i = 0;
+    while i < out.len() {
+        let entries = fs::read_dir(&out[i])
+            .with_context(|| format!("failed to read {}", out[i].display()))?;
+        for entry in entries {
+            let path = entry?.path();
+            if path.is_dir() {
+                out.push(path);
+            }
+        }
+        i += 1;
+    }
+    Ok(out)
+}
+
+fn classify(path: &Path, affected: &mut Affected) {
+    let name = path
+        .file_name()
+        .and_then(|n| n.to_str())
+        .unwrap_or_default();
+
+    // Editor swap and backup files are noise.
+    if name.starts_with('.') || name.ends_with('~') || name.ends_with(".swp") {
+        return;
+    }
+
+    if path.starts_with("generated") {
+        if name == "jsesh.txt" {
+            affected.gardiner = true;
+        }
+    } else if path.starts_with("data/unicode") {
+        affected.unicode = true;
+    } else if path.starts_with("data/signlists") {
+        affected.importers = true;
+    } else if path.starts_with("data") {
+        affected.unicode = true;
+        affected.gardiner = true;
+        affected.importers = true;
+    }
+}
+
+fn rerun(affected: Affected) -> Result<()> {
+    println!();
+    if affected.unicode {
+        super::run_unicode_extractor()?;
+    }
+    if affected.gardiner {
+        super::run_gardiner_extractor()?;
+    }
+    if affected.importers {
+        super::run_importers()?;
+    }
+    super::run_correlator()
+}
+
+fn json_len(path: &str, field: Option<&str>) -> usize {
+    let Ok(text) = fs::read_to_string(path) else {
+        return 0;
+    };
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(&text) else {
+        return 0;
+    };
+    let value = match field {
+        Some(f) => &value[f],
+        None => &value,
+    };
+    value.as_array().map(|a| a.len()).unwrap_or(0)
+}
+
+fn read_counts() -> Counts {
+    Counts {
+        unicode_records: json_len("generated/hieroglyphs_unicode.json", None),
+        jsesh_records: json_len("generated/jsesh_inventory.json", None),
+        unicode_orphans: json_len("reports/orphans.json", Some("unicode_only")),
+        jsesh_orphans: json_len("reports/orphans.json", Some("jsesh_only")),
+    }
+}
+
+fn print_summary(before: &Counts, after: &Counts) {
+    let row = |label: &str, old: usize, new: usize| {
+        let delta = new as i64 - old as i64;
+        println!("  {:<18} {:>6} ({:+})", label, new, delta);
+    };
+
+    println!("summary:");
+    row("unicode records", before.unicode_records, after.unicode_records);
+    row("jsesh records", before.jsesh_records, after.jsesh_records);
+    row("unicode orphans", before.unicode_orphans, after.unicode_orphans);
+    row("jsesh orphans", before.jsesh_orphans, after.jsesh_orphans);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn affected(paths: &[&str]) -> Affected {
+        let mut affected = Affected::default();
+        for path in paths {
+            classify(Path::new(path), &mut affected);
+        }
+        affected
+    }
+
+    const NOTHING: Affected = Affected {
+        unicode: false,
+        gardiner: false,
+        importers: false,
+    };
+    const EVERYTHING: Affected = Affected {
+        unicode: true,
+        gardiner: true,
+        importers: true,
+    };
+
+    #[test]
+    fn each_input_reruns_its_own_step() {
+        let unicode = Affected {
+            unicode: true,
+            ..NOTHING
+        };
+        let gardiner = Affected {
+            gardiner: true,
+            ..NOTHING
+        };
+        let importers = Affected {
+            importers: true,
+            ..NOTHING
+        };
+
+        assert_eq!(affected(&["data/unicode/NamesList.txt"]), unicode);
+        assert_eq!(affected(&["data/unicode/16.0/UnicodeData.txt"]), unicode);
+        assert_eq!(affected(&["generated/jsesh.txt"]), gardiner);
+        assert_eq!(affected(&["data/signlists/moller.csv"]), importers);
+        assert_eq!(affected(&["data/signlists/new/list.csv"]), importers);
+    }
+
+    #[test]
+    fn other_data_reruns_everything() {
+        assert_eq!(affected(&["data/README.md"]), EVERYTHING);
+        assert_eq!(affected(&["data/fonts/extra.txt"]), EVERYTHING);
+        // A directory beside `unicode/` that only shares its prefix.
+        assert_eq!(affected(&["data/unicode-old/NamesList.txt"]), EVERYTHING);
+    }
+
+    #[test]
+    fn steps_accumulate_over_a_burst() {
+        let both = Affected {
+            unicode: true,
+            importers: true,
+            ..NOTHING
+        };
+        assert_eq!(
+            affected(&["data/unicode/NamesList.txt", "data/signlists/moller.csv"]),
+            both
+        );
+    }
+
+    #[test]
+    fn noise_and_other_generated_files_are_ignored() {
+        assert_eq!(affected(&["generated/jsesh_inventory.json"]), NOTHING);
+        assert_eq!(affected(&["generated/correlation.json"]), NOTHING);
+        assert_eq!(affected(&["data/unicode/.NamesList.txt.swp"]), NOTHING);
+        assert_eq!(affected(&["data/signlists/moller.csv~"]), NOTHING);
+        assert_eq!(affected(&["data/signlists/4913.swp"]), NOTHING);
+        assert_eq!(affected(&["data/.hidden"]), NOTHING);
+        assert_eq!(affected(&["reports/orphans.json"]), NOTHING);
+    }
+}
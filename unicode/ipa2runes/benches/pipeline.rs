@@ -0,0 +1,108 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Times the pipeline on a quarter of the bundled dictionary, some 30,000
+//! pronunciations, as the table grows to thousands of rules: the trie
+//! tokenizer against a scan of every rule at each position, the indexed
+//! rule lookup against a scan of the rules, and the whole transliteration.
+//!
+//!     cargo bench --bench pipeline
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use ipa2runes::table::TokenRule;
+use ipa2runes::text::arpabet_to_ipa;
+use ipa2runes::{MappingTable, Options, Tokenizer, Transliterator, ipa, normalize_ipa};
+
+/// The futhorc table with `extra` more rules, two chart symbols each.
+fn big_table(extra: usize) -> MappingTable {
+    let mut table = MappingTable::builtin("futhorc").unwrap();
+    let template = table.rules()[0].clone();
+    let pairs = ipa::CHART.iter().flat_map(|a| {
+        ipa::CHART
+            .iter()
+            .map(move |b| format!("{}{}", a.ipa, b.ipa))
+    });
+    for ipa in pairs.take(extra) {
+        table.set_rule(TokenRule {
+            ipa,
+            ..template.clone()
+        });
+    }
+    table
+}
+
+/// Tokens of `input` as the tokenizer found them before the trie: every
+/// rule and chart symbol tried at each position.
+fn linear_tokens(table: &MappingTable, input: &str) -> usize {
+    let mut count = 0;
+    let mut at = 0;
+    while at < input.len() {
+        let rest = &input[at..];
+        let rules = table
+            .rules()
+            .iter()
+            .filter_map(|r| ipa::match_len(rest, &r.ipa));
+        let chart = ipa::CHART
+            .iter()
+            .filter_map(|s| ipa::match_len(rest, s.ipa));
+        at += rules
+            .chain(chart)
+            .max()
+            .unwrap_or_else(|| rest.chars().next().unwrap().len_utf8());
+        count += 1;
+    }
+    count
+}
+
+fn time(f: impl FnOnce() -> usize) -> Duration {
+    let started = Instant::now();
+    black_box(f());
+    started.elapsed()
+}
+
+fn main() {
+    let inputs: Vec<String> = include_str!("../dict/cmudict.dict")
+        .lines()
+        .step_by(4)
+        .filter_map(|line| arpabet_to_ipa(line.split_once(' ')?.1))
+        .map(|ipa| normalize_ipa(&ipa))
+        .collect();
+    let chars: usize = inputs.iter().map(|s| s.chars().count()).sum();
+    println!("{} pronunciations, {chars} chars", inputs.len());
+
+    for extra in [0, 1000, 5000] {
+        let table = big_table(extra);
+        let tokenizer = Tokenizer::new(&table);
+        let keys: Vec<String> = inputs
+            .iter()
+            .flat_map(|input| tokenizer.tokenize(input))
+            .map(|t| t.key)
+            .collect();
+
+        let trie = time(|| inputs.iter().map(|s| tokenizer.tokenize(s).len()).sum());
+        let linear = time(|| inputs.iter().map(|s| linear_tokens(&table, s)).sum());
+        let indexed = time(|| keys.iter().filter(|k| table.rule(k).is_some()).count());
+        let scanned = time(|| {
+            keys.iter()
+                .filter(|k| table.rules().iter().any(|r| &r.ipa == *k))
+                .count()
+        });
+        let transliterator = Transliterator::new(Options {
+            table: table.clone(),
+            ..Options::default()
+        });
+        let whole = time(|| {
+            inputs
+                .iter()
+                .map(|s| transliterator.transliterate(s).tokens.len())
+                .sum()
+        });
+
+        println!("{:>5} rules:", table.rules().len());
+        println!("  tokenize     trie {trie:>12.2?}   linear scan {linear:>12.2?}");
+        println!("  rule lookup  index {indexed:>11.2?}   linear scan {scanned:>12.2?}");
+        println!("  transliterate, end to end {whole:>12.2?}");
+    }
+}
@@ -0,0 +1,515 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Command-line options: parsed and checked against each other here, so
+//! `main` only loads what they name and runs it.
+
+use std::io::{self, Write};
+
+use ipa2runes::svg::{Direction, Drawing};
+use ipa2runes::{
+    Diacritics, Layout, MappingTable, Mode, Orientation, Profile, Punctuation, Separator,
+};
+
+/// What the command line asks for.
+#[derive(Debug)]
+pub(crate) enum Command {
+    Help,
+    Run(Args),
+}
+
+/// Where the input comes from.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum Input {
+    /// One input given as arguments.
+    Arg(String),
+    /// Newline-delimited inputs from a file, or from stdin for `None`.
+    Lines(Option<String>),
+    /// An interactive session on stdin.
+    Repl,
+}
+
+/// A command line that makes sense: every value parsed, every name known,
+/// no two options in conflict.
+#[derive(Debug)]
+pub(crate) struct Args {
+    pub(crate) input: Input,
+    pub(crate) verbose: bool,
+    pub(crate) text: bool,
+    pub(crate) reverse: bool,
+    pub(crate) json: bool,
+    pub(crate) plain: bool,
+    /// A built-in table; `None` for the default, or every table under
+    /// `--reverse`.
+    pub(crate) alphabet: Option<String>,
+    pub(crate) mapping: Option<String>,
+    pub(crate) mode: Mode,
+    pub(crate) diacritics: Diacritics,
+    pub(crate) layout: Layout,
+    /// A built-in profile name or a `.toml` file.
+    pub(crate) profile: Option<String>,
+    pub(crate) alternatives: Option<usize>,
+    pub(crate) svg: Option<String>,
+    pub(crate) drawing: Drawing,
+}
+
+/// Why a command line was rejected; either way the exit status is 2.
+#[derive(Debug, PartialEq, Eq)]
+pub(crate) enum ArgError {
+    /// A missing or unknown value: the message, if any, then the usage.
+    Usage(Option<String>),
+    /// Options that cannot be combined: the message alone.
+    Conflict(String),
+}
+
+fn unknown(message: String) -> ArgError {
+    ArgError::Usage(Some(message))
+}
+
+fn both(a: &str, b: &str) -> ArgError {
+    ArgError::Conflict(format!("{a} and {b} cannot be combined."))
+}
+
+fn conflict(a: &str, with: &str) -> ArgError {
+    ArgError::Conflict(format!("{a} cannot be combined with {with}."))
+}
+
+/// `value` as one of the names `parse` knows.
+fn named<T>(value: &str, what: &str, parse: fn(&str) -> Option<T>) -> Result<T, ArgError> {
+    parse(value).ok_or_else(|| unknown(format!("unknown {what} {value:?}.")))
+}
+
+fn positive(option: &str, value: &str) -> Result<usize, ArgError> {
+    value
+        .parse()
+        .ok()
+        .filter(|&n: &usize| n > 0)
+        .ok_or_else(|| unknown(format!("{option} needs a positive count, not {value:?}.")))
+}
+
+/// Parse the arguments after the program name. Without an input argument
+/// or `--input`, a piped stdin is read line by line; a terminal is not.
+pub(crate) fn parse(
+    args: impl IntoIterator<Item = String>,
+    stdin_is_terminal: bool,
+) -> Result<Command, ArgError> {
+    let mut verbose = false;
+    let mut text = false;
+    let mut json = false;
+    let mut plain = false;
+    let mut reverse = false;
+    let mut repl = false;
+    let mut layout = Layout::default();
+    let mut mode = Mode::Pragmatic;
+    let mut diacritics = Diacritics::Strip;
+    let mut alphabet: Option<String> = None;
+    let mut mapping: Option<String> = None;
+    let mut input_path: Option<String> = None;
+    let mut alternatives: Option<usize> = None;
+    let mut profile: Option<String> = None;
+    let mut svg: Option<String> = None;
+    let mut drawing = Drawing::default();
+    let mut ipa: Option<String> = None;
+
+    let mut args = args.into_iter();
+    while let Some(a) = args.next() {
+        let mut value = || args.next().ok_or(ArgError::Usage(None));
+        if a == "--verbose" || a == "-v" {
+            verbose = true;
+        } else if a == "--mode" {
+            mode = named(&value()?, "mode", Mode::parse)?;
+        } else if a == "--separator" {
+            layout.separator = named(&value()?, "separator", Separator::parse)?;
+        } else if a == "--punctuation" {
+            layout.punctuation = named(&value()?, "punctuation mode", Punctuation::parse)?;
+        } else if a == "--orientation" {
+            layout.orientation = named(&value()?, "orientation", Orientation::parse)?;
+        } else if a == "--diacritics" {
+            diacritics = named(&value()?, "diacritics policy", Diacritics::parse)?;
+        } else if a == "--alternatives" {
+            alternatives = Some(positive("--alternatives", &value()?)?);
+        } else if a == "--svg" {
+            svg = Some(value()?);
+        } else if a == "--direction" {
+            drawing.direction = named(&value()?, "direction", Direction::parse)?;
+        } else if a == "--wrap" {
+            drawing.wrap = positive("--wrap", &value()?)?;
+        } else if a == "--bind" {
+            drawing.bind = true;
+        } else if a == "--json" {
+            json = true;
+        } else if a == "--plain" {
+            plain = true;
+        } else if a == "--repl" {
+            repl = true;
+        } else if a == "--reverse" {
+            reverse = true;
+        } else if a == "--text" {
+            text = true;
+        } else if a == "--input" {
+            input_path = Some(value()?);
+        } else if a == "--profile" {
+            profile = Some(value()?);
+        } else if a == "--alphabet" {
+            alphabet = Some(value()?);
+        } else if a == "--mapping" {
+            mapping = Some(value()?);
+        } else if a == "--help" || a == "-h" {
+            return Ok(Command::Help);
+        } else if a.starts_with('-') && a != "-" {
+            return Err(unknown(format!("unknown option {a:?}.")));
+        } else {
+            // Several input arguments are one input, joined with spaces.
+            ipa = Some(match ipa {
+                Some(ipa) => format!("{ipa} {a}"),
+                None => a,
+            });
+        }
+    }
+
+    if reverse && text {
+        return Err(both("--reverse", "--text"));
+    }
+    if alternatives.is_some() && reverse {
+        return Err(both("--alternatives", "--reverse"));
+    }
+    if alternatives.is_some() && layout.orientation == Orientation::Vertical {
+        return Err(both("--alternatives", "--orientation vertical"));
+    }
+
+    let input = match (repl, input_path, ipa) {
+        (true, input_path, ipa) => {
+            let other = if reverse {
+                Some("--reverse")
+            } else if alternatives.is_some() {
+                Some("--alternatives")
+            } else if svg.is_some() {
+                Some("--svg")
+            } else if json {
+                Some("--json")
+            } else if input_path.is_some() || ipa.is_some() {
+                Some("an input")
+            } else {
+                None
+            };
+            if let Some(other) = other {
+                return Err(conflict("--repl", other));
+            }
+            Input::Repl
+        }
+        (false, Some(_), Some(_)) => return Err(both("--input", "an input argument")),
+        (false, Some(path), None) => Input::Lines((path != "-").then_some(path)),
+        (false, None, Some(ipa)) => Input::Arg(ipa),
+        (false, None, None) if !stdin_is_terminal => Input::Lines(None),
+        (false, None, None) => return Err(ArgError::Usage(None)),
+    };
+
+    if let Some(path) = &svg {
+        let other = if reverse {
+            Some("--reverse")
+        } else if alternatives.is_some() {
+            Some("--alternatives")
+        } else if layout.orientation == Orientation::Vertical {
+            Some("--orientation vertical")
+        } else if matches!(input, Input::Lines(_)) {
+            Some("batch mode")
+        } else if json && path == "-" {
+            Some("--json")
+        } else {
+            None
+        };
+        if let Some(other) = other {
+            return Err(conflict("--svg", other));
+        }
+    }
+
+    if alphabet.is_some() && mapping.is_some() {
+        return Err(both("--alphabet", "--mapping"));
+    }
+    if let Some(name) = &alphabet
+        && !MappingTable::alphabets().any(|a| a == name)
+    {
+        return Err(unknown(format!("unknown alphabet {name:?}.")));
+    }
+
+    // A vertical result spans several lines; only JSON keeps batch output
+    // one line per input.
+    if matches!(input, Input::Lines(_)) && layout.orientation == Orientation::Vertical && !json {
+        return Err(ArgError::Conflict(
+            "--orientation vertical needs --json in batch mode.".to_string(),
+        ));
+    }
+
+    if let Some(name) = &profile {
+        if !(Profile::names().any(|p| p == name) || name.ends_with(".toml")) {
+            return Err(unknown(format!("unknown profile {name:?}.")));
+        }
+        if reverse {
+            return Err(both("--profile", "--reverse"));
+        }
+    }
+
+    Ok(Command::Run(Args {
+        input,
+        verbose,
+        text,
+        reverse,
+        json,
+        plain,
+        alphabet,
+        mapping,
+        mode,
+        diacritics,
+        layout,
+        profile,
+        alternatives,
+        svg,
+        drawing,
+    }))
+}
+
+pub(crate) fn write_usage(out: &mut dyn Write, prog: &str) -> io::Result<()> {
+    let alphabets: Vec<&str> = MappingTable::alphabets().collect();
+    let profiles: Vec<&str> = Profile::names().collect();
+
+    writeln!(out, "Usage:")?;
+    writeln!(out, "  {prog} [--verbose] [--alphabet NAME | --mapping FILE] \"<IPA>\"")?;
+    writeln!(out, "  {prog} [--verbose] [--alphabet NAME | --mapping FILE] --text \"<English>\"")?;
+    writeln!(out, "  {prog} [--verbose] [--alphabet NAME | --mapping FILE] --reverse \"<runes>\"")?;
+    writeln!(out, "  {prog} [options] [--input FILE] < lines   # one result per line")?;
+    writeln!(out, "  {prog} [options] --repl                   # interactive session")?;
+    writeln!(out, "  {prog} runes [--alphabet NAME] [filters]  # the rune inventory (runes --help)")?;
+    writeln!(out, "Options:")?;
+    writeln!(out, "  --text           input is English text; convert it to IPA first")?;
+    writeln!(out, "  --reverse        input is runes; read them back as approximate IPA")?;
+    writeln!(out, "  --repl           read IPA line by line, with :commands (:help lists them)")?;
+    writeln!(out, "                   (no line editing or history; use rlwrap for those)")?;
+    writeln!(out, "  --input FILE     read newline-delimited inputs from FILE (- for stdin)")?;
+    writeln!(out, "  --plain          print only the result, without the banner")?;
+    writeln!(out, "  --alphabet NAME  target row: {} (default: futhorc)", alphabets.join("|"))?;
+    writeln!(out, "  --mapping FILE   load IPA -> rune tables from a TOML or JSON file")?;
+    writeln!(out, "  --mode MODE      pragmatic|strict|readable (default: pragmatic)")?;
+    writeln!(out, "  --profile NAME   dialect: {} (or a profile .toml file)", profiles.join("|"))?;
+    writeln!(out, "  --separator SEP  word boundary: single (᛫, default)|multiple (᛬)|cross (᛭)|space")?;
+    writeln!(out, "  --punctuation P  sentence punctuation: runic (default)|keep|drop")?;
+    writeln!(out, "  --orientation O  horizontal (default)|vertical (one glyph per line)")?;
+    writeln!(out, "  --svg FILE       also draw the result as SVG to FILE (- for stdout only)")?;
+    writeln!(out, "  --direction D    SVG: ltr (default)|rtl|boustrophedon|serpent")?;
+    writeln!(out, "  --wrap N         SVG: at most N glyphs per line")?;
+    writeln!(out, "  --bind           SVG: bind a token's runes on a shared stave")?;
+    writeln!(out, "  --alternatives N print the N best renderings, ranked, instead of one")?;
+    writeln!(out, "  --diacritics D   IPA diacritics: strip (default)|spell (tʰ -> th, ã -> an)")?;
+    writeln!(out, "  --json           print a JSON trace of every step instead of the banner")?;
+    writeln!(out, "                   (one compact trace per line in batch mode: JSON Lines)")?;
+    writeln!(out, "  --help, -h       print this help")?;
+    writeln!(out, "Examples:")?;
+    writeln!(out, "  {prog} --verbose \"/naɪt/\"")?;
+    writeln!(out, "  {prog} --verbose \"tʃɜːtʃ\"   # 'church' (approx)")?;
+    writeln!(out, "  {prog} --alphabet younger-long \"/naɪt/\"")?;
+    writeln!(out, "  {prog} --alphabet ogham --orientation vertical \"/naɪt/\"")?;
+    writeln!(out, "  {prog} --text \"good night\"")?;
+    writeln!(out, "  {prog} --reverse \"ᚦᛁᛋ᛫ᚾᚪᛁᛏ\"")?;
+    writeln!(out, "  {prog} --mode strict \"ʃɪp\"")?;
+    writeln!(out, "  {prog} --alternatives 5 \"/kʌt/\"")?;
+    writeln!(out, "  {prog} --profile rp \"/kɑr pɑrk/\"")?;
+    writeln!(out, "  {prog} --svg night.svg --direction serpent --bind \"/ðə naɪt/\"")?;
+    writeln!(out, "  {prog} --diacritics spell \"/bʌʔn̩/\"")?;
+    writeln!(out, "  {prog} --mapping mappings/futhorc.toml \"/naɪt/\"")?;
+    writeln!(out, "  {prog} --text --input words.txt > words.tsv")?;
+    writeln!(out, "The README describes the pipeline, the tables, modes and profiles.")?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(args: &[&str]) -> Result<Args, ArgError> {
+        run_on(args, true)
+    }
+
+    /// `args` with stdin a pipe (`false`) or a terminal.
+    fn run_on(args: &[&str], stdin_is_terminal: bool) -> Result<Args, ArgError> {
+        match parse(args.iter().map(|a| a.to_string()), stdin_is_terminal)? {
+            Command::Run(args) => Ok(args),
+            Command::Help => panic!("{args:?} asks for help"),
+        }
+    }
+
+    fn message(args: &[&str]) -> String {
+        match run(args).unwrap_err() {
+            ArgError::Usage(message) => message.expect("a message before the usage"),
+            ArgError::Conflict(message) => message,
+        }
+    }
+
+    #[test]
+    fn parses_values_into_options() {
+        let args = run(&[
+            "--mode",
+            "strict",
+            "--separator",
+            "cross",
+            "--punctuation",
+            "keep",
+            "--diacritics",
+            "spell",
+            "--alternatives",
+            "3",
+            "--alphabet",
+            "elder",
+            "--profile",
+            "rp",
+            "-v",
+            "--plain",
+            "/naɪt/",
+        ])
+        .unwrap();
+        assert_eq!(args.input, Input::Arg("/naɪt/".to_string()));
+        assert_eq!(args.mode, Mode::Strict);
+        assert_eq!(args.layout.separator, Separator::Cross);
+        assert_eq!(args.layout.punctuation, Punctuation::Keep);
+        assert_eq!(args.diacritics, Diacritics::Spell);
+        assert_eq!(args.alternatives, Some(3));
+        assert_eq!(args.alphabet.as_deref(), Some("elder"));
+        assert_eq!(args.profile.as_deref(), Some("rp"));
+        assert!(args.verbose && args.plain && !args.json);
+
+        let args = run(&[
+            "--svg",
+            "out.svg",
+            "--direction",
+            "rtl",
+            "--wrap",
+            "4",
+            "--bind",
+            "x",
+        ])
+        .unwrap();
+        assert_eq!(args.svg.as_deref(), Some("out.svg"));
+        assert_eq!(args.drawing.direction, Direction::RightToLeft);
+        assert_eq!(args.drawing.wrap, 4);
+        assert!(args.drawing.bind);
+    }
+
+    #[test]
+    fn input_arguments_are_joined() {
+        let args = run(&["/ðə/", "--text", "naɪt", "-"]).unwrap();
+        assert_eq!(args.input, Input::Arg("/ðə/ naɪt -".to_string()));
+        assert!(args.text);
+    }
+
+    #[test]
+    fn input_comes_from_arguments_a_file_or_a_pipe() {
+        assert_eq!(
+            run(&["--input", "words.txt"]).unwrap().input,
+            Input::Lines(Some("words.txt".to_string()))
+        );
+        assert_eq!(run(&["--input", "-"]).unwrap().input, Input::Lines(None));
+        assert_eq!(run_on(&[], false).unwrap().input, Input::Lines(None));
+        assert_eq!(run(&["--repl"]).unwrap().input, Input::Repl);
+        // Nothing to read from a terminal: just the usage.
+        assert_eq!(run_on(&[], true).unwrap_err(), ArgError::Usage(None));
+    }
+
+    #[test]
+    fn help_wins_wherever_it_is() {
+        for args in [&["--help"][..], &["-h"], &["/x/", "--repl", "--help"]] {
+            let parsed = parse(args.iter().map(|a| a.to_string()), true).unwrap();
+            assert!(matches!(parsed, Command::Help), "{args:?}");
+        }
+    }
+
+    #[test]
+    fn bad_values_print_the_usage() {
+        assert_eq!(message(&["--mode", "loud", "x"]), "unknown mode \"loud\".");
+        assert_eq!(
+            message(&["--separator", "dot", "x"]),
+            "unknown separator \"dot\"."
+        );
+        assert_eq!(
+            message(&["--direction", "up", "x"]),
+            "unknown direction \"up\"."
+        );
+        assert_eq!(
+            message(&["--alphabet", "latin", "x"]),
+            "unknown alphabet \"latin\"."
+        );
+        assert_eq!(
+            message(&["--profile", "scots", "x"]),
+            "unknown profile \"scots\"."
+        );
+        assert_eq!(
+            message(&["--alternatives", "0", "x"]),
+            "--alternatives needs a positive count, not \"0\"."
+        );
+        assert_eq!(
+            message(&["--wrap", "many", "x"]),
+            "--wrap needs a positive count, not \"many\"."
+        );
+        assert_eq!(
+            message(&["--alphabt", "elder", "x"]),
+            "unknown option \"--alphabt\"."
+        );
+        assert_eq!(run(&["x", "--mode"]).unwrap_err(), ArgError::Usage(None));
+        // A profile file is only read later.
+        assert!(run(&["--profile", "mine.toml", "x"]).is_ok());
+    }
+
+    #[test]
+    fn conflicting_options_are_rejected() {
+        for (args, expected) in [
+            (
+                &["--reverse", "--text", "x"][..],
+                "--reverse and --text cannot be combined.",
+            ),
+            (
+                &["--reverse", "--alternatives", "2", "x"],
+                "--alternatives and --reverse cannot be combined.",
+            ),
+            (
+                &["--alternatives", "2", "--orientation", "vertical", "x"],
+                "--alternatives and --orientation vertical cannot be combined.",
+            ),
+            (
+                &["--repl", "--json"],
+                "--repl cannot be combined with --json.",
+            ),
+            (&["--repl", "x"], "--repl cannot be combined with an input."),
+            (
+                &["--input", "f", "x"],
+                "--input and an input argument cannot be combined.",
+            ),
+            (
+                &["--svg", "a.svg", "--input", "f"],
+                "--svg cannot be combined with batch mode.",
+            ),
+            (
+                &["--svg", "-", "--json", "x"],
+                "--svg cannot be combined with --json.",
+            ),
+            (
+                &["--alphabet", "elder", "--mapping", "t.toml", "x"],
+                "--alphabet and --mapping cannot be combined.",
+            ),
+            (
+                &["--orientation", "vertical", "--input", "f"],
+                "--orientation vertical needs --json in batch mode.",
+            ),
+            (
+                &["--profile", "rp", "--reverse", "x"],
+                "--profile and --reverse cannot be combined.",
+            ),
+        ] {
+            assert_eq!(
+                run(args).unwrap_err(),
+                ArgError::Conflict(expected.to_string()),
+                "{args:?}"
+            );
+        }
+
+        // Each is fine on its own terms.
+        assert!(run(&["--svg", "a.svg", "--json", "x"]).is_ok());
+        assert!(run(&["--orientation", "vertical", "--json", "--input", "f"]).is_ok());
+    }
+}
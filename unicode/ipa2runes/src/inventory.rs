@@ -0,0 +1,281 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Rune inventory: every rune a table defines, with what the table
+//! knows (glyph, codepoint, name, tradition, the sounds it writes) and
+//! the lore kept in `inventory/runes.toml` (its names across the
+//! traditions, meaning, place in its ætt or aicme, rune-poem lines).
+
+use std::collections::BTreeMap;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+use unicode_normalization::char::is_combining_mark;
+
+use crate::{MappingTable, Mode, RuneChar, Tradition};
+
+const LORE: &str = include_str!("../inventory/runes.toml");
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct LoreFile {
+    rows: Vec<RowDef>,
+    runes: Vec<RuneLore>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RowDef {
+    table: String,
+    groups: Vec<GroupDef>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GroupDef {
+    name: String,
+    runes: String,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct RuneLore {
+    glyphs: String,
+    #[serde(default)]
+    names: BTreeMap<String, String>,
+    meaning: String,
+    #[serde(default)]
+    poems: BTreeMap<String, String>,
+}
+
+/// Where a rune stands in its row: ætt (or aicme) and position, from 1.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Place {
+    pub group: String,
+    pub number: usize,
+    pub position: usize,
+}
+
+/// One rune of a table, with its lore. Serializes for `runes --json`.
+#[derive(Debug, Clone, Serialize)]
+pub struct Entry {
+    /// The table's key for the rune (FEOH, ISS, ...).
+    pub key: String,
+    #[serde(flatten)]
+    pub rune: RuneChar,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub place: Option<Place>,
+    /// Tokens the table writes with this rune alone.
+    pub sounds: Vec<String>,
+    /// Tokens it writes together with other runes (aɪ for ᛁ).
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub part_of: Vec<String>,
+    /// The rune's name in each tradition: elder, futhorc, younger, ogham.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub names: BTreeMap<String, String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub meaning: Option<String>,
+    /// Lines of the rune poems, by poem.
+    #[serde(skip_serializing_if = "BTreeMap::is_empty")]
+    pub poems: BTreeMap<String, String>,
+}
+
+/// Which entries to keep. Empty fields keep everything.
+#[derive(Debug, Clone, Default)]
+pub struct Filter {
+    pub tradition: Option<Tradition>,
+    /// Ætt or aicme number, from 1.
+    pub group: Option<usize>,
+    /// In the key, the table's name or any tradition's; case and accents
+    /// are ignored, so tyr finds týr and tīr.
+    pub name: Option<String>,
+    /// An IPA token the rune writes, alone or with others.
+    pub sound: Option<String>,
+}
+
+impl Filter {
+    pub fn matches(&self, e: &Entry) -> bool {
+        let name = self.name.as_deref().map(fold);
+        self.tradition.is_none_or(|t| e.rune.tradition == t)
+            && self
+                .group
+                .is_none_or(|g| e.place.as_ref().is_some_and(|p| p.number == g))
+            && name.is_none_or(|n| {
+                std::iter::once(&e.key)
+                    .chain([&e.rune.name])
+                    .chain(e.names.values())
+                    .any(|s| fold(s).contains(&n))
+            })
+            && self
+                .sound
+                .as_ref()
+                .is_none_or(|s| e.sounds.contains(s) || e.part_of.contains(s))
+    }
+}
+
+/// Lowercase, without accents.
+fn fold(s: &str) -> String {
+    s.nfd()
+        .filter(|&c| !is_combining_mark(c))
+        .collect::<String>()
+        .to_lowercase()
+}
+
+fn lore() -> LoreFile {
+    toml::from_str(LORE).expect("built-in rune lore is valid")
+}
+
+/// Every rune `table` defines, in the order of its row (ættir first,
+/// then runes outside them by codepoint), with their lore.
+pub fn entries(table: &MappingTable) -> Vec<Entry> {
+    let lore = lore();
+    let groups = lore
+        .rows
+        .iter()
+        .find(|r| r.table == table.name)
+        .map_or(&[][..], |r| &r.groups[..]);
+    let place = |ch: char| {
+        groups.iter().enumerate().find_map(|(i, g)| {
+            let position = g.runes.chars().position(|c| c == ch)?;
+            Some(Place {
+                group: g.name.clone(),
+                number: i + 1,
+                position: position + 1,
+            })
+        })
+    };
+
+    let mut entries: Vec<Entry> = table
+        .runes
+        .iter()
+        .map(|(key, rune)| {
+            let (mut sounds, mut part_of) = (Vec::new(), Vec::new());
+            for rule in table.rules() {
+                let runes = &rule.mapping_for(Mode::Pragmatic).runes;
+                if runes.iter().all(|r| r.ch == rune.ch) && !runes.is_empty() {
+                    sounds.push(rule.ipa.clone());
+                } else if runes.iter().any(|r| r.ch == rune.ch) {
+                    part_of.push(rule.ipa.clone());
+                }
+            }
+            let lore = lore
+                .runes
+                .iter()
+                .find(|l| l.glyphs.contains(rune.ch))
+                .cloned();
+            Entry {
+                key: key.clone(),
+                rune: rune.clone(),
+                place: place(rune.ch),
+                sounds,
+                part_of,
+                names: lore.as_ref().map(|l| l.names.clone()).unwrap_or_default(),
+                meaning: lore.as_ref().map(|l| l.meaning.clone()),
+                poems: lore.map(|l| l.poems).unwrap_or_default(),
+            }
+        })
+        .collect();
+    entries.sort_by_key(|e| {
+        let place = e.place.as_ref().map(|p| (p.number, p.position));
+        (place.is_none(), place, e.rune.codepoint)
+    });
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn builtin(name: &str) -> Vec<Entry> {
+        entries(&MappingTable::builtin(name).unwrap())
+    }
+
+    #[test]
+    fn rows_follow_their_aettir() {
+        let elder = builtin("elder");
+        let row: String = elder.iter().map(|e| e.rune.ch).collect();
+        assert_eq!(row, "ᚠᚢᚦᚨᚱᚲᚷᚹᚺᚾᛁᛃᛇᛈᛉᛊᛏᛒᛖᛗᛚᛜᛞᛟ");
+        let tyr = elder.iter().find(|e| e.key == "TIWAZ").unwrap();
+        assert_eq!(tyr.place.as_ref().unwrap().group, "Týr's ætt");
+        assert_eq!(tyr.names["younger"], "týr");
+
+        // The Futhorc's approximation runes stand outside its ættir.
+        let futhorc = builtin("futhorc");
+        assert!(futhorc.last().unwrap().place.is_none());
+        assert!(futhorc.iter().any(|e| e.key == "SH" && e.meaning.is_none()));
+    }
+
+    #[test]
+    fn every_row_rune_has_lore() {
+        let lore = lore();
+        for alphabet in MappingTable::alphabets() {
+            let table = MappingTable::builtin(alphabet).unwrap();
+            let row = lore.rows.iter().find(|r| r.table == table.name).unwrap();
+            for group in &row.groups {
+                for ch in group.runes.chars() {
+                    assert!(table.runes.values().any(|r| r.ch == ch), "{alphabet}: {ch}");
+                }
+            }
+            for e in entries(&table) {
+                let approximation = e.rune.tradition == Tradition::Approximation;
+                assert!(e.place.is_some() || approximation, "{alphabet}: {}", e.key);
+                assert!(
+                    e.meaning.is_some() || approximation,
+                    "{alphabet}: {}",
+                    e.key
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn sounds_and_poems() {
+        let younger = builtin("younger-long");
+        let fe = &younger[0];
+        assert_eq!(fe.rune.ch, 'ᚠ');
+        assert!(fe.sounds.contains(&"f".to_string()));
+        assert!(fe.poems["norwegian"].starts_with("Fé vældr"));
+        assert!(fe.poems["icelandic"].starts_with("Fé er"));
+
+        let futhorc = builtin("futhorc");
+        let is = futhorc.iter().find(|e| e.key == "IS").unwrap();
+        assert!(is.part_of.contains(&"aɪ".to_string()));
+    }
+
+    #[test]
+    fn filters() {
+        let futhorc = builtin("futhorc");
+        let keep = |f: Filter| -> Vec<char> {
+            futhorc
+                .iter()
+                .filter(|e| f.matches(e))
+                .map(|e| e.rune.ch)
+                .collect()
+        };
+        let additions = Filter {
+            group: Some(4),
+            ..Filter::default()
+        };
+        assert_eq!(keep(additions), "ᚪᚫᚣᛡᛠᛣᚸ".chars().collect::<Vec<_>>());
+        let fehu = Filter {
+            name: Some("FEHU".to_string()),
+            ..Filter::default()
+        };
+        assert_eq!(keep(fehu), ['ᚠ']);
+        let tyr = Filter {
+            name: Some("tyr".to_string()),
+            ..Filter::default()
+        };
+        assert_eq!(keep(tyr), ['ᛏ']);
+        let k = Filter {
+            sound: Some("k".to_string()),
+            ..Filter::default()
+        };
+        assert_eq!(keep(k), ['ᚳ']);
+        let approximations = Filter {
+            tradition: Some(Tradition::Approximation),
+            ..Filter::default()
+        };
+        assert_eq!(keep(approximations).len(), 3);
+    }
+}
@@ -0,0 +1,340 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! The IPA chart: symbols and diacritics outside the mapping tables.
+//!
+//! Every built-in table covers the same broad core of about forty tokens
+//! (the English phonemes plus a few continental vowels). The rest of the
+//! chart is folded onto that core here: `ʁ` is written as the table's `r`,
+//! `x` as its `h`, `ɚ` as `ər`. A table that defines one of these symbols
+//! itself always wins over the fold.
+//!
+//! Symbols are stored in NFD, the form input is normalized to, so `ç` is
+//! `c` + U+0327 COMBINING CEDILLA here.
+//!
+//! Diacritics (combining marks and the superscript modifier letters) are
+//! not tokens of their own; they attach to the sound before them and are
+//! either stripped or spelled out as extra sounds (`--diacritics`).
+
+use unicode_normalization::char::is_combining_mark;
+
+/// An IPA symbol and the broad core symbols it is written with.
+#[derive(Debug, Clone, Copy)]
+pub struct Symbol {
+    pub ipa: &'static str,
+    pub name: &'static str,
+    /// Core IPA written in its place; empty when it has no rune at all.
+    pub broad: &'static str,
+}
+
+const fn sym(ipa: &'static str, name: &'static str, broad: &'static str) -> Symbol {
+    Symbol { ipa, name, broad }
+}
+
+/// The IPA chart (2020 revision) minus the broad core the tables define.
+pub const CHART: &[Symbol] = &[
+    // Pulmonic consonants: plosives
+    sym("ʈ", "voiceless retroflex plosive", "t"),
+    sym("ɖ", "voiced retroflex plosive", "d"),
+    sym("c", "voiceless palatal plosive", "k"),
+    sym("ɟ", "voiced palatal plosive", "g"),
+    sym("q", "voiceless uvular plosive", "k"),
+    sym("ɢ", "voiced uvular plosive", "g"),
+    sym("ɡ", "voiced velar plosive (IPA script g)", "g"),
+    sym("ʔ", "glottal stop", ""),
+    sym("ʡ", "epiglottal plosive", ""),
+    // Nasals
+    sym("ɱ", "labiodental nasal", "m"),
+    sym("ɳ", "retroflex nasal", "n"),
+    sym("ɲ", "palatal nasal", "nj"),
+    sym("ɴ", "uvular nasal", "ŋ"),
+    // Trills, taps and flaps
+    sym("ʙ", "bilabial trill", "b"),
+    sym("ʀ", "uvular trill", "r"),
+    sym("ⱱ", "labiodental flap", "v"),
+    sym("ɾ", "alveolar tap", "r"),
+    sym("ɽ", "retroflex flap", "r"),
+    sym("ɺ", "alveolar lateral flap", "l"),
+    // Fricatives
+    sym("ɸ", "voiceless bilabial fricative", "f"),
+    sym("β", "voiced bilabial fricative", "v"),
+    sym("ʂ", "voiceless retroflex fricative", "ʃ"),
+    sym("ʐ", "voiced retroflex fricative", "ʒ"),
+    sym("c\u{327}", "voiceless palatal fricative", "h"),
+    sym("ʝ", "voiced palatal fricative", "j"),
+    sym("x", "voiceless velar fricative", "h"),
+    sym("ɣ", "voiced velar fricative", "g"),
+    sym("χ", "voiceless uvular fricative", "h"),
+    sym("ʁ", "voiced uvular fricative", "r"),
+    sym("ħ", "voiceless pharyngeal fricative", "h"),
+    sym("ʕ", "voiced pharyngeal fricative", "h"),
+    sym("ɦ", "voiced glottal fricative", "h"),
+    sym("ɬ", "voiceless alveolar lateral fricative", "hl"),
+    sym("ɮ", "voiced alveolar lateral fricative", "l"),
+    // Approximants
+    sym("ʋ", "labiodental approximant", "v"),
+    sym("ɹ", "alveolar approximant", "r"),
+    sym("ɻ", "retroflex approximant", "r"),
+    sym("ɰ", "velar approximant", "w"),
+    sym("ɭ", "retroflex lateral approximant", "l"),
+    sym("ʎ", "palatal lateral approximant", "lj"),
+    sym("ʟ", "velar lateral approximant", "l"),
+    sym("ɫ", "velarized alveolar lateral approximant", "l"),
+    // Non-pulmonic consonants: clicks
+    sym("ʘ", "bilabial click", "p"),
+    sym("ǀ", "dental click", "t"),
+    sym("ǃ", "postalveolar click", "k"),
+    sym("ǂ", "palatoalveolar click", "t"),
+    sym("ǁ", "alveolar lateral click", "l"),
+    // Voiced implosives
+    sym("ɓ", "bilabial implosive", "b"),
+    sym("ɗ", "dental/alveolar implosive", "d"),
+    sym("ʄ", "palatal implosive", "g"),
+    sym("ɠ", "velar implosive", "g"),
+    sym("ʛ", "uvular implosive", "g"),
+    // Other symbols
+    sym("ʍ", "voiceless labial-velar fricative", "hw"),
+    sym("ɥ", "labial-palatal approximant", "w"),
+    sym("ʜ", "voiceless epiglottal fricative", "h"),
+    sym("ʢ", "voiced epiglottal fricative", "h"),
+    sym("ɕ", "voiceless alveolo-palatal fricative", "ʃ"),
+    sym("ʑ", "voiced alveolo-palatal fricative", "ʒ"),
+    sym("ɧ", "simultaneous ʃ and x", "ʃ"),
+    // Affricate ligatures (the tie-bar spellings are matched as pairs)
+    sym("ʦ", "voiceless alveolar affricate", "ts"),
+    sym("ʣ", "voiced alveolar affricate", "dz"),
+    sym("ʧ", "voiceless postalveolar affricate", "tʃ"),
+    sym("ʨ", "voiceless alveolo-palatal affricate", "tʃ"),
+    sym("ʥ", "voiced alveolo-palatal affricate", "dʒ"),
+    // Vowels
+    sym("ɨ", "close central unrounded vowel", "i"),
+    sym("ʉ", "close central rounded vowel", "u"),
+    sym("ɯ", "close back unrounded vowel", "u"),
+    sym("ʏ", "near-close front rounded vowel", "y"),
+    sym("ø", "close-mid front rounded vowel", "e"),
+    sym("ɘ", "close-mid central unrounded vowel", "ə"),
+    sym("ɵ", "close-mid central rounded vowel", "o"),
+    sym("ɤ", "close-mid back unrounded vowel", "o"),
+    sym("œ", "open-mid front rounded vowel", "ɛ"),
+    sym("ɞ", "open-mid central rounded vowel", "ɜ"),
+    sym("ɐ", "near-open central vowel", "ə"),
+    sym("ɶ", "open front rounded vowel", "a"),
+    sym("ɒ", "open back rounded vowel", "ɔ"),
+    sym("ɚ", "r-coloured mid central vowel", "ər"),
+    sym("ɝ", "r-coloured open-mid central vowel", "ɜr"),
+    // Suprasegmentals and tone letters
+    sym("ː", "long", ""),
+    sym("ˑ", "half-long", ""),
+    sym("‿", "linking", ""),
+    sym("˥", "extra-high tone", ""),
+    sym("˦", "high tone", ""),
+    sym("˧", "mid tone", ""),
+    sym("˨", "low tone", ""),
+    sym("˩", "extra-low tone", ""),
+    sym("ꜜ", "downstep", ""),
+    sym("ꜛ", "upstep", ""),
+];
+
+/// What `--diacritics spell` writes for a diacritic.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Spelling {
+    /// Nothing; the mark only refines the sound.
+    Drop,
+    /// A core sound before the one it marks (syllabic n̩ → ən).
+    Before(&'static str),
+    /// A core sound after the one it marks (tʰ → th, ã → an).
+    After(&'static str),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Diacritic {
+    pub mark: char,
+    pub name: &'static str,
+    pub spelling: Spelling,
+}
+
+const fn mark(mark: char, name: &'static str, spelling: Spelling) -> Diacritic {
+    Diacritic {
+        mark,
+        name,
+        spelling,
+    }
+}
+
+/// The chart's diacritics, combining marks and modifier letters alike.
+pub const DIACRITICS: &[Diacritic] = &[
+    mark('\u{325}', "voiceless", Spelling::Drop),
+    mark('\u{30A}', "voiceless", Spelling::Drop),
+    mark('\u{32C}', "voiced", Spelling::Drop),
+    mark('ʰ', "aspirated", Spelling::After("h")),
+    mark('ʱ', "breathy aspirated", Spelling::After("h")),
+    mark('\u{339}', "more rounded", Spelling::Drop),
+    mark('\u{31C}', "less rounded", Spelling::Drop),
+    mark('\u{31F}', "advanced", Spelling::Drop),
+    mark('\u{320}', "retracted", Spelling::Drop),
+    mark('\u{308}', "centralized", Spelling::Drop),
+    mark('\u{33D}', "mid-centralized", Spelling::Drop),
+    mark('\u{329}', "syllabic", Spelling::Before("ə")),
+    mark('\u{30D}', "syllabic", Spelling::Before("ə")),
+    mark('\u{32F}', "non-syllabic", Spelling::Drop),
+    mark('˞', "rhoticity", Spelling::After("r")),
+    mark('\u{324}', "breathy voiced", Spelling::Drop),
+    mark('\u{330}', "creaky voiced", Spelling::Drop),
+    mark('\u{33C}', "linguolabial", Spelling::Drop),
+    mark('ʷ', "labialized", Spelling::After("w")),
+    mark('ʲ', "palatalized", Spelling::After("j")),
+    mark('ˠ', "velarized", Spelling::Drop),
+    mark('ˤ', "pharyngealized", Spelling::Drop),
+    mark('\u{334}', "velarized or pharyngealized", Spelling::Drop),
+    mark('\u{31D}', "raised", Spelling::Drop),
+    mark('\u{31E}', "lowered", Spelling::Drop),
+    mark('\u{318}', "advanced tongue root", Spelling::Drop),
+    mark('\u{319}', "retracted tongue root", Spelling::Drop),
+    mark('\u{32A}', "dental", Spelling::Drop),
+    mark('\u{33A}', "apical", Spelling::Drop),
+    mark('\u{33B}', "laminal", Spelling::Drop),
+    mark('\u{303}', "nasalized", Spelling::After("n")),
+    mark('ⁿ', "nasal release", Spelling::After("n")),
+    mark('ˡ', "lateral release", Spelling::After("l")),
+    mark('\u{31A}', "no audible release", Spelling::Drop),
+    mark('ʼ', "ejective", Spelling::Drop),
+    mark('\u{306}', "extra-short", Spelling::Drop),
+    mark('\u{30B}', "extra-high tone", Spelling::Drop),
+    mark('\u{301}', "high tone", Spelling::Drop),
+    mark('\u{304}', "mid tone", Spelling::Drop),
+    mark('\u{300}', "low tone", Spelling::Drop),
+    mark('\u{30F}', "extra-low tone", Spelling::Drop),
+    mark('\u{30C}', "rising tone", Spelling::Drop),
+    mark('\u{302}', "falling tone", Spelling::Drop),
+];
+
+/// A chart symbol by its (NFD) spelling.
+pub fn lookup(ipa: &str) -> Option<&'static Symbol> {
+    CHART.iter().find(|s| s.ipa == ipa)
+}
+
+pub fn diacritic(c: char) -> Option<&'static Diacritic> {
+    DIACRITICS.iter().find(|d| d.mark == c)
+}
+
+/// U+0361 and U+035C join two symbols into one sound: t͡ʃ, k͜p.
+pub fn is_tie(c: char) -> bool {
+    c == '\u{361}' || c == '\u{35C}'
+}
+
+/// A mark that attaches to the preceding sound: any combining mark other
+/// than a tie bar, or one of the chart's modifier letters.
+pub fn is_modifier(c: char) -> bool {
+    (is_combining_mark(c) && !is_tie(c)) || diacritic(c).is_some()
+}
+
+/// Vowel letters of the chart, rhotic ɚ and ɝ included.
+const VOWELS: &str = "iyɨʉɯuɪʏʊeøɘɵɤoəɛœɜɞʌɔæɐaɶɑɒɚɝ";
+
+/// True for a vowel letter; context for the dialect profiles' rewrites.
+pub fn is_vowel(c: char) -> bool {
+    VOWELS.contains(c)
+}
+
+/// Bytes of `input` that spell `pattern`, if `input` starts with it.
+/// Tie bars between two letters of `pattern` are skipped, so `tʃ` matches
+/// both "tʃ" and "t͡ʃ".
+pub fn match_len(input: &str, pattern: &str) -> Option<usize> {
+    let mut rest = input.char_indices().peekable();
+    let mut first = true;
+    for p in pattern.chars() {
+        if !first && !is_tie(p) {
+            while let Some(&(_, c)) = rest.peek() {
+                if !is_tie(c) {
+                    break;
+                }
+                rest.next();
+            }
+        }
+        first = false;
+        match rest.next() {
+            Some((_, c)) if c == p => {}
+            _ => return None,
+        }
+    }
+    Some(rest.peek().map_or(input.len(), |&(i, _)| i))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::table::MappingTable;
+    use unicode_normalization::UnicodeNormalization;
+
+    #[test]
+    fn chart_symbols_are_nfd_and_distinct() {
+        for (i, s) in CHART.iter().enumerate() {
+            let nfd: String = s.ipa.nfd().collect();
+            assert_eq!(nfd, s.ipa, "{} ({}) is not NFD", s.ipa, s.name);
+            assert!(
+                CHART[..i].iter().all(|t| t.ipa != s.ipa),
+                "{} listed twice",
+                s.ipa
+            );
+        }
+    }
+
+    #[test]
+    fn diacritics_are_modifiers() {
+        for d in DIACRITICS {
+            assert!(is_modifier(d.mark), "{:?} ({})", d.mark, d.name);
+            assert!(!is_tie(d.mark));
+        }
+        assert!(!is_modifier('\u{361}'));
+        assert!(!is_modifier('a'));
+    }
+
+    /// Every fold lands on symbols each built-in table defines itself, so
+    /// no chart symbol is ever unmapped with the shipped tables.
+    #[test]
+    fn broad_forms_use_table_tokens() {
+        for alphabet in MappingTable::alphabets() {
+            let table = MappingTable::builtin(alphabet).unwrap();
+            for s in CHART {
+                let mut rest = s.broad;
+                while !rest.is_empty() {
+                    let rule = table
+                        .rules()
+                        .iter()
+                        .find(|r| rest.starts_with(r.ipa.as_str()))
+                        .unwrap_or_else(|| {
+                            panic!("{alphabet}: broad {:?} of {} has no rule", rest, s.ipa)
+                        });
+                    rest = &rest[rule.ipa.len()..];
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn spelled_diacritics_use_table_tokens() {
+        for alphabet in MappingTable::alphabets() {
+            let table = MappingTable::builtin(alphabet).unwrap();
+            for d in DIACRITICS {
+                if let Spelling::Before(ipa) | Spelling::After(ipa) = d.spelling {
+                    assert!(
+                        table.rules().iter().any(|r| r.ipa == ipa),
+                        "{alphabet}: {:?} spells {ipa:?}",
+                        d.mark
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn match_len_skips_tie_bars() {
+        assert_eq!(match_len("tʃa", "tʃ"), Some("tʃ".len()));
+        assert_eq!(match_len("t\u{361}ʃa", "tʃ"), Some("t\u{361}ʃ".len()));
+        assert_eq!(match_len("t\u{35C}ʃ", "tʃ"), Some("t\u{35C}ʃ".len()));
+        assert_eq!(match_len("ts", "tʃ"), None);
+        assert_eq!(match_len("t", "tʃ"), None);
+        assert_eq!(match_len("c\u{327}", "c\u{327}"), Some(3));
+        assert_eq!(match_len("c", "c\u{327}"), None);
+    }
+}
@@ -0,0 +1,239 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Output layout: how the rune string marks words and sentences.
+//!
+//! Runes of one word are written together, as in the inscriptions; the
+//! gap between words becomes the separator, or the runic form of the
+//! sentence punctuation that ends the word.
+//!
+//! Ogham is laid out its own way: each word is wrapped in feather marks
+//! (᚛ U+169B ... ᚜ U+169C) and words are divided by the Ogham space mark
+//! (U+1680). Ogham has no sentence punctuation, so the runic marks are
+//! dropped; `Punctuation::Keep` still writes the ASCII ones.
+
+use crate::{RuneChar, Script};
+
+/// ᚛ U+169B OGHAM FEATHER MARK, which opens an Ogham word.
+const FEATHER: char = '\u{169B}';
+/// ᚜ U+169C OGHAM REVERSED FEATHER MARK, which closes it.
+const REVERSED_FEATHER: char = '\u{169C}';
+/// U+1680 OGHAM SPACE MARK, the stemline between words.
+const OGHAM_SPACE: char = '\u{1680}';
+
+/// How word boundaries are written.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Separator {
+    /// ᛫ U+16EB RUNIC SINGLE PUNCTUATION
+    Single,
+    /// ᛬ U+16EC RUNIC MULTIPLE PUNCTUATION
+    Multiple,
+    /// ᛭ U+16ED RUNIC CROSS PUNCTUATION
+    Cross,
+    /// A plain space.
+    Space,
+}
+
+/// What happens to sentence punctuation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Punctuation {
+    /// , ; : become ᛬ and . ! ? become ᛭.
+    Runic,
+    /// Written through unchanged.
+    Keep,
+    /// Dropped; the word boundary is still written.
+    Drop,
+}
+
+/// Which way the output runs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Orientation {
+    /// One line, left to right.
+    Horizontal,
+    /// One glyph per line. Runes read top to bottom; Ogham reads bottom
+    /// to top, as along the edge of a standing stone.
+    Vertical,
+}
+
+/// Output layout: how the rune string marks words and sentences.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Layout {
+    pub separator: Separator,
+    pub punctuation: Punctuation,
+    pub orientation: Orientation,
+}
+
+impl Default for Layout {
+    /// ᛫ between words, runic sentence punctuation, horizontal.
+    fn default() -> Self {
+        Self {
+            separator: Separator::Single,
+            punctuation: Punctuation::Runic,
+            orientation: Orientation::Horizontal,
+        }
+    }
+}
+
+impl Separator {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "single" => Some(Separator::Single),
+            "multiple" => Some(Separator::Multiple),
+            "cross" => Some(Separator::Cross),
+            "space" => Some(Separator::Space),
+            _ => None,
+        }
+    }
+
+    pub fn glyph(self) -> char {
+        match self {
+            Separator::Single => '\u{16EB}',
+            Separator::Multiple => '\u{16EC}',
+            Separator::Cross => '\u{16ED}',
+            Separator::Space => ' ',
+        }
+    }
+
+    /// The word divider in `script`: Ogham writes the space mark for
+    /// every separator but a plain space.
+    pub fn glyph_in(self, script: Script) -> char {
+        match (script, self) {
+            (Script::Ogham, Separator::Space) => ' ',
+            (Script::Ogham, _) => OGHAM_SPACE,
+            (Script::Runic, _) => self.glyph(),
+        }
+    }
+}
+
+impl Orientation {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "horizontal" => Some(Orientation::Horizontal),
+            "vertical" => Some(Orientation::Vertical),
+            _ => None,
+        }
+    }
+}
+
+impl Punctuation {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "runic" => Some(Punctuation::Runic),
+            "keep" => Some(Punctuation::Keep),
+            "drop" => Some(Punctuation::Drop),
+            _ => None,
+        }
+    }
+
+    /// How one sentence punctuation mark is written, if at all.
+    pub fn render(self, c: char) -> Option<char> {
+        match self {
+            Punctuation::Runic => match c {
+                ',' | ';' | ':' => Some('\u{16EC}'),
+                _ => Some('\u{16ED}'),
+            },
+            Punctuation::Keep => Some(c),
+            Punctuation::Drop => None,
+        }
+    }
+
+    /// As [`render`](Punctuation::render), in `script`: Ogham has no
+    /// punctuation marks of its own, so the runic ones are dropped.
+    pub fn render_in(self, script: Script, c: char) -> Option<char> {
+        match (script, self) {
+            (Script::Ogham, Punctuation::Runic) => None,
+            _ => self.render(c),
+        }
+    }
+}
+
+/// Builds the final rune string: runes of one word run together, and the
+/// gap between words becomes either its punctuation or the separator.
+pub(crate) struct RuneWriter {
+    layout: Layout,
+    script: Script,
+    out: String,
+    /// An Ogham word is open: its feather mark is written, the reversed
+    /// one is not yet.
+    in_word: bool,
+    /// A word boundary has been seen since the last rune.
+    space: bool,
+    /// Punctuation seen since the last rune, already rendered.
+    punct: String,
+}
+
+impl RuneWriter {
+    pub(crate) fn new(layout: Layout, script: Script) -> Self {
+        Self {
+            layout,
+            script,
+            out: String::new(),
+            in_word: false,
+            space: false,
+            punct: String::new(),
+        }
+    }
+
+    pub(crate) fn boundary(&mut self) {
+        self.space = true;
+    }
+
+    /// Queue sentence punctuation; returns how it will be written.
+    pub(crate) fn punctuation(&mut self, c: char) -> Option<char> {
+        let glyph = self.layout.punctuation.render_in(self.script, c)?;
+        self.punct.push(glyph);
+        Some(glyph)
+    }
+
+    pub(crate) fn runes(&mut self, runes: &[RuneChar]) {
+        if runes.is_empty() {
+            return;
+        }
+        self.flush_gap();
+        if self.script == Script::Ogham && !self.in_word {
+            self.out.push(FEATHER);
+            self.in_word = true;
+        }
+        self.out.extend(runes.iter().map(|r| r.ch));
+    }
+
+    fn close_word(&mut self) {
+        if self.in_word {
+            self.out.push(REVERSED_FEATHER);
+            self.in_word = false;
+        }
+    }
+
+    fn flush_gap(&mut self) {
+        if self.space || !self.punct.is_empty() {
+            self.close_word();
+        }
+        if !self.punct.is_empty() {
+            self.out.push_str(&self.punct);
+            if self.layout.punctuation == Punctuation::Keep && self.space {
+                self.out.push(' ');
+            }
+        } else if self.space && !self.out.is_empty() {
+            self.out.push(self.layout.separator.glyph_in(self.script));
+        }
+        self.space = false;
+        self.punct.clear();
+    }
+
+    /// Trailing punctuation is kept; a trailing separator is not.
+    /// Vertical output puts each glyph on its own line.
+    pub(crate) fn finish(mut self) -> String {
+        self.close_word();
+        self.out.push_str(&self.punct);
+        if self.layout.orientation == Orientation::Horizontal {
+            return self.out;
+        }
+
+        let mut glyphs: Vec<String> = self.out.chars().map(String::from).collect();
+        if self.script == Script::Ogham {
+            glyphs.reverse();
+        }
+        glyphs.join("\n")
+    }
+}
+
@@ -0,0 +1,1021 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! IPA → runic transliteration as a library.
+//!
+//! The `ipa2runes` binary is a thin command-line wrapper around this crate;
+//! see its documentation (`src/main.rs`) for the background, the mapping
+//! tables and the conventions the output follows.
+//!
+//! The pipeline has four stages, each usable on its own:
+//!
+//! 1. [`normalize_ipa`]: NFD, slashes and stress marks stripped, word
+//!    boundaries and sentence punctuation made explicit.
+//! 2. [`Tokenizer`]: greedy longest-match against a [`MappingTable`] and
+//!    the IPA chart, compiled into a prefix trie, with diacritics and tie
+//!    bars attached to their sounds; each token keeps its byte span.
+//! 3. [`Mapper`]: one token to a [`Mapping`] of runes plus rationale.
+//!    [`TableMapper`] is the implementation backed by a mapping table;
+//!    [`TableMapper::futhorc`] is the default Anglo-Saxon Futhorc one.
+//!    [`Mapper::alternatives`] ranks every defensible mapping instead.
+//! 4. [`map_tokens`]: every token mapped and the runes laid out as words
+//!    and punctuation.
+//!
+//! [`transliterate`] runs them all, and [`n_best`] ranks the whole
+//! transliterations the tokens' alternatives add up to; a
+//! [`Transliterator`] does both for many inputs with one set-up.
+//! [`svg::render`] draws a result from the runes' strokes, without a font:
+//!
+//! ```
+//! use ipa2runes::{transliterate, Options};
+//!
+//! let result = transliterate("/naɪt/", Options::default());
+//! assert_eq!(result.output, "ᚾᚪᛁᛏ");
+//! assert!(result.unmapped.is_empty());
+//! ```
+
+pub mod inventory;
+pub mod ipa;
+pub mod layout;
+pub mod mapper;
+pub mod normalize;
+pub mod profile;
+pub mod reverse;
+pub mod strokes;
+pub mod svg;
+pub mod table;
+pub mod text;
+pub mod tokenize;
+
+use serde::{Deserialize, Serialize};
+
+pub use layout::{Layout, Orientation, Punctuation, Separator};
+pub use mapper::{Mapper, TableMapper};
+pub use normalize::normalize_ipa;
+pub use profile::{Profile, Rewritten};
+pub use table::{MappingTable, TableError};
+pub use text::{Dictionary, WordIpa};
+pub use tokenize::{Token, Tokenizer};
+
+use layout::RuneWriter;
+use normalize::SENTENCE_PUNCTUATION;
+
+/// A rough “tradition” label for the rune we choose.
+/// This is not Unicode’s classification—it's our explicit
+/// metadata for auditing the mapping.
+///
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Tradition {
+    /// Common to the older runic tradition and
+    /// present in Anglo-Saxon Futhorc usage.
+    FuthorcCore,
+
+    /// Letters strongly associated with the
+    /// expanded Anglo-Saxon Futhorc inventory
+    /// (Old English additions).
+    AngloSaxonExtension,
+
+    /// Letters of the older 24-rune row that the
+    /// Futhorc replaced (ansuz, kauna, jera, ...).
+    ElderFuthark,
+
+    /// Viking Age Younger Futhark forms, long-branch
+    /// and short-twig.
+    ScandinavianVariant,
+
+    /// Dotted and Latin-modelled runes of the
+    /// Scandinavian medieval row.
+    Medieval,
+
+    /// The twenty Ogham letters of the four
+    /// aicmí, as cut on the early stones.
+    Ogham,
+
+    /// The Ogham forfeda: supplementary
+    /// letters the manuscripts added.
+    Forfeda,
+
+    /// Our mapping hack: no historically clean
+    /// rune exists; we approximate with
+    /// something readable.
+    Approximation,
+}
+
+impl Tradition {
+    /// A tag as written in the tables: futhorc-core, ogham, ...
+    pub fn parse(s: &str) -> Option<Self> {
+        use serde::de::IntoDeserializer;
+        use serde::de::value::{Error, StrDeserializer};
+
+        let tag: StrDeserializer<Error> = s.into_deserializer();
+        Self::deserialize(tag).ok()
+    }
+}
+
+/// The Unicode block a mapping table writes in, and with it how words
+/// are laid out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Script {
+    /// The Runic block; words divided by runic punctuation.
+    #[default]
+    Runic,
+    /// The Ogham block; each word wrapped in feather marks.
+    Ogham,
+}
+
+impl Script {
+    /// Assigned codepoints of the script's block.
+    pub fn block(self) -> std::ops::RangeInclusive<u32> {
+        match self {
+            // U+16A0..U+16FF; the last seven are unassigned.
+            Script::Runic => 0x16A0..=0x16F8,
+            // U+1680..U+169F; the last three are unassigned.
+            Script::Ogham => 0x1680..=0x169C,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Script::Runic => "Runic",
+            Script::Ogham => "Ogham",
+        }
+    }
+}
+
+/// Which of a token's mappings to use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Mode {
+    /// The table's default mappings, approximations included.
+    Pragmatic,
+
+    /// Historically attested spellings only: tokens whose
+    /// runes are tagged Approximation are refused.
+    Strict,
+
+    /// One rune per sound wherever the table offers it.
+    Readable,
+}
+
+impl Mode {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "pragmatic" => Some(Mode::Pragmatic),
+            "strict" => Some(Mode::Strict),
+            "readable" => Some(Mode::Readable),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Mode::Pragmatic => "pragmatic",
+            Mode::Strict => "strict",
+            Mode::Readable => "readable",
+        }
+    }
+}
+
+/// What happens to IPA diacritics (ʰ, ̃, ̩, ...).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Diacritics {
+    /// Dropped; the sound is written as its base symbol.
+    Strip,
+    /// Written as an extra sound where the chart suggests one:
+    /// tʰ → th, ã → an, n̩ → ən. Other marks are still dropped.
+    Spell,
+}
+
+impl Diacritics {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "strip" => Some(Diacritics::Strip),
+            "spell" => Some(Diacritics::Spell),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Diacritics::Strip => "strip",
+            Diacritics::Spell => "spell",
+        }
+    }
+}
+
+/// Rune metadata: store BOTH the glyph and the
+/// Unicode code point to avoid ambiguity.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct RuneChar {
+    #[serde(rename = "glyph")]
+    pub ch: char,
+    pub codepoint: u32,
+    pub name: String,
+    pub tradition: Tradition,
+}
+
+/// A mapping result can be one rune or multiple
+/// runes (e.g., diphthongs, affricates).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mapping {
+    pub runes: Vec<RuneChar>,
+    pub rationale: String,
+}
+
+/// One of the defensible mappings for a token, with how strongly the
+/// table recommends it: 1 for the mode's own choice, less for the rest.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Alternative {
+    pub mapping: Mapping,
+    pub score: f64,
+}
+
+/* ===========================================================================
+ * Transliteration
+ * ===========================================================================
+ */
+
+/// How [`transliterate`] reads its input and writes runes.
+#[derive(Debug, Clone)]
+pub struct Options {
+    /// The IPA → rune table; the built-in Futhorc one by default.
+    pub table: MappingTable,
+    pub mode: Mode,
+    pub diacritics: Diacritics,
+    pub layout: Layout,
+    /// The input is English text, converted to IPA with the bundled
+    /// pronouncing dictionary first.
+    pub text: bool,
+    /// Dialect profile whose rewrites apply to the normalized IPA; set
+    /// it with [`Options::with_profile`], which also layers its token
+    /// overrides on `table`.
+    pub profile: Option<Profile>,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Self {
+            table: MappingTable::builtin("futhorc").expect("default table"),
+            mode: Mode::Pragmatic,
+            diacritics: Diacritics::Strip,
+            layout: Layout::default(),
+            text: false,
+            profile: None,
+        }
+    }
+}
+
+impl Options {
+    /// Read the input in a dialect: `profile`'s token overrides are
+    /// layered on the table and its rewrites applied to the normalized
+    /// IPA. Fails when an override uses a rune the table lacks.
+    pub fn with_profile(self, profile: Profile) -> Result<Self, TableError> {
+        Ok(Self {
+            table: profile.apply_to(&self.table)?,
+            profile: Some(profile),
+            ..self
+        })
+    }
+}
+
+/// The result of [`transliterate`]: the rune string and every step that
+/// led to it. Serializes to the `--json` trace.
+#[derive(Debug, Clone, Serialize)]
+pub struct Transliteration {
+    pub input: TraceInput,
+    pub table: String,
+    pub script: Script,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub profile: Option<String>,
+    pub mode: &'static str,
+    pub diacritics: &'static str,
+    pub tokens: Vec<TraceToken>,
+    pub unmapped: Vec<String>,
+    pub refused: Vec<String>,
+    pub output: String,
+}
+
+impl Transliteration {
+    /// The runes written, in order, without layout.
+    pub fn runes(&self) -> impl Iterator<Item = &RuneChar> {
+        self.tokens
+            .iter()
+            .filter(|t| t.status == TokenStatus::Mapped)
+            .flat_map(|t| &t.runes)
+    }
+
+    /// True when every token was written.
+    pub fn is_complete(&self) -> bool {
+        self.unmapped.is_empty() && self.refused.is_empty()
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceInput {
+    pub raw: String,
+    /// Present with --text: each word and the IPA it became.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub words: Option<Vec<WordIpa>>,
+    /// The IPA actually tokenized; token spans index into this string.
+    pub normalized: String,
+    /// With a profile: the normalized IPA before its rewrites, and each
+    /// rewrite that applied (offsets into `before_rewrites`, then into
+    /// the result of the rewrites before it).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before_rewrites: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub rewrites: Vec<Rewritten>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenStatus {
+    Mapped,
+    Unmapped,
+    Refused,
+    /// A word boundary; written as the separator.
+    Boundary,
+    /// Sentence punctuation; written per the punctuation setting.
+    Punctuation,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct TraceToken {
+    pub token: String,
+    /// Byte offsets into `input.normalized`, end exclusive.
+    pub span: [usize; 2],
+    pub rule: Option<String>,
+    /// Diacritics attached to the token, stripped or spelled.
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub diacritics: Vec<char>,
+    pub status: TokenStatus,
+    pub runes: Vec<RuneChar>,
+    pub rationale: Option<String>,
+}
+
+/// Run `input` through the whole pipeline.
+pub fn transliterate(input: &str, options: Options) -> Transliteration {
+    Transliterator::new(options).transliterate(input)
+}
+
+/// The pipeline set up once for many inputs: the table's tokenizer is
+/// compiled and its mapper built up front, as batch mode wants.
+#[derive(Debug, Clone)]
+pub struct Transliterator {
+    options: Options,
+    mapper: TableMapper,
+}
+
+impl Transliterator {
+    pub fn new(options: Options) -> Self {
+        Self {
+            mapper: TableMapper::new(options.table.clone(), options.mode, options.diacritics),
+            options,
+        }
+    }
+
+    /// Run `input` through the whole pipeline; see [`transliterate`].
+    pub fn transliterate(&self, input: &str) -> Transliteration {
+        let (options, mapper) = (&self.options, &self.mapper);
+        let prepared = prepare(input, options);
+        let tokens = mapper.tokenizer().tokenize(&prepared.normalized);
+        let (steps, output) = map_tokens(&tokens, mapper, options.layout);
+
+        let collect = |status| {
+            steps
+                .iter()
+                .filter(|t| t.status == status)
+                .map(|t| t.token.clone())
+                .collect()
+        };
+
+        Transliteration {
+            input: TraceInput {
+                raw: input.to_string(),
+                words: prepared.words,
+                normalized: prepared.normalized,
+                before_rewrites: prepared.before_rewrites,
+                rewrites: prepared.rewrites,
+            },
+            table: mapper.table().name.clone(),
+            script: mapper.table().script,
+            profile: options.profile.as_ref().map(|p| p.name.clone()),
+            mode: options.mode.as_str(),
+            diacritics: options.diacritics.as_str(),
+            unmapped: collect(TokenStatus::Unmapped),
+            refused: collect(TokenStatus::Refused),
+            tokens: steps,
+            output,
+        }
+    }
+
+    /// The `n` best transliterations of `input`; see [`n_best`].
+    pub fn n_best(&self, input: &str, n: usize) -> Vec<Ranked> {
+        let (options, mapper) = (&self.options, &self.mapper);
+        let normalized = prepare(input, options).normalized;
+        let tokens = mapper.tokenizer().tokenize(&normalized);
+        rank(&tokens, mapper, options.layout, n)
+    }
+}
+
+/// The input after the stages before tokenizing.
+struct Prepared {
+    words: Option<Vec<WordIpa>>,
+    before_rewrites: Option<String>,
+    normalized: String,
+    rewrites: Vec<Rewritten>,
+}
+
+/// Stages before tokenizing: English text through the pronunciation
+/// front end, normalization, then the profile's rewrites.
+fn prepare(input: &str, options: &Options) -> Prepared {
+    let (ipa, words) = if options.text {
+        let (ipa, words) = text::text_to_ipa(input, Dictionary::shared());
+        (ipa, Some(words))
+    } else {
+        (input.to_string(), None)
+    };
+
+    let normalized = normalize_ipa(&ipa);
+    match &options.profile {
+        Some(profile) => {
+            let (rewritten, rewrites) = profile.rewrite(&normalized);
+            Prepared {
+                words,
+                before_rewrites: Some(normalized),
+                normalized: rewritten,
+                rewrites,
+            }
+        }
+        None => Prepared {
+            words,
+            before_rewrites: None,
+            normalized,
+            rewrites: Vec::new(),
+        },
+    }
+}
+
+/// Map every token and lay the runes out: runes of one word together,
+/// boundaries and punctuation per `layout`. Returns one trace entry per
+/// token and the rune string.
+pub fn map_tokens(
+    tokens: &[Token],
+    mapper: &dyn Mapper,
+    layout: Layout,
+) -> (Vec<TraceToken>, String) {
+    let mut steps = Vec::with_capacity(tokens.len());
+    let mut writer = RuneWriter::new(layout, mapper.script());
+
+    for tok in tokens {
+        let layout_step = match role(tok) {
+            Role::Sound => None,
+            Role::Boundary => {
+                writer.boundary();
+                Some((TokenStatus::Boundary, "word boundary".to_string()))
+            }
+            Role::Punctuation(c) => {
+                let rendered = writer
+                    .punctuation(c)
+                    .map(|g| format!("written as {g:?}"))
+                    .unwrap_or_else(|| "dropped".to_string());
+                Some((TokenStatus::Punctuation, format!("sentence punctuation {c:?}, {rendered}")))
+            }
+        };
+        if let Some((status, rationale)) = layout_step {
+            steps.push(TraceToken {
+                token: tok.text.clone(),
+                span: [tok.start, tok.end],
+                rule: None,
+                diacritics: tok.diacritics.clone(),
+                status,
+                runes: Vec::new(),
+                rationale: Some(rationale),
+            });
+            continue;
+        }
+
+        let mapping = mapper.map(tok);
+        let status = match &mapping {
+            Some(mapping) if mapper.refuses(mapping) => TokenStatus::Refused,
+            Some(_) => TokenStatus::Mapped,
+            None => TokenStatus::Unmapped,
+        };
+        if let (TokenStatus::Mapped, Some(mapping)) = (status, &mapping) {
+            writer.runes(&mapping.runes);
+        }
+
+        let (runes, rationale) = match mapping {
+            Some(m) => (m.runes, Some(m.rationale)),
+            None => (Vec::new(), None),
+        };
+        steps.push(TraceToken {
+            token: tok.text.clone(),
+            span: [tok.start, tok.end],
+            rule: tok.desc.clone(),
+            diacritics: tok.diacritics.clone(),
+            status,
+            runes,
+            rationale,
+        });
+    }
+
+    (steps, writer.finish())
+}
+
+/// What a token is to the layout.
+enum Role {
+    Boundary,
+    Punctuation(char),
+    Sound,
+}
+
+/// Word boundaries and sentence punctuation are layout, not sounds.
+fn role(tok: &Token) -> Role {
+    let mut chars = tok.key.chars();
+    match (chars.next(), chars.next()) {
+        (Some(' '), None) => Role::Boundary,
+        (Some(c), None) if SENTENCE_PUNCTUATION.contains(&c) => Role::Punctuation(c),
+        _ => Role::Sound,
+    }
+}
+
+/* ===========================================================================
+ * Alternatives
+ * ===========================================================================
+ */
+
+/// One whole transliteration among the n best, see [`n_best`].
+#[derive(Debug, Clone, Serialize)]
+pub struct Ranked {
+    pub output: String,
+    /// Product of the scores of the alternatives chosen; 1 for the
+    /// default transliteration.
+    pub score: f64,
+    /// The tokens written with other than their first alternative.
+    pub choices: Vec<Choice>,
+}
+
+/// A token written with one of its other alternatives.
+#[derive(Debug, Clone, Serialize)]
+pub struct Choice {
+    pub token: String,
+    /// Byte offsets into the normalized input, end exclusive.
+    pub span: [usize; 2],
+    pub runes: Vec<RuneChar>,
+    pub score: f64,
+    pub rationale: String,
+}
+
+/// The `n` best transliterations of `input`, best first: every
+/// combination of the tokens' [alternatives](Mapper::alternatives),
+/// ranked by the product of their scores. The first is the output of
+/// [`transliterate`], except that alternatives strict mode refuses are
+/// left out rather than reported, so a refused token may still be written
+/// another way. Outputs that come out identical are listed once.
+pub fn n_best(input: &str, options: Options, n: usize) -> Vec<Ranked> {
+    Transliterator::new(options).n_best(input, n)
+}
+
+fn rank(tokens: &[Token], mapper: &TableMapper, layout: Layout, n: usize) -> Vec<Ranked> {
+    let alternatives: Vec<Vec<Alternative>> = tokens
+        .iter()
+        .map(|tok| match role(tok) {
+            Role::Sound => mapper
+                .alternatives(tok)
+                .into_iter()
+                .filter(|a| !mapper.refuses(&a.mapping))
+                .collect(),
+            _ => Vec::new(),
+        })
+        .collect();
+
+    // Beam search over the tokens; keeping the n best prefixes is exact,
+    // since every score is a product of factors in (0, 1]. A token with
+    // nothing to offer keeps index 0 and writes nothing.
+    let mut beam: Vec<(f64, Vec<usize>)> = vec![(1.0, Vec::new())];
+    for offered in &alternatives {
+        if offered.len() < 2 {
+            for (score, picks) in &mut beam {
+                *score *= offered.first().map_or(1.0, |a| a.score);
+                picks.push(0);
+            }
+            continue;
+        }
+        let mut next = Vec::with_capacity(beam.len() * offered.len());
+        for (score, picks) in &beam {
+            for (i, alternative) in offered.iter().enumerate() {
+                let mut picks = picks.clone();
+                picks.push(i);
+                next.push((score * alternative.score, picks));
+            }
+        }
+        // Stable: ties keep the better prefix, then the better alternative.
+        next.sort_by(|a, b| b.0.total_cmp(&a.0));
+        next.truncate(n.max(1));
+        beam = next;
+    }
+
+    let mut ranked: Vec<Ranked> = Vec::with_capacity(n);
+    for (score, picks) in beam.into_iter().take(n) {
+        let mut writer = RuneWriter::new(layout, mapper.script());
+        let mut choices = Vec::new();
+        for ((tok, offered), &pick) in tokens.iter().zip(&alternatives).zip(&picks) {
+            match role(tok) {
+                Role::Boundary => writer.boundary(),
+                Role::Punctuation(c) => {
+                    writer.punctuation(c);
+                }
+                Role::Sound => {
+                    let Some(chosen) = offered.get(pick) else {
+                        continue;
+                    };
+                    writer.runes(&chosen.mapping.runes);
+                    if pick > 0 {
+                        choices.push(Choice {
+                            token: tok.text.clone(),
+                            span: [tok.start, tok.end],
+                            runes: chosen.mapping.runes.clone(),
+                            score: chosen.score,
+                            rationale: chosen.mapping.rationale.clone(),
+                        });
+                    }
+                }
+            }
+        }
+
+        let output = writer.finish();
+        if !ranked.iter().any(|r| r.output == output) {
+            ranked.push(Ranked {
+                output,
+                score,
+                choices,
+            });
+        }
+    }
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_table(alphabet: &str) -> Options {
+        Options {
+            table: MappingTable::builtin(alphabet).unwrap(),
+            ..Options::default()
+        }
+    }
+
+    #[test]
+    fn transliterates_with_defaults() {
+        let t = transliterate("/naɪt/", Options::default());
+        assert_eq!(t.output, "ᚾᚪᛁᛏ");
+        assert_eq!(t.table, "futhorc");
+        assert!(t.is_complete());
+        assert_eq!(t.runes().count(), 4);
+    }
+
+    #[test]
+    fn reports_unmapped_tokens() {
+        let t = transliterate("na☃t", Options::default());
+        assert_eq!(t.unmapped, ["☃"]);
+        assert_eq!(t.output, "ᚾᚪᛏ");
+        assert!(!t.is_complete());
+    }
+
+    #[test]
+    fn strict_mode_refuses_approximations() {
+        let options = Options {
+            mode: Mode::Strict,
+            ..Options::default()
+        };
+        let t = transliterate("ʒ", options);
+        assert_eq!(t.refused, ["ʒ"]);
+        assert_eq!(t.output, "");
+    }
+
+    #[test]
+    fn lays_out_words_and_punctuation() {
+        let t = transliterate("/ðə naɪt, ðə deɪ./", Options::default());
+        assert_eq!(t.output, "ᚦᛖ᛫ᚾᚪᛁᛏ᛬ᚦᛖ᛫ᛞᛖᛁ᛭");
+
+        let options = Options {
+            layout: Layout {
+                separator: Separator::Space,
+                punctuation: Punctuation::Keep,
+                ..Layout::default()
+            },
+            ..Options::default()
+        };
+        let t = transliterate("/ðə naɪt, ðə deɪ./", options);
+        assert_eq!(t.output, "ᚦᛖ ᚾᚪᛁᛏ, ᚦᛖ ᛞᛖᛁ.");
+    }
+
+    #[test]
+    fn ogham_wraps_words_in_feather_marks() {
+        let t = transliterate("/ðə naɪt, ðə deɪ./", with_table("ogham"));
+        assert_eq!(t.script, Script::Ogham);
+        assert_eq!(t.output, "᚛ᚇᚆᚓ᚜\u{1680}᚛ᚅᚙᚈ᚜\u{1680}᚛ᚇᚆᚓ᚜\u{1680}᚛ᚇᚓᚔ᚜");
+        assert!(t.is_complete());
+
+        let options = Options {
+            layout: Layout {
+                punctuation: Punctuation::Keep,
+                ..Layout::default()
+            },
+            ..with_table("ogham")
+        };
+        let t = transliterate("/naɪt, deɪ./", options);
+        assert_eq!(t.output, "᚛ᚅᚙᚈ᚜, ᚛ᚇᚓᚔ᚜.");
+    }
+
+    #[test]
+    fn forfeda_write_diphthongs_except_in_strict_mode() {
+        let t = transliterate("/bɔɪ/", with_table("ogham"));
+        assert_eq!(t.output, "᚛ᚁᚖ᚜");
+        assert_eq!(t.runes().nth(1).unwrap().tradition, Tradition::Forfeda);
+
+        let options = Options {
+            mode: Mode::Strict,
+            ..with_table("ogham")
+        };
+        let t = transliterate("/bɔɪ/", options);
+        assert_eq!(t.output, "᚛ᚁᚑᚔ᚜");
+    }
+
+    #[test]
+    fn vertical_output() {
+        let vertical = Layout {
+            orientation: Orientation::Vertical,
+            ..Layout::default()
+        };
+        let t = transliterate("/naɪt/", Options { layout: vertical, ..Options::default() });
+        assert_eq!(t.output, "ᚾ\nᚪ\nᛁ\nᛏ");
+
+        // Ogham is read from the bottom up.
+        let t = transliterate("/naɪt/", Options { layout: vertical, ..with_table("ogham") });
+        assert_eq!(t.output, "᚜\nᚈ\nᚙ\nᚅ\n᚛");
+    }
+
+    #[test]
+    fn n_best_ranks_whole_transliterations() {
+        let ranked = n_best("/kʌt/", Options::default(), 4);
+        assert_eq!(ranked.len(), 4);
+        assert_eq!(ranked[0].output, transliterate("/kʌt/", Options::default()).output);
+        assert_eq!(ranked[0].score, 1.0);
+        assert!(ranked[0].choices.is_empty());
+        assert!(ranked.windows(2).all(|w| w[0].score >= w[1].score));
+        // CALC for /k/ is the best single change.
+        assert_eq!(ranked[1].output, "ᛣᚪᛏ");
+        assert_eq!(ranked[1].choices.len(), 1);
+        assert_eq!(ranked[1].choices[0].token, "k");
+    }
+
+    #[test]
+    fn n_best_skips_what_strict_mode_refuses() {
+        let options = Options {
+            mode: Mode::Strict,
+            ..Options::default()
+        };
+        for ranked in n_best("/dɒɡ/", options, 10) {
+            assert!(!ranked.output.contains('ᛑ'), "{}", ranked.output);
+        }
+    }
+
+    #[test]
+    fn dialect_profiles() {
+        let rp = Options::default().with_profile(Profile::builtin("rp").unwrap()).unwrap();
+        let t = transliterate("/kɑr pɑrk/", rp);
+        assert_eq!(t.output, transliterate("/kɑ pɑk/", Options::default()).output);
+        assert_eq!(t.profile.as_deref(), Some("rp"));
+        assert_eq!(t.input.rewrites.len(), 2);
+        assert_eq!(t.input.before_rewrites.as_deref(), Some("kɑr pɑrk"));
+
+        let oe = Options::default()
+            .with_profile(Profile::builtin("old-english").unwrap())
+            .unwrap();
+        let t = transliterate("/æɑɣe/", oe);
+        assert_eq!(t.output, "ᛠᚷᛖ");
+        assert!(t.is_complete());
+    }
+
+    #[test]
+    fn english_text_front_end() {
+        let options = Options {
+            text: true,
+            ..Options::default()
+        };
+        let t = transliterate("good night", options);
+        assert_eq!(t.output, "ᚷᚢᛞ᛫ᚾᚪᛁᛏ");
+        assert_eq!(t.input.words.as_ref().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn other_alphabets() {
+        for alphabet in MappingTable::alphabets() {
+            let t = transliterate("/naɪt/", with_table(alphabet));
+            assert_eq!(t.table, alphabet);
+            assert!(t.is_complete(), "{alphabet}");
+            assert!(!t.output.is_empty());
+        }
+    }
+
+    #[test]
+    fn tables_round_trip_through_toml() {
+        for alphabet in MappingTable::alphabets() {
+            let table = MappingTable::builtin(alphabet).unwrap();
+            let saved = MappingTable::from_toml(&table.to_toml(), "<saved>").unwrap();
+            assert_eq!(saved.runes, table.runes, "{alphabet}");
+            assert_eq!(saved.rules().len(), table.rules().len());
+            for mode in [Mode::Pragmatic, Mode::Strict, Mode::Readable] {
+                for rule in table.rules() {
+                    assert_eq!(saved.rule(&rule.ipa).unwrap().ranked(mode), rule.ranked(mode));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn set_rule_keeps_rules_ordered_and_indexed() {
+        let mut table = MappingTable::builtin("futhorc").unwrap();
+        let template = table.rule("t").unwrap().clone();
+        let count = table.rules().len();
+
+        table.set_rule(table::TokenRule {
+            ipa: "tʃʃ".to_string(),
+            ..template.clone()
+        });
+        table.set_rule(table::TokenRule {
+            desc: "replaced".to_string(),
+            ..template
+        });
+        assert_eq!(table.rules().len(), count + 1);
+        assert_eq!(table.rules()[0].ipa, "tʃʃ");
+        assert_eq!(table.rule("t").unwrap().desc, "replaced");
+        for rule in table.rules() {
+            assert_eq!(table.rule(&rule.ipa).unwrap().ipa, rule.ipa);
+        }
+        let lengths: Vec<usize> = table.rules().iter().map(|r| r.ipa.len()).collect();
+        assert!(lengths.is_sorted_by(|a, b| a >= b));
+    }
+
+    #[test]
+    fn spans_index_the_normalized_input() {
+        let t = transliterate("/ˈtʃɜːtʃ/", Options::default());
+        for step in &t.tokens {
+            let [start, end] = step.span;
+            assert_eq!(&t.input.normalized[start..end], step.token);
+        }
+    }
+
+    /* -----------------------------------------------------------------------
+     * Properties, on generated input
+     * -----------------------------------------------------------------------
+     */
+
+    /// `count` pseudo-random strings, the same every run: chart letters,
+    /// diacritics, tie bars, stress marks, delimiters, spaces, punctuation,
+    /// precomposed letters and characters no table knows.
+    fn random_inputs(count: usize) -> Vec<String> {
+        let pool: Vec<char> = ipa::CHART
+            .iter()
+            .flat_map(|s| s.ipa.chars())
+            .chain(ipa::DIACRITICS.iter().map(|d| d.mark))
+            .chain("\u{361}\u{35C}ˈˌ/[]   \t.,;:!?-'ãéçñ☃ᚠAZ1".chars())
+            .collect();
+        // xorshift64
+        let mut state: u64 = 0x2545_F491_4F6C_DD1D;
+        let mut next = move || {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            state as usize
+        };
+        (0..count)
+            .map(|_| {
+                let len = next() % 24;
+                (0..len).map(|_| pool[next() % pool.len()]).collect()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn normalization_is_idempotent() {
+        for input in random_inputs(20000) {
+            let once = normalize_ipa(&input);
+            assert_eq!(normalize_ipa(&once), once, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn tokens_tile_the_normalized_input() {
+        for alphabet in MappingTable::alphabets() {
+            let tokenizer = Tokenizer::new(&MappingTable::builtin(alphabet).unwrap());
+            for input in random_inputs(2000) {
+                let normalized = normalize_ipa(&input);
+                let tokens = tokenizer.tokenize(&normalized);
+                let joined: String = tokens.iter().map(|t| t.text.as_str()).collect();
+                assert_eq!(joined, normalized, "{alphabet}: {input:?}");
+
+                let mut at = 0;
+                for t in &tokens {
+                    assert_eq!(t.start, at, "{alphabet}: {input:?}");
+                    assert_eq!(&normalized[t.start..t.end], t.text);
+                    at = t.end;
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_token_is_accounted_for() {
+        let t = Transliterator::new(Options::default());
+        for input in random_inputs(2000) {
+            let result = t.transliterate(&input);
+            for step in &result.tokens {
+                let written = !step.runes.is_empty();
+                match step.status {
+                    TokenStatus::Unmapped => assert!(result.unmapped.contains(&step.token)),
+                    TokenStatus::Refused => assert!(result.refused.contains(&step.token)),
+                    TokenStatus::Boundary | TokenStatus::Punctuation => assert!(!written),
+                    TokenStatus::Mapped => {}
+                }
+            }
+            let runes: String = result.runes().map(|r| r.ch).collect();
+            assert!(
+                runes.chars().all(|c| result.output.contains(c)),
+                "{input:?}"
+            );
+        }
+    }
+
+    /* -----------------------------------------------------------------------
+     * Corpus
+     * -----------------------------------------------------------------------
+     */
+
+    const CORPUS: &[(&str, &str)] = &[
+        ("futhorc", include_str!("../corpus/futhorc.tsv")),
+        ("elder", include_str!("../corpus/elder.tsv")),
+        ("younger-long", include_str!("../corpus/younger-long.tsv")),
+        ("younger-short", include_str!("../corpus/younger-short.tsv")),
+        ("medieval", include_str!("../corpus/medieval.tsv")),
+        ("ogham", include_str!("../corpus/ogham.tsv")),
+    ];
+
+    #[test]
+    fn corpus_words_are_written_as_recorded() {
+        let dictionary = Dictionary::shared();
+        let mut changed = Vec::new();
+        for (alphabet, corpus) in CORPUS {
+            let t = Transliterator::new(with_table(alphabet));
+            let lines = corpus
+                .lines()
+                .filter(|l| !l.is_empty() && !l.starts_with('#'));
+            for line in lines {
+                let [word, ipa, runes] = line.split('\t').collect::<Vec<_>>()[..] else {
+                    panic!("{alphabet}: not word, IPA and runes: {line:?}");
+                };
+                let looked_up = dictionary.lookup(word);
+                if looked_up.as_deref() != Some(ipa) {
+                    changed.push(format!(
+                        "{alphabet}: {word} is now {looked_up:?}, not {ipa}"
+                    ));
+                }
+                let output = t.transliterate(ipa).output;
+                if output != runes {
+                    changed.push(format!(
+                        "{alphabet}: {word} /{ipa}/ is now {output}, not {runes}"
+                    ));
+                }
+            }
+        }
+        assert!(
+            changed.is_empty(),
+            "{} corpus lines changed:\n{}",
+            changed.len(),
+            changed.join("\n")
+        );
+    }
+
+    #[test]
+    fn younger_corpus_never_writes_a_rune_twice() {
+        // The Younger Futhark writes no geminates, so a doubled rune in a
+        // recorded word is a table bug snapshotted into the corpus.
+        let younger = CORPUS.iter().filter(|(a, _)| a.starts_with("younger"));
+        for (alphabet, corpus) in younger {
+            let lines = corpus
+                .lines()
+                .filter(|l| !l.is_empty() && !l.starts_with('#'));
+            for line in lines {
+                let runes: Vec<char> = line.rsplit('\t').next().unwrap().chars().collect();
+                assert!(
+                    runes.windows(2).all(|w| w[0] != w[1]),
+                    "{alphabet}: {line:?} writes a rune twice"
+                );
+            }
+        }
+    }
+}
@@ -1,226 +1,32 @@
 // SPDX-License-Identifier: MIT
 // Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
-//
-/*!
-===============================================================================
-ipa2runes — IPA → Runic Transliteration Tool
-===============================================================================
 
-Overview
---------
-This program converts an input string written in IPA (International Phonetic
-Alphabet) into a sequence of runic characters, primarily using letters from
-the Anglo-Saxon runic tradition (commonly called the Futhorc).
+//! ipa2runes: transliterate IPA, or English text, into runes.
+//!
+//! The pipeline lives in the library crate. This binary parses the command
+//! line (cli.rs), loads the table and profile it names, and prints each
+//! result as a banner, bare runes, TSV lines or JSON. `--help` lists the
+//! options; README.md describes the pipeline, tables, modes and profiles.
 
-The tool is designed as an educational and experimental encoding pipeline,
-not as a historically strict reconstruction of Old English writing. Its goal
-is to provide a deterministic and auditable mapping from phonetic input to
-runic output for experimentation, visualization, and fun.
-
-Example:
-
-    Input IPA:  /naɪt/
-    Tokens:     n + aɪ + t
-    Output:     ᚾᚪᛁᛏ
-
-This allows modern words to be represented using runes through phonetic
-approximation.
-
--------------------------------------------------------------------------------
-Historical Background
--------------------------------------------------------------------------------
-
-Runes are letters from early Germanic writing systems used across Northern
-Europe roughly between 150 CE and 1200 CE.
-
-Multiple runic alphabets existed:
-
-  • Elder Futhark – earliest common runic system (~24 letters)
-  • Younger Futhark – Viking Age Scandinavian system (~16 letters)
-  • Anglo-Saxon Futhorc – expanded system used in England
-
-The Anglo-Saxon Futhorc expanded the rune inventory to represent additional
-sounds found in Old English, particularly vowels. Because of this expansion,
-it is better suited for representing modern phonetics than earlier runic
-alphabets.
-
-Unicode places all runic letters into a single RUNIC block, mixing symbols
-from multiple traditions. This program explicitly tags runes by tradition
-to make mapping decisions transparent.
-
--------------------------------------------------------------------------------
-Purpose of This Tool
--------------------------------------------------------------------------------
-
-This program treats runes as a phonetic encoding target rather than as a
-strict historical writing system.
-
-Goals:
-
-  • Convert IPA phonemes to readable rune sequences
-  • Show mapping decisions clearly
-  • Allow experimentation with phonetic encodings
-  • Provide verbose and auditable output
-  • Support future extension into encoding pipelines
-
-Non-goals:
-
-  • Perfect historical accuracy
-  • Reconstruction of Old English orthography
-  • Linguistic scholarship tooling
-
-This is an engineering tool and educational experiment.
-
--------------------------------------------------------------------------------
-How Conversion Works
--------------------------------------------------------------------------------
-
-The processing pipeline:
-
-    IPA input
-        ↓
-    Normalize input
-        ↓
-    Tokenize into phonemes (greedy matching)
-        ↓
-    Map phonemes to runes
-        ↓
-    Produce runic output string
-
-Tokenization identifies multi-character phonemes first, such as:
-
-    tʃ   (church)
-    dʒ   (judge)
-    aɪ   (time)
-    oʊ   (go)
-
-Each phoneme is then mapped to:
-
-  • a direct rune match,
-  • a reasonable approximation,
-  • or a sequence of runes.
-
-Some sounds do not exist in historical rune inventories; these are handled
-using approximation rules.
-
--------------------------------------------------------------------------------
-Verbosity and Auditability
--------------------------------------------------------------------------------
-
-Verbose mode explains:
-
-  • input normalization
-  • phoneme tokenization
-  • rune mapping decisions
-  • output rune inventory
-  • mapping rationale
-
-This makes the transliteration process transparent and easy to debug or
-extend.
-
--------------------------------------------------------------------------------
-Rune Metadata
--------------------------------------------------------------------------------
-
-Each rune is stored with:
-
-  • The rune glyph itself
-  • Unicode code point
-  • Traditional name
-  • Historical tradition classification
-
-Example:
-
-    ᚠ (U+16A0 FEOH)
-
-This prevents confusion when working across fonts or terminals.
-
--------------------------------------------------------------------------------
-Limitations
--------------------------------------------------------------------------------
-
-  • Modern English phonetics differ greatly from Old English.
-  • Many sounds lack direct rune equivalents.
-  • Results are approximate, not historical spellings.
-  • Dialect differences may produce alternative outputs.
-
-Despite these limitations, output remains readable and consistent.
-
--------------------------------------------------------------------------------
-Future Directions
--------------------------------------------------------------------------------
-
-Possible future improvements:
-
-  • Text → IPA conversion
-  • Mode selection (strict vs readable mappings)
-  • JSON mapping definitions
-  • JSON trace output
-  • Extended rune inventories
-  • Encoding visualization tools
-  • Interactive transliteration utilities
-
--------------------------------------------------------------------------------
-Summary
--------------------------------------------------------------------------------
-
-ipa2runes demonstrates how historical writing systems can be explored as
-phonetic encoding targets using modern tooling. It is intended as a fun,
-educational, and extensible experiment rather than a scholarly instrument.
-
-Enjoy experimenting with runes.
-
-===============================================================================
-*/
+mod cli;
+mod repl;
+mod runes;
 
+use std::collections::BTreeMap;
 use std::env;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, IsTerminal};
+use std::path::Path;
 
-/// A rough “tradition” label for the rune we choose.
-/// This is not Unicode’s classification—it's our explicit 
-/// metadata for auditing the mapping.
-///
-#[allow(dead_code)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
-enum Tradition {
-    /// Common to the older runic tradition and 
-    /// present in Anglo-Saxon Futhorc usage.
-    FuthorcCore,
-
-    /// Letters strongly associated with the 
-    /// expanded Anglo-Saxon Futhorc inventory
-    /// (Old English additions).
-    AngloSaxonExtension,
-
-    /// Not used in this minimal tool, but 
-    /// included for completeness.
-    ScandinavianVariant,
-
-    /// Our mapping hack: no historically clean 
-    /// rune exists; we approximate with
-    /// something readable.
-    Approximation,
-}
-
-/// Rune metadata: store BOTH the glyph and the 
-/// Unicode code point to avoid ambiguity.
-#[derive(Debug, Clone, Copy)]
-struct RuneChar {
-    ch: char,
-    codepoint: u32,
-    name: &'static str,
-    tradition: Tradition,
-}
+use ipa2runes::reverse::{self, ReadBack, Reading};
+use ipa2runes::svg;
+use ipa2runes::text::Source;
+use ipa2runes::{
+    MappingTable, Options, Orientation, Profile, Ranked, RuneChar, Script, TokenStatus,
+    Transliteration, Transliterator, n_best, transliterate,
+};
 
-impl RuneChar {
-    const fn new(ch: char, codepoint: u32, name: &'static str, tradition: Tradition) -> Self {
-        Self {
-            ch,
-            codepoint,
-            name,
-            tradition,
-        }
-    }
-}
+use cli::{ArgError, Args, Command, Input};
 
 /// Helper: format a rune as "ᚠ(U+16A0 FEHU/FEOH)" etc.
 fn fmt_rune(r: &RuneChar) -> String {
@@ -230,570 +36,497 @@ fn fmt_rune(r: &RuneChar) -> String {
     )
 }
 
-/// A mapping result can be one rune or multiple 
-/// runes (e.g., diphthongs, affricates).
-#[derive(Debug, Clone)]
-struct Mapping {
-    runes: Vec<RuneChar>,
-    rationale: &'static str,
-}
-
-/// Greedy token match for IPA parsing.
-#[derive(Debug, Clone)]
-struct TokenRule {
-    ipa: &'static str,
-    desc: &'static str,
-}
-
-///
-/// Normalize IPA input:
-///
-/// - strip surrounding slashes /.../
-/// - remove common stress markers ˈ ˌ
-/// - remove spaces
-/// - keep IPA symbols; keep ː (length) but we’ll treat it as ignorable
-///
-fn normalize_ipa(input: &str) -> String {
-    let mut s = input.trim().to_string();
-    if s.starts_with('/') && s.ends_with('/') && s.len() >= 2 {
-        s = s[1..s.len() - 1].to_string();
+/// Verbose mode: explain every step of a transliteration on stderr.
+fn explain(t: &Transliteration) {
+    if let Some(words) = &t.input.words {
+        eprintln!("[text] raw: {:?}", t.input.raw);
+        for w in words {
+            let source = match w.source {
+                Source::Dictionary => "dictionary",
+                Source::Rules => "letter-to-sound rules",
+            };
+            eprintln!("[text] {:<16} -> /{}/ ({})", w.word, w.ipa, source);
+        }
+        eprintln!();
+    } else {
+        eprintln!("[input] raw:        {:?}", t.input.raw);
     }
-    s.chars()
-        .filter(|&c| {
-            c != 'ˈ'
-                && c != 'ˌ'
-                && !c.is_ascii_punctuation()
-                && c != ' '
-                && c != '\t'
-                && c != '\n'
-                && c != '\r'
-        })
-        .collect()
-}
-
-///
-/// Tokenize IPA with greedy longest-match against a rule list.
-/// Any unknown char becomes its own token so we can explain failures.
-///
-fn tokenize_ipa(ipa: &str, verbose: bool) -> Vec<String> {
-    // Order matters: longer sequences must come before shorter ones.
-    // This is a pragmatic starter set: expand as your project needs.
-    let rules = [
-        // Affricates
-        TokenRule {
-            ipa: "tʃ",
-            desc: "voiceless postalveolar affricate (ch)",
-        },
-        TokenRule {
-            ipa: "dʒ",
-            desc: "voiced postalveolar affricate (j)",
-        },
-        TokenRule {
-            ipa: "ʤ",
-            desc: "voiced postalveolar affricate ligature (j)",
-        },
-        // Common English diphthongs
-        TokenRule {
-            ipa: "aɪ",
-            desc: "diphthong (as in 'time')",
-        },
-        TokenRule {
-            ipa: "eɪ",
-            desc: "diphthong (as in 'day')",
-        },
-        TokenRule {
-            ipa: "oʊ",
-            desc: "diphthong (as in 'go')",
-        },
-        TokenRule {
-            ipa: "aʊ",
-            desc: "diphthong (as in 'now')",
-        },
-        TokenRule {
-            ipa: "ɔɪ",
-            desc: "diphthong (as in 'boy')",
-        },
-        // Common multichar consonant symbols
-        TokenRule {
-            ipa: "θ",
-            desc: "voiceless dental fricative (th in 'thin')",
-        },
-        TokenRule {
-            ipa: "ð",
-            desc: "voiced dental fricative (th in 'this')",
-        },
-        TokenRule {
-            ipa: "ŋ",
-            desc: "velar nasal (ng in 'sing')",
-        },
-        TokenRule {
-            ipa: "ʃ",
-            desc: "voiceless postalveolar fricative (sh)",
-        },
-        TokenRule {
-            ipa: "ʒ",
-            desc: "voiced postalveolar fricative (zh)",
-        },
-        // Length marker (we treat it as ignorable token)
-        TokenRule {
-            ipa: "ː",
-            desc: "length marker",
-        },
-    ];
-
-    let mut out = Vec::new();
-    let mut i = 0;
-    while i < ipa.len() {
-        let rest = &ipa[i..];
-
-        // Try match any rule
-        let mut matched: Option<&TokenRule> = None;
-        for rule in &rules {
-            if rest.starts_with(rule.ipa) {
-                matched = Some(rule);
-                break;
-            }
+    if let Some(before) = &t.input.before_rewrites {
+        eprintln!("[input] normalized: {:?}", before);
+        for r in &t.input.rewrites {
+            eprintln!("[profile] {:?} -> {:?} at byte {}: {}", r.from, r.to, r.at, r.rationale);
         }
-
-        if let Some(rule) = matched {
-            out.push(rule.ipa.to_string());
-            if verbose {
-                eprintln!("[tokenize] matched {:?}: {}", rule.ipa, rule.desc);
+        eprintln!("[input] rewritten:  {:?}", t.input.normalized);
+    } else {
+        eprintln!("[input] normalized: {:?}", t.input.normalized);
+    }
+    eprintln!();
+
+    for step in &t.tokens {
+        let fallback = matches!(
+            step.status,
+            TokenStatus::Unmapped | TokenStatus::Boundary | TokenStatus::Punctuation
+        );
+        match &step.rule {
+            Some(rule) => eprintln!("[tokenize] matched {:?}: {}", step.token, rule),
+            None if fallback => eprintln!("[tokenize] fallback single char token: {:?}", step.token),
+            None => eprintln!("[tokenize] matched {:?}", step.token),
+        }
+        for c in &step.diacritics {
+            let name = ipa2runes::ipa::diacritic(*c).map_or("combining mark", |d| d.name);
+            eprintln!("[tokenize]   diacritic {:?}: {}", c, name);
+        }
+    }
+    eprintln!();
+    let texts: Vec<&str> = t.tokens.iter().map(|s| s.token.as_str()).collect();
+    eprintln!("[tokens] {:?}", texts);
+    eprintln!();
+
+    for (idx, step) in t.tokens.iter().enumerate() {
+        eprintln!("[map] token[{idx}] = {:?}", step.token);
+        let rationale = step.rationale.as_deref().unwrap_or_default();
+        let rendered: Vec<String> = step.runes.iter().map(fmt_rune).collect();
+        match step.status {
+            TokenStatus::Boundary | TokenStatus::Punctuation => {
+                eprintln!("      -> [layout] {rationale}");
             }
-            i += rule.ipa.len();
-        } else {
-            // fallback: one Unicode scalar
-            let ch = rest.chars().next().unwrap();
-            out.push(ch.to_string());
-            if verbose {
-                eprintln!("[tokenize] fallback single char token: {:?}", ch);
+            TokenStatus::Refused => {
+                eprintln!("      -> [refused] strict mode rejects approximation: {}",
+                          rendered.join(" + "));
+            }
+            TokenStatus::Mapped if step.runes.is_empty() => {
+                eprintln!("      -> (no output) {rationale}");
+            }
+            TokenStatus::Mapped => {
+                eprintln!("      -> {}", rendered.join(" + "));
+                eprintln!("         rationale: {rationale}");
+            }
+            TokenStatus::Unmapped => {
+                eprintln!("      -> [unmapped] no rule for token {:?}.", step.token);
             }
-            i += ch.len_utf8();
         }
+        eprintln!();
     }
 
-    out
-}
-
-///
-/// Rune constants (glyph + Unicode + friendly name).
-///
-/// Notes:
-/// - Names here are Unicode-ish / commonly used runic names; treat them as
-///   labels, not strict philology.
-/// - Tradition tags are for our *decision audit*.
-///
-#[allow(dead_code)]
-mod runes {
-    use super::{RuneChar, Tradition};
-
-    // Core-ish / widely used in Futhorc contexts
-    pub const FEOH:  RuneChar = RuneChar::new('ᚠ', 0x16A0, "feoh (fehu)", Tradition::FuthorcCore);
-    pub const UR:    RuneChar = RuneChar::new('ᚢ', 0x16A2, "ur", Tradition::FuthorcCore);
-    pub const THORN: RuneChar = RuneChar::new('ᚦ', 0x16A6, "thorn", Tradition::FuthorcCore);
-
-    // Old English distinct “os” form is often represented with ᚩ in Unicode (RUNI LETTER OS)
-    pub const OS:   RuneChar = RuneChar::new('ᚩ', 0x16A9, "os", Tradition::AngloSaxonExtension);
-
-    pub const RAD:  RuneChar = RuneChar::new('ᚱ', 0x16B1, "rad", Tradition::FuthorcCore);
-
-    // Unicode distinguishes KAUNA/KAUN/CEN; for Futhorc transliteration we use CEN (ᚳ).
-    pub const CEN:   RuneChar = RuneChar::new('ᚳ', 0x16B3, "cen", Tradition::AngloSaxonExtension);
-
-    pub const GYFU:  RuneChar = RuneChar::new('ᚷ', 0x16B7, "gyfu", Tradition::FuthorcCore);
-    pub const WYNN:  RuneChar = RuneChar::new('ᚹ', 0x16B9, "wynn", Tradition::AngloSaxonExtension);
-
-    pub const HAEGL: RuneChar = RuneChar::new('ᚻ', 0x16BB, "haegl", Tradition::AngloSaxonExtension);
-    pub const NYD:   RuneChar = RuneChar::new('ᚾ', 0x16BE, "nyd", Tradition::FuthorcCore);
-    pub const IS:    RuneChar = RuneChar::new('ᛁ', 0x16C1, "is", Tradition::FuthorcCore);
-
-    // Unicode has JERAN (ᛃ) and GER (ᛄ). Old English “ger” is often used;
-    // we pick ᛄ for /j/ or /g/ palatal-ish.
-    pub const GER: RuneChar = RuneChar::new('ᛄ', 0x16C4, "ger", Tradition::AngloSaxonExtension);
-
-    pub const EOH: RuneChar = RuneChar::new('ᛇ', 0x16C7, "eoh (iwaz)", Tradition::FuthorcCore);
-    pub const PEORTH: RuneChar = RuneChar::new('ᛈ', 0x16C8, "peorth", Tradition::FuthorcCore);
-    pub const EOLHX: RuneChar = RuneChar::new('ᛉ', 0x16C9, "eolhx (algiz)", Tradition::FuthorcCore);
-
-    // S: Unicode has SOWILO (ᛊ) and SIGEL (ᛋ). Old English rune name “sigel” corresponds to ᛋ.
-    pub const SIGEL: RuneChar = RuneChar::new('ᛋ', 0x16CB, "sigel", Tradition::AngloSaxonExtension);
-
-    pub const TIR: RuneChar = RuneChar::new('ᛏ', 0x16CF, "tir", Tradition::FuthorcCore);
-    pub const BEORC: RuneChar = RuneChar::new('ᛒ', 0x16D2, "beorc", Tradition::AngloSaxonExtension);
-    pub const EH: RuneChar = RuneChar::new('ᛖ', 0x16D6, "eh", Tradition::FuthorcCore);
-    pub const MANN: RuneChar = RuneChar::new('ᛗ', 0x16D7, "mann", Tradition::FuthorcCore);
-    pub const LAGU: RuneChar = RuneChar::new('ᛚ', 0x16DA, "lagu", Tradition::FuthorcCore);
-
-    // /ŋ/ often maps to ING (ᛝ).
-    pub const ING: RuneChar = RuneChar::new('ᛝ', 0x16DD, "ing", Tradition::FuthorcCore);
-
-    pub const DAEG: RuneChar = RuneChar::new('ᛞ', 0x16DE, "daeg", Tradition::FuthorcCore);
-    pub const ETHEL: RuneChar = RuneChar::new(
-        'ᛟ',
-        0x16DF,
-        "ethel (othalan)",
-        Tradition::AngloSaxonExtension,
-    );
-
-    // Vowels expanded in Old English
-    pub const AC: RuneChar = RuneChar::new('ᚪ', 0x16AA, "ac", Tradition::AngloSaxonExtension);
-    pub const AESC: RuneChar = RuneChar::new('ᚫ', 0x16AB, "aesc", Tradition::AngloSaxonExtension);
-
-    // Additional Old English runes
-    pub const YR: RuneChar = RuneChar::new('ᚣ', 0x16A3, "yr", Tradition::AngloSaxonExtension);
-    pub const EAR: RuneChar = RuneChar::new('ᛠ', 0x16E0, "ear", Tradition::AngloSaxonExtension);
-    pub const IOR: RuneChar = RuneChar::new('ᛡ', 0x16E1, "ior", Tradition::AngloSaxonExtension);
-
-    // This is a *hack* rune for “sh”: Unicode has RUNIC LETTER SH (ᛲ) but it's a
-    // later scholarly addition; we tag it as Approximation to keep the audit honest.
-    pub const SH: RuneChar = RuneChar::new(
-        'ᛲ',
-        0x16F2,
-        "sh (modern addition)",
-        Tradition::Approximation,
-    );
+    // Summary / audit
+    let runes: Vec<&RuneChar> = t.runes().collect();
+    eprintln!("[audit] mode: {}", t.mode);
+    eprintln!("[audit] output rune count: {}", runes.len());
+    if !t.unmapped.is_empty() {
+        eprintln!("[audit] unmapped tokens: {:?}", t.unmapped);
+        eprintln!("[audit] suggestion: add these IPA symbols to \
+                   the mapping table (see --mapping).");
+    }
+    if !t.refused.is_empty() {
+        eprintln!("[audit] refused in strict mode: {:?}", t.refused);
+        eprintln!("[audit] suggestion: give these tokens a strict \
+                   mapping in the table, or use --mode pragmatic.");
+    }
 
-    // D: Unicode has RUNIC LETTER D (ᛑ) and DAGAZ/DAEG (ᛞ). We'll use ᛞ for /d/ for simplicity.
-    // V is tricky; Unicode has RUNIC LETTER V (ᚡ), but it’s not a standard Futhorc “core” letter.
-    pub const V: RuneChar = RuneChar::new('ᚡ', 0x16A1, "v (unicode)", Tradition::Approximation);
+    // Show per-rune details
+    eprintln!();
+    eprintln!("[audit] per-rune output details:");
+    for (i, r) in runes.iter().enumerate() {
+        eprintln!("  [ {i:03} ] {} ", fmt_rune(r));
+    }
+    eprintln!();
+    eprintln!("[output] rune string: {}", t.output);
+    eprintln!();
 }
 
-/// Map one IPA token to runes with rationale.
-/// Deterministic defaults, auditable reasoning.
-fn map_token(token: &str) -> Option<Mapping> {
-    use runes::*;
-
-    // Ignore length marker
-    if token == "ː" {
-        return Some(Mapping {
-            runes: vec![],
-            rationale:
-                "IPA length marker ignored for rune output (no direct \
-                rune length diacritic here).",
-        });
+/// Verbose mode for --reverse.
+fn explain_reading(r: &ReadBack) {
+    eprintln!("[reverse] table: {}", r.table);
+    for reading in &r.readings {
+        if reading.names.is_empty() {
+            continue;
+        }
+        eprintln!(
+            "[reverse] {:<4} {:<28} -> {}",
+            reading.runes,
+            reading.names.join(" + "),
+            reading.ipa.join(" | ")
+        );
+    }
+    if !r.unread.is_empty() {
+        eprintln!("[reverse] unreadable: {:?}", r.unread);
     }
+    eprintln!();
+}
 
-    // Multi-symbol tokens
-    match token {
-        "tʃ" => {
-            // Choice: use CEN + SH (approx) for readability
-            return Some(Mapping {
-                runes: vec![CEN, SH],
-                rationale: "Affricate /tʃ/ approximated as /t/~/k/ \
-                           + /ʃ/: CEN + (approx) SH.",
-            });
-        }
-        "dʒ" | "ʤ" => {
-            return Some(Mapping {
-                runes: vec![DAEG, SH],
-                rationale: "Affricate /dʒ/ approximated as /d/ \
-                           + /ʒ/~/ʃ/: DAEG + (approx) SH.",
-            });
+/// Print the n best renderings under the banner, each with the tokens
+/// written differently from the first.
+fn print_ranked(ranked: &[Ranked]) {
+    println!("Here are the {} best renderings:\n", ranked.len());
+    for (i, r) in ranked.iter().enumerate() {
+        println!(" {:>2}. {}   (score {:.3})", i + 1, r.output, r.score);
+        for c in &r.choices {
+            let glyphs: String = c.runes.iter().map(|r| r.ch).collect();
+            println!("       {:?} -> {} ({:.2}) {}", c.token, glyphs, c.score, c.rationale);
         }
-        "aɪ" => {
-            return Some(Mapping {
-                runes: vec![AC, IS],
-                rationale: "Diphthong /aɪ/ approximated as AC + IS (a + i).",
-            });
+    }
+    println!();
+}
+
+/// Totals over a batch, reported on stderr and folded into the exit status.
+#[derive(Debug, Default)]
+struct BatchStats {
+    lines: usize,
+    /// Lines with at least one unmapped, refused or unreadable token.
+    incomplete: usize,
+    unmapped: BTreeMap<String, usize>,
+    refused: BTreeMap<String, usize>,
+}
+
+impl BatchStats {
+    fn add(&mut self, unmapped: &[String], refused: &[String]) {
+        self.lines += 1;
+        if !unmapped.is_empty() || !refused.is_empty() {
+            self.incomplete += 1;
         }
-        "eɪ" => {
-            return Some(Mapping {
-                runes: vec![EH, IS],
-                rationale: "Diphthong /eɪ/ approximated as EH + IS (e + i).",
-            });
+        for t in unmapped {
+            *self.unmapped.entry(t.clone()).or_default() += 1;
         }
-        "oʊ" => {
-            return Some(Mapping {
-                runes: vec![OS, UR],
-                rationale: "Diphthong /oʊ/ approximated as OS + UR (o + u).",
-            });
+        for t in refused {
+            *self.refused.entry(t.clone()).or_default() += 1;
         }
-        "aʊ" => {
-            return Some(Mapping {
-                runes: vec![AC, UR],
-                rationale: "Diphthong /aʊ/ approximated as AC + UR (a + u).",
-            });
+    }
+
+    /// `what` names the unmapped column: "unmapped IPA tokens" forwards,
+    /// "unreadable characters" for --reverse.
+    fn report(&self, what: &str) {
+        let counts = |m: &BTreeMap<String, usize>| {
+            let mut v: Vec<(&String, &usize)> = m.iter().collect();
+            v.sort_by(|a, b| b.1.cmp(a.1).then(a.0.cmp(b.0)));
+            v.iter()
+                .map(|(t, n)| format!("{t:?} x{n}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        eprintln!(
+            "[batch] {} lines, {} incomplete",
+            self.lines, self.incomplete
+        );
+        if !self.refused.is_empty() {
+            eprintln!("Warning: approximations refused in strict mode: {}", counts(&self.refused));
         }
-        "ɔɪ" => {
-            return Some(Mapping {
-                runes: vec![OS, IS],
-                rationale: "Diphthong /ɔɪ/ approximated as OS + IS (o + i).",
-            });
+        if !self.unmapped.is_empty() {
+            eprintln!("Warning: {what}: {}", counts(&self.unmapped));
         }
-        _ => {}
     }
+}
 
-    // Single-symbol tokens (including IPA chars 
-    // that are one Unicode scalar)
-    match token {
-        // Consonants
-        "p" => Some(Mapping {
-            runes: vec![PEORTH],
-            rationale: "/p/ -> PEORTH (p).",
-        }),
-        "b" => Some(Mapping {
-            runes: vec![BEORC],
-            rationale: "/b/ -> BEORC (b).",
-        }),
-        "t" => Some(Mapping {
-            runes: vec![TIR],
-            rationale: "/t/ -> TIR (t).",
-        }),
-        "d" => Some(Mapping {
-            runes: vec![DAEG],
-            rationale: "/d/ -> DAEG (d) (using daeg form for simplicity).",
-        }),
-        "k" => Some(Mapping {
-            runes: vec![CEN],
-            rationale: "/k/ -> CEN (c/k) chosen as Futhorc-friendly form.",
-        }),
-        "g" => Some(Mapping {
-            runes: vec![GYFU],
-            rationale: "/g/ -> GYFU (g).",
-        }),
-        "f" => Some(Mapping {
-            runes: vec![FEOH],
-            rationale: "/f/ -> FEOH (f).",
-        }),
-        "v" => Some(Mapping {
-            runes: vec![V],
-            rationale: "/v/ -> Unicode V rune (approximation; not cleanly historical).",
-        }),
-        "θ" => Some(Mapping {
-            runes: vec![THORN],
-            rationale: "/θ/ -> THORN.",
-        }),
-        "ð" => Some(Mapping {
-            runes: vec![THORN],
-            rationale: "/ð/ -> THORN (same rune used for th-sounds here).",
-        }),
-        "s" => Some(Mapping {
-            runes: vec![SIGEL],
-            rationale: "/s/ -> SIGEL.",
-        }),
-        "z" => Some(Mapping {
-            runes: vec![SIGEL],
-            rationale: "/z/ -> SIGEL (approx; no dedicated z rune in this scheme).",
-        }),
-        "h" => Some(Mapping {
-            runes: vec![HAEGL],
-            rationale: "/h/ -> HAEGL.",
-        }),
-        "m" => Some(Mapping {
-            runes: vec![MANN],
-            rationale: "/m/ -> MANN.",
-        }),
-        "n" => Some(Mapping {
-            runes: vec![NYD],
-            rationale: "/n/ -> NYD.",
-        }),
-        "ŋ" => Some(Mapping {
-            runes: vec![ING],
-            rationale: "/ŋ/ -> ING.",
-        }),
-        "r" => Some(Mapping {
-            runes: vec![RAD],
-            rationale: "/r/ -> RAD.",
-        }),
-        "l" => Some(Mapping {
-            runes: vec![LAGU],
-            rationale: "/l/ -> LAGU.",
-        }),
-        "j" => Some(Mapping {
-            runes: vec![GER],
-            rationale: "/j/ -> GER (approx for y-sound; pragmatic mapping).",
-        }),
-        "w" => Some(Mapping {
-            runes: vec![WYNN],
-            rationale: "/w/ -> WYNN.",
-        }),
-        "ʃ" => Some(Mapping {
-            runes: vec![SH],
-            rationale: "/ʃ/ -> SH rune (approximation; later addition).",
-        }),
-        "ʒ" => Some(Mapping {
-            runes: vec![SH],
-            rationale: "/ʒ/ -> SH rune (approximation; closest readable option).",
-        }),
-
-        // Vowels (pragmatic defaults)
-        "a" => Some(Mapping {
-            runes: vec![AC],
-            rationale: "/a/ -> AC (a).",
-        }),
-        "æ" => Some(Mapping {
-            runes: vec![AESC],
-            rationale: "/æ/ -> AESC (ash).",
-        }),
-        "e" => Some(Mapping {
-            runes: vec![EH],
-            rationale: "/e/ -> EH (e).",
-        }),
-        "ɛ" => Some(Mapping {
-            runes: vec![EH],
-            rationale: "/ɛ/ -> EH (approx; open-mid front vowel mapped to e-rune).",
-        }),
-        "i" => Some(Mapping {
-            runes: vec![IS],
-            rationale: "/i/ -> IS (i).",
-        }),
-        "ɪ" => Some(Mapping {
-            runes: vec![IS],
-            rationale: "/ɪ/ -> IS (approx; i-like vowel).",
-        }),
-        "o" => Some(Mapping {
-            runes: vec![OS],
-            rationale: "/o/ -> OS (o).",
-        }),
-        "ɔ" => Some(Mapping {
-            runes: vec![OS],
-            rationale: "/ɔ/ -> OS (approx; o-like vowel).",
-        }),
-        "u" => Some(Mapping {
-            runes: vec![UR],
-            rationale: "/u/ -> UR (u).",
-        }),
-        "ʊ" => Some(Mapping {
-            runes: vec![UR],
-            rationale: "/ʊ/ -> UR (approx; u-like vowel).",
-        }),
-        "ə" => Some(Mapping {
-            runes: vec![EH],
-            rationale: "/ə/ -> EH (approx schwa -> e as neutral vowel).",
-        }),
-        "ʌ" => Some(Mapping {
-            runes: vec![AC],
-            rationale: "/ʌ/ -> AC (approx; a-like central vowel).",
-        }),
-
-        // A couple extra OE-ish vowel runes you may want to play with
-        "y" => Some(Mapping {
-            runes: vec![YR],
-            rationale: "/y/ -> YR (approx front rounded vowel; if present in your IPA).",
-        }),
-
-        _ => None,
-    }
+/// Print the usage to stderr and exit with status 2.
+fn usage(prog: &str) -> ! {
+    let _ = cli::write_usage(&mut io::stderr(), prog);
+    std::process::exit(2);
+}
+
+/// Print the usage to stdout for `--help` and exit successfully.
+fn help(prog: &str) -> ! {
+    let _ = cli::write_usage(&mut io::stdout().lock(), prog);
+    std::process::exit(0);
 }
 
 fn main() {
-    let mut args: Vec<String> = env::args().collect();
-
-    let prog = args.remove(0);
-    if args.is_empty() {
-        eprintln!("Usage:");
-        eprintln!("  {prog} [--verbose] \"<IPA>\"");
-        eprintln!("Examples:");
-        eprintln!("  {prog} --verbose \"/naɪt/\"");
-        eprintln!("  {prog} --verbose \"tʃɜːtʃ\"   # 'church' (approx)");
+    let mut args = env::args();
+    let prog = args.next().unwrap_or_else(|| "ipa2runes".to_string());
+
+    let mut args = args.peekable();
+    if args.peek().is_some_and(|a| a == "runes") {
+        args.next();
+        runes::main(&prog, args);
+        return;
+    }
+
+    let args = match cli::parse(args, io::stdin().is_terminal()) {
+        Ok(Command::Run(args)) => args,
+        Ok(Command::Help) => help(&prog),
+        Err(ArgError::Usage(message)) => {
+            if let Some(message) = message {
+                eprintln!("Error: {message}");
+            }
+            usage(&prog);
+        }
+        Err(ArgError::Conflict(message)) => {
+            eprintln!("Error: {message}");
+            std::process::exit(2);
+        }
+    };
+
+    let (tables, opts) = load(&args).unwrap_or_else(|message| {
+        eprintln!("Error: {message}");
         std::process::exit(2);
+    });
+    if args.verbose && !args.reverse {
+        explain_setup(&args, &opts);
     }
 
-    let mut verbose = false;
-    let mut ipa_input: Option<String> = None;
+    let code = match &args.input {
+        Input::Repl => run_repl(opts),
+        Input::Lines(path) => run_batch(&args, path.as_deref(), &tables, opts),
+        Input::Arg(input) if args.reverse => run_reverse(&args, input, &tables),
+        Input::Arg(input) => run_one(&args, input, opts),
+    };
+    std::process::exit(code);
+}
 
-    for a in args {
-        if a == "--verbose" || a == "-v" {
-            verbose = true;
-        } else if ipa_input.is_none() {
-            ipa_input = Some(a);
-        } else {
-            // If user provided multiple non-flag args, 
-            // join with space (rare, but safe).
-            ipa_input = Some(format!("{} {}", ipa_input.unwrap(), a));
+/// The tables `args` name, and the options to transliterate with: the
+/// first table, with the profile layered on it.
+fn load(args: &Args) -> Result<(Vec<MappingTable>, Options), String> {
+    let tables = match (&args.mapping, &args.alphabet) {
+        (Some(path), _) => vec![
+            MappingTable::load(Path::new(path))
+                .map_err(|e| format!("invalid mapping table: {e}"))?,
+        ],
+        // Runes say which row they come from; each input picks its own.
+        (None, None) if args.reverse => MappingTable::alphabets()
+            .filter_map(MappingTable::builtin)
+            .collect(),
+        (None, name) => {
+            let name = name.as_deref().unwrap_or("futhorc");
+            vec![MappingTable::builtin(name).expect("alphabet checked by cli::parse")]
         }
+    };
+
+    let mut opts = Options {
+        table: tables[0].clone(),
+        mode: args.mode,
+        diacritics: args.diacritics,
+        layout: args.layout,
+        text: args.text,
+        profile: None,
+    };
+    // A built-in profile by name, or a TOML file.
+    if let Some(name) = &args.profile {
+        let profile = match Profile::builtin(name) {
+            Some(profile) => profile,
+            None => Profile::load(Path::new(name)).map_err(|e| format!("invalid profile: {e}"))?,
+        };
+        opts = opts
+            .with_profile(profile)
+            .map_err(|e| format!("profile does not fit the table: {e}"))?;
     }
+    Ok((tables, opts))
+}
 
-    let ipa_input = ipa_input.unwrap();
-    let normalized = normalize_ipa(&ipa_input);
+/// Verbose mode: the table, profile and mode in use, on stderr.
+fn explain_setup(args: &Args, opts: &Options) {
+    eprintln!("[note] mapping goal: deterministic IPA -> \
+               rune string (audited), not strict historical orthography.");
+    eprintln!(
+        "[mapping] table: {} ({})",
+        opts.table.name,
+        args.mapping.as_deref().unwrap_or("built-in")
+    );
+    if !opts.table.description.is_empty() {
+        eprintln!("[mapping] {}", opts.table.description);
+    }
+    if let Some(profile) = &opts.profile {
+        eprintln!("[mapping] profile: {} ({})", profile.name, profile.description);
+    }
+    eprintln!("[mapping] mode: {}", opts.mode.as_str());
+    eprintln!("[mapping] diacritics: {}", opts.diacritics.as_str());
+    eprintln!();
+}
 
-    if verbose {
-        eprintln!("[input] raw:        {:?}", ipa_input);
-        eprintln!("[input] normalized: {:?}", normalized);
-        eprintln!("[note] mapping goal: deterministic IPA -> \
-                   rune string (audited), not strict historical orthography.");
-        eprintln!();
+fn run_repl(opts: Options) -> i32 {
+    let mut session = repl::Session::new(opts);
+    if let Err(e) = session.run(io::stdin().lock(), &mut io::stdout()) {
+        eprintln!("Error: {e}");
+        return 2;
     }
+    0
+}
 
-    let tokens = tokenize_ipa(&normalized, verbose);
+/// One result per input line, from the file at `path` or stdin; exit
+/// status 1 if some line was only partly transliterated.
+fn run_batch(args: &Args, path: Option<&str>, tables: &[MappingTable], opts: Options) -> i32 {
+    let reader: Box<dyn BufRead> = match path {
+        None => Box::new(io::stdin().lock()),
+        Some(path) => match File::open(path) {
+            Ok(file) => Box::new(BufReader::new(file)),
+            Err(e) => {
+                eprintln!("Error: cannot read {path}: {e}");
+                return 2;
+            }
+        },
+    };
+
+    let mut stats = BatchStats::default();
+    let transliterator = Transliterator::new(opts);
+    for line in reader.lines() {
+        let line = match line {
+            Ok(line) => line,
+            Err(e) => {
+                eprintln!("Error: cannot read input: {e}");
+                return 2;
+            }
+        };
+        let line = line.strip_suffix('\r').unwrap_or(&line);
+
+        // One output line per input line, so results stay aligned
+        // with their sources; tabs in a source would break the TSV.
+        let (result, doc) = if args.reverse {
+            let trace = reverse::read_back(line, reverse::table_for_runes(tables, line));
+            if args.verbose {
+                explain_reading(&trace);
+            }
+            stats.add(&trace.unread, &[]);
+            let doc = args
+                .json
+                .then(|| serde_json::to_string(&trace).expect("trace serializes"));
+            (trace.output, doc)
+        } else {
+            let trace = transliterator.transliterate(line);
+            if args.verbose {
+                explain(&trace);
+            }
+            stats.add(&trace.unmapped, &trace.refused);
+            match args.alternatives {
+                // The n best results as extra columns, or one JSON array.
+                Some(n) => {
+                    let ranked = transliterator.n_best(line, n);
+                    let doc = args
+                        .json
+                        .then(|| serde_json::to_string(&ranked).expect("ranking serializes"));
+                    let outputs: Vec<&str> = ranked.iter().map(|r| r.output.as_str()).collect();
+                    (outputs.join("\t"), doc)
+                }
+                None => {
+                    let doc = args
+                        .json
+                        .then(|| serde_json::to_string(&trace).expect("trace serializes"));
+                    (trace.output, doc)
+                }
+            }
+        };
 
-    if verbose {
-        eprintln!();
-        eprintln!("[tokens] {:?}", tokens);
-        eprintln!();
+        match doc {
+            Some(doc) => println!("{doc}"),
+            None if args.plain => println!("{result}"),
+            None => println!("{}\t{}", line.replace('\t', " "), result),
+        }
     }
 
-    let mut out_runes: Vec<crate::RuneChar> = Vec::new();
-    let mut unknown: Vec<String> = Vec::new();
+    let what = if args.reverse {
+        "unreadable characters"
+    } else {
+        "unmapped IPA tokens"
+    };
+    if args.verbose || stats.incomplete > 0 {
+        stats.report(what);
+    }
+    // Exit code 1: some line was only partly transliterated.
+    if stats.incomplete > 0 { 1 } else { 0 }
+}
 
-    for (idx, tok) in tokens.iter().enumerate() {
-        if verbose {
-            eprintln!("[map] token[{idx}] = {:?}", tok);
-        }
+/// `--reverse` on one input; exit status 1 if some character is unread.
+fn run_reverse(args: &Args, input: &str, tables: &[MappingTable]) -> i32 {
+    let trace = reverse::read_back(input, reverse::table_for_runes(tables, input));
+    if args.verbose {
+        explain_reading(&trace);
+    }
 
-        match map_token(tok) {
-            Some(mapping) => {
-                if verbose {
-                    if mapping.runes.is_empty() {
-                        eprintln!("      -> (no output) {}", mapping.rationale);
-                    } else {
-                        let rendered: Vec<String> = mapping.runes.iter().map(fmt_rune).collect();
-                        eprintln!("      -> {}", rendered.join(" + "));
-                        eprintln!("         rationale: {}", mapping.rationale);
-                    }
-                }
-                out_runes.extend(mapping.runes);
-            }
-            None => {
-                unknown.push(tok.clone());
-                if verbose {
-                    eprintln!("      -> [unmapped] no rule for token {:?}.", tok);
-                }
+    if args.json {
+        let doc = serde_json::to_string_pretty(&trace).expect("trace serializes");
+        println!("{doc}");
+    } else if args.plain {
+        println!("{}", trace.output);
+    } else {
+        println!("Here it is read back as IPA:\n");
+        println!(" /{}/ \n", trace.output);
+
+        let mut ambiguous: Vec<&Reading> = Vec::new();
+        for r in trace.readings.iter().filter(|r| r.is_ambiguous()) {
+            if !ambiguous.iter().any(|a| a.runes == r.runes) {
+                ambiguous.push(r);
             }
         }
-
-        if verbose {
-            eprintln!();
+        if !ambiguous.is_empty() {
+            println!("Ambiguous runes:");
+            for r in ambiguous {
+                println!("  {:<4} {:<28} {}", r.runes, r.names.join(" + "), r.ipa.join(" | "));
+            }
+            println!();
         }
     }
 
-    // Build final rune string
-    let rune_string: String = out_runes
-        .iter()
-        .map(|r| r.ch.to_string())
-        .collect::<Vec<_>>()
-        .join(" ");
+    if !trace.unread.is_empty() {
+        eprintln!("Warning: unreadable characters: {:?}", trace.unread);
+        return 1;
+    }
+    0
+}
 
-    // Summary / audit
-    if verbose {
-        eprintln!("[audit] output rune count: {}", out_runes.len());
-        if !unknown.is_empty() {
-            eprintln!("[audit] unmapped tokens: {:?}", unknown);
-            eprintln!("[audit] suggestion: extend the token rules \
-                       map_token() table for these IPA symbols.");
+/// One input, with its drawing and ranked alternatives when asked for;
+/// exit status 1 if some token is unmapped or refused.
+fn run_one(args: &Args, input: &str, opts: Options) -> i32 {
+    let ranked = args.alternatives.map(|n| n_best(input, opts.clone(), n));
+    let trace = transliterate(input, opts);
+    if args.verbose {
+        explain(&trace);
+    }
+    let unknown = trace.unmapped.clone();
+    let refused = trace.refused.clone();
+
+    let svg_only = args.svg.as_deref() == Some("-");
+    if let Some(path) = &args.svg {
+        let doc = svg::render(&trace, &args.drawing);
+        if svg_only {
+            print!("{doc}");
+        } else if let Err(e) = fs::write(path, doc) {
+            eprintln!("Error: cannot write {path}: {e}");
+            return 2;
         }
+    }
 
-        // Show per-rune details
-        eprintln!();
-        eprintln!("[audit] per-rune output details:");
-        for (i, r) in out_runes.iter().enumerate() {
-            eprintln!("  [ {i:03} ] {} ", fmt_rune(r));
+    if svg_only {
+        // The drawing is the output.
+    } else if let Some(ranked) = ranked {
+        if args.json {
+            let doc = serde_json::to_string_pretty(&ranked).expect("ranking serializes");
+            println!("{doc}");
+        } else if args.plain {
+            for r in &ranked {
+                println!("{}", r.output);
+            }
+        } else {
+            print_ranked(&ranked);
+        }
+    } else if args.json {
+        let doc = serde_json::to_string_pretty(&trace).expect("trace serializes");
+        println!("{doc}");
+    } else if args.plain {
+        println!("{}", trace.output);
+    } else {
+        let script = match trace.script {
+            Script::Runic => "Runes",
+            Script::Ogham => "Ogham",
+        };
+        println!("Here it is represented in {script}:\n");
+        match args.layout.orientation {
+            Orientation::Horizontal => println!(" {} \n", trace.output),
+            Orientation::Vertical => println!("{}\n", trace.output),
         }
-        eprintln!();
-        eprintln!("[output] rune string: ");
     }
 
-    println!("Here it is represented in Runes:\n");
-    println!(" {} \n", rune_string);
+    if !refused.is_empty() {
+        eprintln!("Warning: approximations refused in strict mode: {:?}", refused);
+    }
 
-    if !unknown.is_empty() {
+    if !unknown.is_empty() || !refused.is_empty() {
 
         // Non-verbose still signals it with an exit 
         // code and message (useful in pipelines).
-        eprintln!("Warning: unmapped IPA tokens: {:?}", unknown);
+        if !unknown.is_empty() {
+            eprintln!("Warning: unmapped IPA tokens: {:?}", unknown);
+        }
 
         // Exit code 1 indicates partial success 
         // (output produced, but incomplete mapping).
-        std::process::exit(1);
+        return 1;
     }
+    0
 }
@@ -0,0 +1,664 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Stage 3: tokens to runes.
+
+use crate::ipa::{self, Spelling};
+use crate::table::MappingTable;
+use crate::tokenize::{Token, Tokenizer};
+use crate::{Alternative, Diacritics, Mapping, Mode, Script, Tradition};
+
+/// Turns one token into runes, with the rationale for the choice.
+pub trait Mapper {
+    /// The mapping for `token`, or `None` when it cannot be written.
+    fn map(&self, token: &Token) -> Option<Mapping>;
+
+    /// Every defensible mapping for `token`, best first, each scored in
+    /// (0, 1]. The first is the one [`map`](Mapper::map) returns; the
+    /// default offers nothing else.
+    fn alternatives(&self, token: &Token) -> Vec<Alternative> {
+        self.map(token)
+            .map(|mapping| vec![Alternative { mapping, score: 1.0 }])
+            .unwrap_or_default()
+    }
+
+    /// Whether a mapping [`map`](Mapper::map) returned must still be
+    /// refused; the token is then reported rather than written.
+    fn refuses(&self, _mapping: &Mapping) -> bool {
+        false
+    }
+
+    /// The script the runes are written in, which decides how
+    /// [`map_tokens`](crate::map_tokens) lays out words.
+    fn script(&self) -> Script {
+        Script::Runic
+    }
+}
+
+/// The [`Mapper`] backed by a [`MappingTable`], in one [`Mode`].
+#[derive(Debug, Clone)]
+pub struct TableMapper {
+    table: MappingTable,
+    /// The table's trie, also used to spell chart symbols in its rules.
+    tokenizer: Tokenizer,
+    mode: Mode,
+    diacritics: Diacritics,
+}
+
+impl TableMapper {
+    pub fn new(table: MappingTable, mode: Mode, diacritics: Diacritics) -> Self {
+        Self {
+            tokenizer: Tokenizer::new(&table),
+            table,
+            mode,
+            diacritics,
+        }
+    }
+
+    /// The default: the built-in Anglo-Saxon Futhorc table, pragmatic
+    /// mode, diacritics stripped.
+    pub fn futhorc() -> Self {
+        let table = MappingTable::builtin("futhorc").expect("default table");
+        Self::new(table, Mode::Pragmatic, Diacritics::Strip)
+    }
+
+    pub fn table(&self) -> &MappingTable {
+        &self.table
+    }
+
+    /// The tokenizer for the table, compiled once with the mapper.
+    pub fn tokenizer(&self) -> &Tokenizer {
+        &self.tokenizer
+    }
+
+    /// Map one IPA symbol: the table's own rule, or else the broad form of
+    /// an IPA chart symbol written with the table's rules.
+    fn map_symbol(&self, symbol: &str) -> Option<Mapping> {
+        if let Some(mapping) = self.table.lookup(symbol, self.mode) {
+            return Some(mapping.clone());
+        }
+
+        let chart = ipa::lookup(symbol)?;
+        if chart.broad.is_empty() {
+            return Some(Mapping {
+                runes: Vec::new(),
+                rationale: format!("{symbol} ({}) has no rune; dropped.", chart.name),
+            });
+        }
+
+        let mut runes = Vec::new();
+        let mut reasons = Vec::new();
+        let mut rest = chart.broad;
+        while !rest.is_empty() {
+            let rule = self.table.rule(self.tokenizer.match_rule(rest)?)?;
+            let mapping = rule.mapping_for(self.mode);
+            runes.extend(mapping.runes.iter().cloned());
+            reasons.push(mapping.rationale.as_str());
+            rest = &rest[rule.ipa.len()..];
+        }
+
+        Some(Mapping {
+            runes,
+            rationale: format!(
+                "{symbol} ({}) written as /{}/: {}",
+                chart.name,
+                chart.broad,
+                reasons.join(" ")
+            ),
+        })
+    }
+
+    /// A token without a table rule: an IPA chart symbol, or a tied pair
+    /// written part by part.
+    fn map_unruled(&self, token: &Token) -> Option<Mapping> {
+        self.map_symbol(&token.key).or_else(|| {
+            if token.parts.len() < 2 {
+                return None;
+            }
+            let parts = token
+                .parts
+                .iter()
+                .map(|p| self.map_symbol(p))
+                .collect::<Option<Vec<_>>>()?;
+            Some(Mapping {
+                runes: parts.iter().flat_map(|m| m.runes.iter().cloned()).collect(),
+                rationale: format!(
+                    "tied {} written part by part: {}",
+                    token.parts.join("+"),
+                    parts.iter().map(|m| m.rationale.as_str()).collect::<Vec<_>>().join(" ")
+                ),
+            })
+        })
+    }
+
+    /// Strip or spell the token's diacritics onto `mapping`.
+    fn apply_diacritics(&self, token: &Token, mapping: &mut Mapping) {
+        for &c in &token.diacritics {
+            let (name, spelling) = match ipa::diacritic(c) {
+                Some(d) => (d.name, d.spelling),
+                None => ("combining mark", Spelling::Drop),
+            };
+            let spelled = match (self.diacritics, spelling) {
+                (Diacritics::Spell, Spelling::Before(s) | Spelling::After(s)) => {
+                    self.map_symbol(s).map(|m| (s, m.runes))
+                }
+                _ => None,
+            };
+            match spelled {
+                Some((s, runes)) => {
+                    if let Spelling::Before(_) = spelling {
+                        mapping.runes.splice(0..0, runes);
+                    } else {
+                        mapping.runes.extend(runes);
+                    }
+                    mapping
+                        .rationale
+                        .push_str(&format!(" Diacritic {c:?} ({name}) spelled as /{s}/."));
+                }
+                None => mapping
+                    .rationale
+                    .push_str(&format!(" Diacritic {c:?} ({name}) stripped.")),
+            }
+        }
+    }
+}
+
+impl Mapper for TableMapper {
+    fn map(&self, token: &Token) -> Option<Mapping> {
+        self.alternatives(token).into_iter().next().map(|a| a.mapping)
+    }
+
+    /// Deterministic defaults, auditable reasoning: a token with a table
+    /// rule offers every mapping the rule has, ranked; a tied pair the
+    /// table does not know is mapped part by part. Diacritics are then
+    /// stripped or spelled out on each.
+    fn alternatives(&self, token: &Token) -> Vec<Alternative> {
+        let chart = ipa::lookup(&token.key)
+            .and_then(|symbol| Some((symbol, self.table.rule(symbol.broad)?)));
+        let ranked = match (self.table.rule(&token.key), chart) {
+            (Some(rule), _) => rule.ranked(self.mode),
+            // A chart symbol folded onto one table token (ɡ → g) offers
+            // that token's alternatives.
+            (None, Some((symbol, rule))) => rule
+                .ranked(self.mode)
+                .into_iter()
+                .map(|mut alternative| {
+                    alternative.mapping.rationale = format!(
+                        "{} ({}) written as /{}/: {}",
+                        token.key, symbol.name, symbol.broad, alternative.mapping.rationale
+                    );
+                    alternative
+                })
+                .collect(),
+            (None, None) => self
+                .map_unruled(token)
+                .map(|mapping| vec![Alternative { mapping, score: 1.0 }])
+                .unwrap_or_default(),
+        };
+
+        ranked
+            .into_iter()
+            .map(|mut alternative| {
+                self.apply_diacritics(token, &mut alternative.mapping);
+                alternative
+            })
+            .collect()
+    }
+
+    /// Strict mode refuses a mapping that still relies on an approximation rune.
+    fn refuses(&self, mapping: &Mapping) -> bool {
+        self.mode == Mode::Strict
+            && mapping
+                .runes
+                .iter()
+                .any(|r| r.tradition == Tradition::Approximation)
+    }
+
+    fn script(&self) -> Script {
+        self.table.script
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalize_ipa;
+    use crate::tokenize::Tokenizer;
+
+    fn mapper(alphabet: &str, mode: Mode, diacritics: Diacritics) -> TableMapper {
+        TableMapper::new(MappingTable::builtin(alphabet).unwrap(), mode, diacritics)
+    }
+
+    /// The rune glyphs for `input` (without layout) and the tokens left
+    /// unmapped.
+    fn convert(mapper: &TableMapper, input: &str) -> (String, Vec<String>) {
+        let normalized = normalize_ipa(input);
+        let mut runes = String::new();
+        let mut unmapped = Vec::new();
+        for tok in Tokenizer::new(mapper.table()).tokenize(&normalized) {
+            match mapper.map(&tok) {
+                Some(m) => runes.extend(m.runes.iter().map(|r| r.ch)),
+                None => unmapped.push(tok.text),
+            }
+        }
+        (runes, unmapped)
+    }
+
+    fn strip() -> TableMapper {
+        mapper("futhorc", Mode::Pragmatic, Diacritics::Strip)
+    }
+
+    fn spell() -> TableMapper {
+        mapper("futhorc", Mode::Pragmatic, Diacritics::Spell)
+    }
+
+    /// Every token of the built-in Futhorc table and the runes it writes
+    /// in pragmatic, strict and readable mode; `None` where strict mode
+    /// refuses it.
+    const FUTHORC: &[(&str, &str, Option<&str>, &str)] = &[
+        ("ɔɪ", "ᚩᛁ", Some("ᚩᛁ"), "ᚩᛁ"),
+        ("tʃ", "ᚳᛲ", Some("ᚳ"), "ᚳ"),
+        ("dʒ", "ᛞᛲ", Some("ᚳᚷ"), "ᛄ"),
+        ("aɪ", "ᚪᛁ", Some("ᚪᛁ"), "ᚪᛁ"),
+        ("eɪ", "ᛖᛁ", Some("ᛖᛁ"), "ᛖᛁ"),
+        ("oʊ", "ᚩᚢ", Some("ᚩᚢ"), "ᚩᚢ"),
+        ("aʊ", "ᚪᚢ", Some("ᚪᚢ"), "ᚪᚢ"),
+        ("ʤ", "ᛞᛲ", Some("ᚳᚷ"), "ᛄ"),
+        ("ː", "", Some(""), ""),
+        ("θ", "ᚦ", Some("ᚦ"), "ᚦ"),
+        ("ð", "ᚦ", Some("ᚦ"), "ᚦ"),
+        ("ŋ", "ᛝ", Some("ᛝ"), "ᛝ"),
+        ("ʃ", "ᛲ", Some("ᛋᚳ"), "ᛲ"),
+        ("ʒ", "ᛲ", None, "ᛲ"),
+        ("æ", "ᚫ", Some("ᚫ"), "ᚫ"),
+        ("ɛ", "ᛖ", Some("ᛖ"), "ᛖ"),
+        ("ɪ", "ᛁ", Some("ᛁ"), "ᛁ"),
+        ("ɔ", "ᚩ", Some("ᚩ"), "ᚩ"),
+        ("ʊ", "ᚢ", Some("ᚢ"), "ᚢ"),
+        ("ə", "ᛖ", Some("ᛖ"), "ᛖ"),
+        ("ʌ", "ᚪ", Some("ᚪ"), "ᚪ"),
+        ("ɑ", "ᚪ", Some("ᚪ"), "ᚪ"),
+        ("ɜ", "ᛖ", Some("ᛖ"), "ᛖ"),
+        ("p", "ᛈ", Some("ᛈ"), "ᛈ"),
+        ("b", "ᛒ", Some("ᛒ"), "ᛒ"),
+        ("t", "ᛏ", Some("ᛏ"), "ᛏ"),
+        ("d", "ᛞ", Some("ᛞ"), "ᛞ"),
+        ("k", "ᚳ", Some("ᚳ"), "ᚳ"),
+        ("g", "ᚷ", Some("ᚷ"), "ᚷ"),
+        ("f", "ᚠ", Some("ᚠ"), "ᚠ"),
+        ("v", "ᚡ", Some("ᚠ"), "ᚡ"),
+        ("s", "ᛋ", Some("ᛋ"), "ᛋ"),
+        ("z", "ᛋ", Some("ᛋ"), "ᛋ"),
+        ("h", "ᚻ", Some("ᚻ"), "ᚻ"),
+        ("m", "ᛗ", Some("ᛗ"), "ᛗ"),
+        ("n", "ᚾ", Some("ᚾ"), "ᚾ"),
+        ("r", "ᚱ", Some("ᚱ"), "ᚱ"),
+        ("l", "ᛚ", Some("ᛚ"), "ᛚ"),
+        ("j", "ᛄ", Some("ᛄ"), "ᛄ"),
+        ("w", "ᚹ", Some("ᚹ"), "ᚹ"),
+        ("a", "ᚪ", Some("ᚪ"), "ᚪ"),
+        ("e", "ᛖ", Some("ᛖ"), "ᛖ"),
+        ("i", "ᛁ", Some("ᛁ"), "ᛁ"),
+        ("o", "ᚩ", Some("ᚩ"), "ᚩ"),
+        ("u", "ᚢ", Some("ᚢ"), "ᚢ"),
+        ("y", "ᚣ", Some("ᚣ"), "ᚣ"),
+    ];
+
+    #[test]
+    fn every_futhorc_token_writes_its_runes() {
+        assert_every_token_writes("futhorc", FUTHORC);
+    }
+
+    /// Every token of the built-in Elder Futhark table and its runes. The
+    /// row has a rune for each of its sounds, so no mode changes the
+    /// spelling; English sounds it lacks take the nearest rune.
+    const ELDER: &[(&str, &str)] = &[
+        ("tʃ", "ᛏᛊ"),
+        ("dʒ", "ᛞᛉ"),
+        ("ʤ", "ᛞᛉ"),
+        ("ː", ""),
+        ("aɪ", "ᚨᛁ"),
+        ("eɪ", "ᛖᛁ"),
+        ("oʊ", "ᛟᚢ"),
+        ("aʊ", "ᚨᚢ"),
+        ("ɔɪ", "ᛟᛁ"),
+        ("p", "ᛈ"),
+        ("b", "ᛒ"),
+        ("t", "ᛏ"),
+        ("d", "ᛞ"),
+        ("k", "ᚲ"),
+        ("g", "ᚷ"),
+        ("f", "ᚠ"),
+        ("v", "ᚠ"),
+        ("θ", "ᚦ"),
+        ("ð", "ᚦ"),
+        ("s", "ᛊ"),
+        ("z", "ᛉ"),
+        ("h", "ᚺ"),
+        ("ʃ", "ᛊ"),
+        ("ʒ", "ᛉ"),
+        ("m", "ᛗ"),
+        ("n", "ᚾ"),
+        ("ŋ", "ᛜ"),
+        ("r", "ᚱ"),
+        ("l", "ᛚ"),
+        ("j", "ᛃ"),
+        ("w", "ᚹ"),
+        ("a", "ᚨ"),
+        ("æ", "ᚨ"),
+        ("e", "ᛖ"),
+        ("ɛ", "ᛖ"),
+        ("i", "ᛁ"),
+        ("ɪ", "ᛁ"),
+        ("o", "ᛟ"),
+        ("ɔ", "ᛟ"),
+        ("u", "ᚢ"),
+        ("ʊ", "ᚢ"),
+        ("ə", "ᛖ"),
+        ("ʌ", "ᚨ"),
+        ("ɑ", "ᚨ"),
+        ("ɜ", "ᛖ"),
+        ("y", "ᚢ"),
+    ];
+
+    /// Every token of the built-in long-branch Younger Futhark table and
+    /// its runes, in every mode. The 16-rune row writes k/g, t/d and p/b
+    /// with one rune each, i/e/j with ᛁ and u/o/w with ᚢ, and never writes
+    /// a rune twice, so /ŋk/ takes one ᚴ.
+    const YOUNGER_LONG: &[(&str, &str)] = &[
+        ("tʃ", "ᛏᛋ"),
+        ("dʒ", "ᛏᛋ"),
+        ("ʤ", "ᛏᛋ"),
+        ("ː", ""),
+        ("aɪ", "ᛅᛁ"),
+        ("eɪ", "ᛅᛁ"),
+        ("oʊ", "ᚬᚢ"),
+        ("aʊ", "ᛅᚢ"),
+        ("ɔɪ", "ᚬᛁ"),
+        ("p", "ᛒ"),
+        ("b", "ᛒ"),
+        ("t", "ᛏ"),
+        ("d", "ᛏ"),
+        ("k", "ᚴ"),
+        ("g", "ᚴ"),
+        ("f", "ᚠ"),
+        ("v", "ᚠ"),
+        ("θ", "ᚦ"),
+        ("ð", "ᚦ"),
+        ("s", "ᛋ"),
+        ("z", "ᛋ"),
+        ("h", "ᚼ"),
+        ("ʃ", "ᛋ"),
+        ("ʒ", "ᛋ"),
+        ("m", "ᛘ"),
+        ("n", "ᚾ"),
+        ("ŋk", "ᚾᚴ"),
+        ("ŋg", "ᚾᚴ"),
+        ("ŋɡ", "ᚾᚴ"),
+        ("ŋ", "ᚾᚴ"),
+        ("r", "ᚱ"),
+        ("l", "ᛚ"),
+        ("j", "ᛁ"),
+        ("w", "ᚢ"),
+        ("a", "ᛅ"),
+        ("æ", "ᛅ"),
+        ("e", "ᛁ"),
+        ("ɛ", "ᛅ"),
+        ("i", "ᛁ"),
+        ("ɪ", "ᛁ"),
+        ("o", "ᚬ"),
+        ("ɔ", "ᚬ"),
+        ("u", "ᚢ"),
+        ("ʊ", "ᚢ"),
+        ("ə", "ᛁ"),
+        ("ʌ", "ᛅ"),
+        ("ɑ", "ᛅ"),
+        ("ɜ", "ᛁ"),
+        ("y", "ᚢ"),
+    ];
+
+    /// The same tokens in the short-twig row: ᛐ ᛌ ᛓ ᚽ ᛙ ᚿ ᛆ ᚭ in place of
+    /// the long-branch ᛏ ᛋ ᛒ ᚼ ᛘ ᚾ ᛅ ᚬ.
+    const YOUNGER_SHORT: &[(&str, &str)] = &[
+        ("tʃ", "ᛐᛌ"),
+        ("dʒ", "ᛐᛌ"),
+        ("ʤ", "ᛐᛌ"),
+        ("ː", ""),
+        ("aɪ", "ᛆᛁ"),
+        ("eɪ", "ᛆᛁ"),
+        ("oʊ", "ᚭᚢ"),
+        ("aʊ", "ᛆᚢ"),
+        ("ɔɪ", "ᚭᛁ"),
+        ("p", "ᛓ"),
+        ("b", "ᛓ"),
+        ("t", "ᛐ"),
+        ("d", "ᛐ"),
+        ("k", "ᚴ"),
+        ("g", "ᚴ"),
+        ("f", "ᚠ"),
+        ("v", "ᚠ"),
+        ("θ", "ᚦ"),
+        ("ð", "ᚦ"),
+        ("s", "ᛌ"),
+        ("z", "ᛌ"),
+        ("h", "ᚽ"),
+        ("ʃ", "ᛌ"),
+        ("ʒ", "ᛌ"),
+        ("m", "ᛙ"),
+        ("n", "ᚿ"),
+        ("ŋk", "ᚿᚴ"),
+        ("ŋg", "ᚿᚴ"),
+        ("ŋɡ", "ᚿᚴ"),
+        ("ŋ", "ᚿᚴ"),
+        ("r", "ᚱ"),
+        ("l", "ᛚ"),
+        ("j", "ᛁ"),
+        ("w", "ᚢ"),
+        ("a", "ᛆ"),
+        ("æ", "ᛆ"),
+        ("e", "ᛁ"),
+        ("ɛ", "ᛆ"),
+        ("i", "ᛁ"),
+        ("ɪ", "ᛁ"),
+        ("o", "ᚭ"),
+        ("ɔ", "ᚭ"),
+        ("u", "ᚢ"),
+        ("ʊ", "ᚢ"),
+        ("ə", "ᛁ"),
+        ("ʌ", "ᛆ"),
+        ("ɑ", "ᛆ"),
+        ("ɜ", "ᛁ"),
+        ("y", "ᚢ"),
+    ];
+
+    #[test]
+    fn every_elder_and_younger_token_writes_its_runes() {
+        for (alphabet, table) in [
+            ("elder", ELDER),
+            ("younger-long", YOUNGER_LONG),
+            ("younger-short", YOUNGER_SHORT),
+        ] {
+            let rows: Vec<_> = table
+                .iter()
+                .map(|&(ipa, runes)| (ipa, runes, Some(runes), runes))
+                .collect();
+            assert_every_token_writes(alphabet, &rows);
+        }
+    }
+
+    /// Checks that `rows` lists every token of `alphabet`'s table once and
+    /// that each writes the listed runes in pragmatic, strict and readable
+    /// mode.
+    fn assert_every_token_writes(alphabet: &str, rows: &[(&str, &str, Option<&str>, &str)]) {
+        let table = MappingTable::builtin(alphabet).unwrap();
+        let mut listed: Vec<&str> = rows.iter().map(|row| row.0).collect();
+        let mut rules: Vec<&str> = table.rules().iter().map(|r| r.ipa.as_str()).collect();
+        listed.sort_unstable();
+        rules.sort_unstable();
+        assert_eq!(listed, rules, "{alphabet}: every token of the table is listed once");
+
+        for &(ipa, pragmatic, strict, readable) in rows {
+            let modes = [
+                (Mode::Pragmatic, Some(pragmatic)),
+                (Mode::Strict, strict),
+                (Mode::Readable, Some(readable)),
+            ];
+            for (mode, expected) in modes {
+                let m = mapper(alphabet, mode, Diacritics::Strip);
+                let token = &Tokenizer::new(m.table()).tokenize(ipa)[0];
+                let mapping = m.map(token).unwrap();
+                let written: Option<String> = (!m.refuses(&mapping))
+                    .then(|| mapping.runes.iter().map(|r| r.ch).collect());
+                assert_eq!(
+                    written.as_deref(),
+                    expected,
+                    "{alphabet}: {ipa} in {} mode",
+                    mode.as_str()
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn every_chart_symbol_maps() {
+        for alphabet in MappingTable::alphabets() {
+            for mode in [Mode::Pragmatic, Mode::Strict, Mode::Readable] {
+                let m = mapper(alphabet, mode, Diacritics::Spell);
+                for symbol in ipa::CHART {
+                    let tokens = Tokenizer::new(m.table()).tokenize(symbol.ipa);
+                    assert!(
+                        m.map(&tokens[0]).is_some(),
+                        "{alphabet}: {} ({}) unmapped",
+                        symbol.ipa,
+                        symbol.name
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn every_table_token_maps_to_its_rule() {
+        for alphabet in MappingTable::alphabets() {
+            let m = mapper(alphabet, Mode::Pragmatic, Diacritics::Strip);
+            for rule in m.table().rules() {
+                let tokens = Tokenizer::new(m.table()).tokenize(&rule.ipa);
+                assert_eq!(m.map(&tokens[0]).as_ref(), Some(&rule.mapping), "{alphabet}");
+            }
+        }
+    }
+
+    #[test]
+    fn every_diacritic_strips() {
+        let (bare, _) = convert(&strip(), "a");
+        for d in ipa::DIACRITICS {
+            let input = format!("a{}", d.mark);
+            assert_eq!(convert(&strip(), &input), (bare.clone(), vec![]), "{}", d.name);
+        }
+    }
+
+    #[test]
+    fn spelled_diacritics() {
+        let spell = |input| convert(&spell(), input).0;
+        let strip = |input| convert(&strip(), input).0;
+
+        assert_eq!(spell("tʰ"), strip("th"));
+        assert_eq!(spell("ɑ̃"), strip("ɑn"));
+        assert_eq!(spell("n̩"), strip("ən"));
+        assert_eq!(spell("kʷ"), strip("kw"));
+        assert_eq!(spell("tʲ"), strip("tj"));
+        assert_eq!(spell("ə˞"), strip("ər"));
+        assert_eq!(spell("dⁿ"), strip("dn"));
+        assert_eq!(spell("dˡ"), strip("dl"));
+        assert_eq!(spell("t̪"), strip("t"));
+    }
+
+    #[test]
+    fn common_symbols_are_mapped() {
+        for input in [
+            "ɜː", "ɑ", "ɒ", "ɹ", "ɾ", "ʔ", "x", "ç", "ɑ̃", "n̩", "tʰ", "t͡ʃ", "ɡ", "ɚ", "ʁ", "ɲ",
+        ] {
+            let (_, unmapped) = convert(&strip(), input);
+            assert!(unmapped.is_empty(), "{input}: {unmapped:?}");
+        }
+    }
+
+    #[test]
+    fn chart_folds() {
+        let runes = |input| convert(&strip(), input).0;
+
+        assert_eq!(runes("ɹ"), runes("r"));
+        assert_eq!(runes("ɡ"), runes("g"));
+        assert_eq!(runes("x"), runes("h"));
+        assert_eq!(runes("ɚ"), runes("ər"));
+        assert_eq!(runes("ʍ"), runes("hw"));
+        assert_eq!(runes("ʔ"), "");
+        assert_eq!(runes("ː"), "");
+    }
+
+    #[test]
+    fn precomposed_and_decomposed_agree() {
+        // U+00E7 vs c + U+0327; U+1EBD vs e + U+0303.
+        assert_eq!(convert(&spell(), "\u{e7}"), convert(&spell(), "c\u{327}"));
+        assert_eq!(convert(&spell(), "\u{1ebd}"), convert(&spell(), "e\u{303}"));
+        // The palatal fricative is not a palatal plosive with a mark on it.
+        assert_ne!(convert(&strip(), "ç"), convert(&strip(), "c"));
+    }
+
+    #[test]
+    fn tied_pairs() {
+        assert_eq!(convert(&strip(), "t\u{361}ʃ"), convert(&strip(), "tʃ"));
+        assert_eq!(convert(&strip(), "k\u{35C}p").0, convert(&strip(), "kp").0);
+    }
+
+    #[test]
+    fn table_wins_over_chart() {
+        // The medieval row has its own ø; the chart would fold it to e.
+        let m = mapper("medieval", Mode::Pragmatic, Diacritics::Strip);
+        let token = &Tokenizer::new(m.table()).tokenize("ø")[0];
+        assert_eq!(
+            m.map(token).as_ref(),
+            m.table().lookup("ø", Mode::Pragmatic)
+        );
+    }
+
+    #[test]
+    fn alternatives_are_ranked() {
+        let m = strip();
+        let token = &Tokenizer::new(m.table()).tokenize("k")[0];
+        let alternatives = m.alternatives(token);
+        assert!(alternatives.len() > 1);
+        assert_eq!(Some(&alternatives[0].mapping), m.map(token).as_ref());
+        assert_eq!(alternatives[0].score, 1.0);
+        assert!(alternatives.windows(2).all(|w| w[0].score >= w[1].score));
+
+        // Another mode's mapping is offered too, diacritics applied.
+        let token = &Tokenizer::new(m.table()).tokenize("tʃʰ")[0];
+        let runes: Vec<Vec<char>> = m
+            .alternatives(token)
+            .iter()
+            .map(|a| a.mapping.runes.iter().map(|r| r.ch).collect())
+            .collect();
+        assert!(runes.contains(&vec!['ᚳ']));
+
+        // A chart symbol folded onto a table token offers its alternatives.
+        let token = &Tokenizer::new(m.table()).tokenize("ɡ")[0];
+        assert_eq!(m.alternatives(token).len(), 2);
+    }
+
+    #[test]
+    fn strict_mode_refuses_approximations_only() {
+        let m = mapper("futhorc", Mode::Strict, Diacritics::Strip);
+        let p = mapper("futhorc", Mode::Pragmatic, Diacritics::Strip);
+        for rule in m.table().rules() {
+            let token = &Tokenizer::new(m.table()).tokenize(&rule.ipa)[0];
+            let mapping = m.map(token).unwrap();
+            let approximate = mapping
+                .runes
+                .iter()
+                .any(|r| r.tradition == Tradition::Approximation);
+            assert_eq!(m.refuses(&mapping), approximate, "{}", rule.ipa);
+            assert!(!p.refuses(&mapping));
+        }
+    }
+}
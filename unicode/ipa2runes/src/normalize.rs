@@ -0,0 +1,109 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Stage 1: input normalization.
+
+use unicode_normalization::UnicodeNormalization;
+
+/// Sentence punctuation that survives normalization and is written out
+/// by the [`Layout`](crate::Layout).
+pub const SENTENCE_PUNCTUATION: &[char] = &['.', ',', ';', ':', '!', '?'];
+
+///
+/// Normalize IPA input:
+///
+/// - decompose to Unicode NFD, so "ẽ" and "e" + U+0303 are the same
+///   input and diacritics stand apart from their base letters
+/// - strip surrounding slashes /.../
+/// - remove common stress markers ˈ ˌ
+/// - collapse whitespace (and inner / [ ] delimiters) to one space per
+///   word boundary
+/// - keep sentence punctuation at the end of a word, one mark per word;
+///   inside a word it is dropped, so the IPA syllable break in "ə.baʊt"
+///   is not a full stop
+/// - drop any other ASCII punctuation
+/// - keep IPA symbols and diacritics; keep ː (length) but we’ll treat
+///   it as ignorable
+///
+pub fn normalize_ipa(input: &str) -> String {
+    let mut s: String = input.trim().nfd().collect();
+    if s.starts_with('/') && s.ends_with('/') && s.len() >= 2 {
+        s = s[1..s.len() - 1].to_string();
+    }
+
+    let is_boundary = |c: char| c.is_whitespace() || c == '/' || c == '[' || c == ']';
+
+    let chars: Vec<char> = s.chars().collect();
+    let mut out = String::new();
+    for (i, &c) in chars.iter().enumerate() {
+        if is_boundary(c) {
+            if !out.is_empty() && !out.ends_with(' ') {
+                out.push(' ');
+            }
+        } else if SENTENCE_PUNCTUATION.contains(&c)
+            && chars.get(i + 1).is_none_or(|&n| is_boundary(n))
+        {
+            // "naɪt ." and "naɪt." are the same sentence end, and a word
+            // ends once: "naɪt? !" keeps the last mark, as "naɪt?!" does.
+            if out.ends_with(' ') {
+                out.pop();
+            }
+            if out.ends_with(SENTENCE_PUNCTUATION) {
+                out.pop();
+            }
+            out.push(c);
+        } else if c != 'ˈ' && c != 'ˌ' && !c.is_ascii_punctuation() {
+            out.push(c);
+        }
+    }
+
+    if out.ends_with(' ') {
+        out.pop();
+    }
+    // Dropping a stress mark or punctuation can bring two diacritics
+    // together; put them back in canonical order.
+    out.nfd().collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strips_delimiters_and_stress() {
+        assert_eq!(normalize_ipa("/naɪt/"), "naɪt");
+        assert_eq!(normalize_ipa("[ˈwɔːtər]"), "wɔːtər");
+        assert_eq!(normalize_ipa("  /ðə ˌnaɪt/ "), "ðə naɪt");
+    }
+
+    #[test]
+    fn collapses_boundaries() {
+        assert_eq!(normalize_ipa("ðə   naɪt"), "ðə naɪt");
+        assert_eq!(normalize_ipa("/ðə/ /naɪt/"), "ðə naɪt");
+        assert_eq!(normalize_ipa("ðə\tnaɪt\n"), "ðə naɪt");
+    }
+
+    #[test]
+    fn keeps_sentence_punctuation_at_word_ends() {
+        assert_eq!(normalize_ipa("/ðə naɪt, ðə deɪ./"), "ðə naɪt, ðə deɪ.");
+        assert_eq!(normalize_ipa("naɪt ."), "naɪt.");
+        assert_eq!(normalize_ipa("naɪt; ;"), "naɪt;");
+        assert_eq!(normalize_ipa("naɪt? !"), normalize_ipa("naɪt?!"));
+        // A syllable break inside a word is not a full stop.
+        assert_eq!(normalize_ipa("ə.baʊt"), "əbaʊt");
+        assert_eq!(normalize_ipa("naɪt-taɪm"), "naɪttaɪm");
+    }
+
+    #[test]
+    fn reorders_marks_brought_together() {
+        // U+0319 sorts before U+033D once the ; between them is gone.
+        assert_eq!(normalize_ipa("ʄ\u{33d};\u{319}ɽ"), "ʄ\u{319}\u{33d}ɽ");
+    }
+
+    #[test]
+    fn decomposes_to_nfd() {
+        assert_eq!(normalize_ipa("\u{e7}"), "c\u{327}");
+        assert_eq!(normalize_ipa("\u{1ebd}"), "e\u{303}");
+        assert_eq!(normalize_ipa("t\u{361}ʃ"), "t\u{361}ʃ");
+    }
+}
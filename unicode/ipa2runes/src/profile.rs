@@ -0,0 +1,384 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Dialect profiles (`--profile`): how one accent's IPA is read.
+//!
+//! The mapping tables are tuned to one vague accent. A profile adjusts
+//! both ends of the pipeline for a named dialect: rewrites applied to the
+//! normalized IPA before it is tokenized (an r dropped where a non-rhotic
+//! speaker would not say it, merged vowels folded together), and token
+//! overrides layered on the mapping table. The files under `profiles/`
+//! are compiled in:
+//!
+//! ```toml
+//! [[rewrites]]
+//! from = "r"
+//! to = ""
+//! before = ["consonant", "boundary"]
+//! rationale = "non-rhotic: r is only said before a vowel."
+//!
+//! [[tokens]]
+//! ipa = "əʊ"
+//! as = "oʊ"
+//! rationale = "RP GOAT vowel, written like General American oʊ."
+//! ```
+//!
+//! A token override either names the table's runes (`runes = [...]`) or
+//! borrows another token's mappings (`as = "..."`), which keeps it usable
+//! with every table.
+
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::ipa;
+use crate::normalize::SENTENCE_PUNCTUATION;
+use crate::table::{MappingTable, TableError, TokenRule};
+use crate::Mapping;
+
+/// Compiled-in profiles, selectable with `--profile`.
+const BUILTIN: &[(&str, &str)] = &[
+    ("general-american", include_str!("../profiles/general-american.toml")),
+    ("rp", include_str!("../profiles/rp.toml")),
+    ("old-english", include_str!("../profiles/old-english.toml")),
+];
+
+/* ===========================================================================
+ * File format
+ * ===========================================================================
+ */
+
+#[derive(Debug, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ProfileFile {
+    name: String,
+    #[serde(default)]
+    description: String,
+    #[serde(default)]
+    rewrites: Vec<Rewrite>,
+    #[serde(default)]
+    tokens: Vec<TokenOverride>,
+}
+
+/// One token of the table replaced or added by a profile.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct TokenOverride {
+    ipa: String,
+    #[serde(default)]
+    desc: String,
+    /// Rune keys of the table the profile is applied to.
+    runes: Option<Vec<String>>,
+    /// Or: map the token like this other token of the table.
+    #[serde(rename = "as")]
+    like: Option<String>,
+    rationale: String,
+}
+
+/// What must (or must not) follow a rewrite's `from` for it to apply.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum Context {
+    Vowel,
+    /// Any sound that is not a vowel.
+    Consonant,
+    /// A word boundary, sentence punctuation or the end of the input.
+    Boundary,
+    /// This exact IPA.
+    Ipa(String),
+}
+
+impl From<String> for Context {
+    fn from(s: String) -> Self {
+        match s.as_str() {
+            "vowel" => Context::Vowel,
+            "consonant" => Context::Consonant,
+            "boundary" => Context::Boundary,
+            _ => Context::Ipa(s.nfd().collect()),
+        }
+    }
+}
+
+impl Context {
+    /// Whether `rest`, the input after a match, starts with this context.
+    fn matches(&self, rest: &str) -> bool {
+        let next = rest.chars().next();
+        let boundary = next.is_none_or(|c| c == ' ' || SENTENCE_PUNCTUATION.contains(&c));
+        match self {
+            Context::Boundary => boundary,
+            Context::Vowel => next.is_some_and(ipa::is_vowel),
+            Context::Consonant => !boundary && !next.is_some_and(ipa::is_vowel),
+            Context::Ipa(ipa) => rest.starts_with(ipa.as_str()),
+        }
+    }
+}
+
+/// An IPA rewrite, applied to the normalized input.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct Rewrite {
+    pub from: String,
+    pub to: String,
+    /// Apply only before one of these; anywhere when empty.
+    #[serde(default)]
+    pub before: Vec<Context>,
+    /// Never apply before one of these.
+    #[serde(default)]
+    pub not_before: Vec<Context>,
+    pub rationale: String,
+}
+
+/// A rewrite that applied, for the trace.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Rewritten {
+    pub from: String,
+    pub to: String,
+    /// Byte offset of `from` in the input the rewrite was applied to.
+    pub at: usize,
+    pub rationale: String,
+}
+
+/* ===========================================================================
+ * Profiles
+ * ===========================================================================
+ */
+
+#[derive(Debug, Clone)]
+pub struct Profile {
+    pub name: String,
+    pub description: String,
+    /// Applied in order, each over the whole input.
+    pub rewrites: Vec<Rewrite>,
+    tokens: Vec<TokenOverride>,
+}
+
+impl Profile {
+    /// Names accepted by [`Profile::builtin`].
+    pub fn names() -> impl Iterator<Item = &'static str> {
+        BUILTIN.iter().map(|(name, _)| *name)
+    }
+
+    /// A compiled-in profile by name.
+    pub fn builtin(name: &str) -> Option<Self> {
+        let (name, text) = BUILTIN.iter().find(|(n, _)| *n == name)?;
+        let profile = Self::from_toml(text, &format!("<built-in profile {name}>"))
+            .expect("built-in profile is valid");
+        Some(profile)
+    }
+
+    /// Load a profile from a TOML file.
+    pub fn load(path: &Path) -> Result<Self, TableError> {
+        let origin = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|source| TableError::Io {
+            origin: origin.clone(),
+            source,
+        })?;
+        Self::from_toml(&text, &origin)
+    }
+
+    pub fn from_toml(text: &str, origin: &str) -> Result<Self, TableError> {
+        let file: ProfileFile = toml::from_str(text).map_err(|e| TableError::Parse {
+            origin: origin.to_string(),
+            message: e.to_string(),
+        })?;
+        let invalid = |message: String| TableError::Invalid {
+            origin: origin.to_string(),
+            message,
+        };
+
+        let mut rewrites = file.rewrites;
+        for rewrite in &mut rewrites {
+            rewrite.from = rewrite.from.nfd().collect();
+            rewrite.to = rewrite.to.nfd().collect();
+            if rewrite.from.is_empty() {
+                return Err(invalid("rewrite with empty from".to_string()));
+            }
+        }
+
+        let mut tokens = file.tokens;
+        for token in &mut tokens {
+            token.ipa = token.ipa.nfd().collect();
+            token.like = token.like.as_ref().map(|l| l.nfd().collect());
+            if token.runes.is_some() == token.like.is_some() {
+                return Err(invalid(format!(
+                    "token {:?} needs exactly one of runes and as",
+                    token.ipa
+                )));
+            }
+        }
+
+        Ok(Self {
+            name: file.name,
+            description: file.description,
+            rewrites,
+            tokens,
+        })
+    }
+
+    /// Apply the rewrites to normalized IPA, left to right and without
+    /// overlap, each rewrite in turn. Returns the rewritten IPA and every
+    /// rewrite that applied.
+    pub fn rewrite(&self, ipa: &str) -> (String, Vec<Rewritten>) {
+        let mut text = ipa.to_string();
+        let mut applied = Vec::new();
+
+        for rule in &self.rewrites {
+            let mut out = String::with_capacity(text.len());
+            let mut i = 0;
+            while i < text.len() {
+                let rest = &text[i..];
+                let after = rest.strip_prefix(rule.from.as_str());
+                let fits = after.is_some_and(|after| {
+                    (rule.before.is_empty() || rule.before.iter().any(|c| c.matches(after)))
+                        && !rule.not_before.iter().any(|c| c.matches(after))
+                });
+                if fits {
+                    applied.push(Rewritten {
+                        from: rule.from.clone(),
+                        to: rule.to.clone(),
+                        at: i,
+                        rationale: rule.rationale.clone(),
+                    });
+                    out.push_str(&rule.to);
+                    i += rule.from.len();
+                } else {
+                    let c = rest.chars().next().expect("not at the end");
+                    out.push(c);
+                    i += c.len_utf8();
+                }
+            }
+            text = out;
+        }
+
+        (text, applied)
+    }
+
+    /// `table` with this profile's token overrides layered on: a token
+    /// the table has is replaced, a new one added.
+    pub fn apply_to(&self, table: &MappingTable) -> Result<MappingTable, TableError> {
+        let invalid = |message: String| TableError::Invalid {
+            origin: format!("profile {}", self.name),
+            message,
+        };
+
+        let mut table = table.clone();
+        for token in &self.tokens {
+            let rule = match (&token.runes, &token.like) {
+                (Some(keys), _) => {
+                    let mut runes = Vec::with_capacity(keys.len());
+                    for key in keys {
+                        let rune = table.runes.get(key).ok_or_else(|| {
+                            invalid(format!(
+                                "token {:?} uses rune {key}, which table {} does not define",
+                                token.ipa, table.name
+                            ))
+                        })?;
+                        runes.push(rune.clone());
+                    }
+                    TokenRule {
+                        ipa: token.ipa.clone(),
+                        desc: token.desc.clone(),
+                        mapping: Mapping {
+                            runes,
+                            rationale: token.rationale.clone(),
+                        },
+                        strict: None,
+                        readable: None,
+                        alternatives: Vec::new(),
+                    }
+                }
+                (None, Some(like)) => {
+                    let base = table.rule(like).ok_or_else(|| {
+                        invalid(format!(
+                            "token {:?} is mapped as {like:?}, which table {} does not define",
+                            token.ipa, table.name
+                        ))
+                    })?;
+                    let explain = |m: &Mapping| Mapping {
+                        runes: m.runes.clone(),
+                        rationale: format!("{} As /{like}/: {}", token.rationale, m.rationale),
+                    };
+                    TokenRule {
+                        ipa: token.ipa.clone(),
+                        desc: token.desc.clone(),
+                        mapping: explain(&base.mapping),
+                        strict: base.strict.as_ref().map(explain),
+                        readable: base.readable.as_ref().map(explain),
+                        alternatives: base
+                            .alternatives
+                            .iter()
+                            .map(|a| crate::Alternative {
+                                mapping: explain(&a.mapping),
+                                score: a.score,
+                            })
+                            .collect(),
+                    }
+                }
+                (None, None) => unreachable!("checked on load"),
+            };
+
+            table.set_rule(rule);
+        }
+
+        table.description = format!("{} ({} profile)", table.description, self.name);
+        Ok(table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rewrite(profile: &str, ipa: &str) -> String {
+        Profile::builtin(profile).unwrap().rewrite(ipa).0
+    }
+
+    #[test]
+    fn builtin_profiles_apply_to_their_tables() {
+        for name in Profile::names() {
+            let profile = Profile::builtin(name).unwrap();
+            assert!(profile.apply_to(&MappingTable::builtin("futhorc").unwrap()).is_ok());
+        }
+        // The Old English profile needs the Futhorc's own runes.
+        let elder = MappingTable::builtin("elder").unwrap();
+        assert!(Profile::builtin("old-english").unwrap().apply_to(&elder).is_err());
+        assert!(Profile::builtin("rp").unwrap().apply_to(&elder).is_ok());
+    }
+
+    #[test]
+    fn non_rhotic_r() {
+        assert_eq!(rewrite("rp", "kɑr"), "kɑ");
+        assert_eq!(rewrite("rp", "kɑrd"), "kɑd");
+        assert_eq!(rewrite("rp", "rɛd"), "rɛd");
+        assert_eq!(rewrite("rp", "vɛri"), "vɛri");
+        assert_eq!(rewrite("rp", "bɝd"), "bɜːd");
+    }
+
+    #[test]
+    fn rhotic_nurse_and_merged_vowels() {
+        assert_eq!(rewrite("general-american", "bɜːd"), "bɜrd");
+        assert_eq!(rewrite("general-american", "kɔt kɒt"), "kɑt kɑt");
+        // Not in "boy" or "north".
+        assert_eq!(rewrite("general-american", "bɔɪ nɔrθ"), "bɔɪ nɔrθ");
+    }
+
+    #[test]
+    fn rewrites_are_traced() {
+        let (_, applied) = Profile::builtin("rp").unwrap().rewrite("kɑr pɑrk");
+        let at: Vec<usize> = applied.iter().map(|r| r.at).collect();
+        assert_eq!(at, ["kɑ".len(), "kɑr pɑ".len()]);
+        assert!(applied.iter().all(|r| r.from == "r" && r.to.is_empty()));
+    }
+
+    #[test]
+    fn old_english_diphthongs() {
+        let profile = Profile::builtin("old-english").unwrap();
+        let table = profile.apply_to(&MappingTable::builtin("futhorc").unwrap()).unwrap();
+        let rune = |ipa: &str| table.lookup(ipa, crate::Mode::Pragmatic).unwrap().runes[0].name.clone();
+        assert_eq!(rune("æɑ"), "ear");
+        assert_eq!(rune("io"), "ior");
+        assert_eq!(rune("y"), "yr");
+    }
+}
@@ -0,0 +1,371 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! `--repl`: transliterate line after line, with the audit inline and
+//! `:commands` that change the session's table and mode as it goes.
+//!
+//! Lines are read from stdin as they come, with no line editor: the
+//! terminal's own erase keys work, arrow keys, history and completion do
+//! not. `rlwrap ipa2runes --repl` adds them.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, BufRead, Write};
+
+use ipa2runes::table::TokenRule;
+use ipa2runes::{
+    Mapping, MappingTable, Mode, Options, RuneChar, TokenStatus, Transliteration, transliterate,
+};
+use unicode_normalization::UnicodeNormalization;
+
+const HELP: &str = "\
+Enter IPA to transliterate it, or a command:
+  :alphabet [NAME]      switch tables (drops :map overrides), or list them
+  :mode [MODE]          pragmatic|strict|readable, or show the current one
+  :explain IPA          every mapping the table has for each token of IPA
+  :map IPA RUNE...      write IPA with these runes (keys or glyphs) from now on
+  :unmapped             tokens left unmapped or refused so far
+  :save FILE            write the session's table as a --mapping TOML file
+  :help                 this list
+  :quit                 leave (so does end of input)";
+
+/// The state kept between inputs.
+pub(crate) struct Session {
+    options: Options,
+    /// Tokens overridden with :map, in order.
+    mapped: Vec<String>,
+    unmapped: BTreeMap<String, usize>,
+    refused: BTreeMap<String, usize>,
+}
+
+impl Session {
+    pub(crate) fn new(options: Options) -> Self {
+        Self {
+            options,
+            mapped: Vec::new(),
+            unmapped: BTreeMap::new(),
+            refused: BTreeMap::new(),
+        }
+    }
+
+    /// Read lines from `input` until :quit or its end.
+    pub(crate) fn run(&mut self, input: impl BufRead, out: &mut impl Write) -> io::Result<()> {
+        writeln!(
+            out,
+            "ipa2runes: table {}, mode {}. :help for commands, :quit to leave.",
+            self.options.table.name,
+            self.options.mode.as_str()
+        )?;
+        write!(out, "ipa> ")?;
+        out.flush()?;
+        for line in input.lines() {
+            let line = line?;
+            let line = line.trim();
+            if let Some(command) = line.strip_prefix(':') {
+                let (name, arg) = command.split_once(' ').unwrap_or((command, ""));
+                if matches!(name, "quit" | "q") {
+                    return Ok(());
+                }
+                self.command(name, arg.trim(), out)?;
+            } else if !line.is_empty() {
+                let t = transliterate(line, self.options.clone());
+                self.record(&t);
+                print_tokens(&t, out)?;
+            }
+            write!(out, "ipa> ")?;
+            out.flush()?;
+        }
+        writeln!(out)
+    }
+
+    fn record(&mut self, t: &Transliteration) {
+        for token in &t.unmapped {
+            *self.unmapped.entry(token.clone()).or_default() += 1;
+        }
+        for token in &t.refused {
+            *self.refused.entry(token.clone()).or_default() += 1;
+        }
+    }
+
+    fn command(&mut self, name: &str, arg: &str, out: &mut impl Write) -> io::Result<()> {
+        match name {
+            "help" | "h" => writeln!(out, "{HELP}"),
+            "alphabet" if arg.is_empty() => {
+                for name in MappingTable::alphabets() {
+                    let mark = if name == self.options.table.name {
+                        "*"
+                    } else {
+                        " "
+                    };
+                    writeln!(out, " {mark} {name}")?;
+                }
+                Ok(())
+            }
+            "alphabet" => self.alphabet(arg, out),
+            "mode" if arg.is_empty() => writeln!(out, "mode: {}", self.options.mode.as_str()),
+            "mode" => match Mode::parse(arg) {
+                Some(mode) => {
+                    self.options.mode = mode;
+                    writeln!(out, "mode: {}", mode.as_str())
+                }
+                None => writeln!(out, "Error: unknown mode {arg:?}."),
+            },
+            "explain" if !arg.is_empty() => self.explain(arg, out),
+            "map" if !arg.is_empty() => self.map(arg, out),
+            "unmapped" => {
+                if self.unmapped.is_empty() && self.refused.is_empty() {
+                    return writeln!(out, "every token so far was written");
+                }
+                if !self.unmapped.is_empty() {
+                    writeln!(out, "unmapped: {}", counts(&self.unmapped))?;
+                }
+                if !self.refused.is_empty() {
+                    writeln!(out, "refused:  {}", counts(&self.refused))?;
+                }
+                Ok(())
+            }
+            "save" if !arg.is_empty() => match fs::write(arg, self.options.table.to_toml()) {
+                Ok(()) => writeln!(
+                    out,
+                    "saved {} tokens to {arg}; load it with --mapping {arg}",
+                    self.options.table.rules().len()
+                ),
+                Err(e) => writeln!(out, "Error: cannot write {arg}: {e}"),
+            },
+            "explain" | "map" | "save" => {
+                writeln!(out, "Error: :{name} needs an argument (see :help).")
+            }
+            _ => writeln!(out, "Error: unknown command :{name} (see :help)."),
+        }
+    }
+
+    /// Switch tables, keeping the mode and any profile.
+    fn alphabet(&mut self, name: &str, out: &mut impl Write) -> io::Result<()> {
+        let Some(table) = MappingTable::builtin(name) else {
+            return writeln!(out, "Error: unknown alphabet {name:?}.");
+        };
+        let base = Options {
+            table,
+            profile: None,
+            ..self.options.clone()
+        };
+        let options = match self.options.profile.clone() {
+            Some(profile) => match base.with_profile(profile) {
+                Ok(options) => options,
+                Err(e) => return writeln!(out, "Error: profile does not fit the table: {e}"),
+            },
+            None => base,
+        };
+        self.options = options;
+        if !self.mapped.is_empty() {
+            writeln!(out, "dropped :map overrides for {}", self.mapped.join(" "))?;
+            self.mapped.clear();
+        }
+        writeln!(out, "table: {} ({})", name, self.options.table.description)
+    }
+
+    /// Every mapping the table has for each token of `ipa`: the one each
+    /// mode writes and the ranked alternatives.
+    fn explain(&self, ipa: &str, out: &mut impl Write) -> io::Result<()> {
+        let t = transliterate(ipa, self.options.clone());
+        let table = &self.options.table;
+        for step in &t.tokens {
+            if matches!(
+                step.status,
+                TokenStatus::Boundary | TokenStatus::Punctuation
+            ) {
+                continue;
+            }
+            let Some(rule) = table.rule(&step.token) else {
+                writeln!(out, "{:?}: no rule in {}", step.token, table.name)?;
+                if let Some(rationale) = &step.rationale {
+                    writeln!(out, "  {} {}", glyphs(&step.runes), rationale)?;
+                }
+                continue;
+            };
+            match rule.desc.as_str() {
+                "" => writeln!(out, "{:?}", rule.ipa)?,
+                desc => writeln!(out, "{:?} ({desc})", rule.ipa)?,
+            }
+            for mode in [Mode::Pragmatic, Mode::Strict, Mode::Readable] {
+                let m = rule.mapping_for(mode);
+                let names: Vec<&str> = m.runes.iter().map(|r| r.name.as_str()).collect();
+                writeln!(
+                    out,
+                    "  {:<10} {:<4} {:<20} {}",
+                    mode.as_str(),
+                    glyphs(&m.runes),
+                    names.join(" + "),
+                    m.rationale
+                )?;
+            }
+            let ranked = rule.ranked(self.options.mode);
+            if ranked.len() > 1 {
+                writeln!(out, "  ranked for {}:", self.options.mode.as_str())?;
+                for a in ranked {
+                    writeln!(out, "    {:.2} {}", a.score, glyphs(&a.mapping.runes))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Override (or add) a token's mapping in the session's table. Each
+    /// rune is a key of the table (IS) or one of its glyphs (ᛁ).
+    fn map(&mut self, arg: &str, out: &mut impl Write) -> io::Result<()> {
+        let mut words = arg.split_whitespace();
+        let ipa: String = words.next().unwrap_or_default().nfd().collect();
+        let table = &mut self.options.table;
+        let mut runes = Vec::new();
+        for word in words {
+            let rune = table.runes.get(word).or_else(|| {
+                let mut chars = word.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(c), None) => table.runes.values().find(|r| r.ch == c),
+                    _ => None,
+                }
+            });
+            match rune {
+                Some(rune) => runes.push(rune.clone()),
+                None => return writeln!(out, "Error: {} has no rune {word:?}.", table.name),
+            }
+        }
+        if runes.is_empty() {
+            return writeln!(out, "Error: :map needs a token and at least one rune.");
+        }
+
+        let mapping = Mapping {
+            rationale: format!("/{ipa}/ -> {} (set in the REPL).", names(&runes)),
+            runes,
+        };
+        writeln!(out, "{ipa} -> {}", glyphs(&mapping.runes))?;
+        let rule = match table.rule(&ipa) {
+            // The override holds in every mode.
+            Some(rule) => TokenRule {
+                mapping,
+                strict: None,
+                readable: None,
+                ..rule.clone()
+            },
+            None => TokenRule {
+                ipa: ipa.clone(),
+                desc: String::new(),
+                mapping,
+                strict: None,
+                readable: None,
+                alternatives: Vec::new(),
+            },
+        };
+        table.set_rule(rule);
+        if !self.mapped.contains(&ipa) {
+            self.mapped.push(ipa);
+        }
+        Ok(())
+    }
+}
+
+/// The result, then one line per token with its runes and rationale.
+fn print_tokens(t: &Transliteration, out: &mut impl Write) -> io::Result<()> {
+    writeln!(out, "  {}", t.output)?;
+    for step in &t.tokens {
+        let rationale = step.rationale.as_deref().unwrap_or_default();
+        let note = match step.status {
+            TokenStatus::Boundary | TokenStatus::Punctuation => continue,
+            TokenStatus::Mapped => rationale.to_string(),
+            TokenStatus::Unmapped => "unmapped: no rule for this token".to_string(),
+            TokenStatus::Refused => format!("refused in strict mode: {rationale}"),
+        };
+        writeln!(
+            out,
+            "    {:<6} {:<4} {}",
+            step.token,
+            glyphs(&step.runes),
+            note
+        )?;
+    }
+    Ok(())
+}
+
+fn glyphs(runes: &[RuneChar]) -> String {
+    match runes.is_empty() {
+        true => "-".to_string(),
+        false => runes.iter().map(|r| r.ch).collect(),
+    }
+}
+
+fn names(runes: &[RuneChar]) -> String {
+    let names: Vec<&str> = runes.iter().map(|r| r.name.as_str()).collect();
+    names.join(" + ")
+}
+
+fn counts(tokens: &BTreeMap<String, usize>) -> String {
+    let parts: Vec<String> = tokens.iter().map(|(t, n)| format!("{t:?} ×{n}")).collect();
+    parts.join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(script: &str) -> (Session, String) {
+        let mut session = Session::new(Options::default());
+        let mut out = Vec::new();
+        session.run(script.as_bytes(), &mut out).unwrap();
+        (session, String::from_utf8(out).unwrap())
+    }
+
+    #[test]
+    fn keeps_state_between_inputs() {
+        let script = "/naɪt/\nna☃t\n:mode strict\n/ʒ/\n:unmapped\n:alphabet elder\n";
+        let (s, out) = session(script);
+        assert!(out.contains("  ᚾᚪᛁᛏ\n"), "{out}");
+        assert!(out.contains("    aɪ     ᚪᛁ"));
+        assert!(out.contains("mode: strict"));
+        assert!(out.contains("unmapped: \"☃\" ×1"));
+        assert!(out.contains("refused:  \"ʒ\" ×1"));
+        assert_eq!(s.options.table.name, "elder");
+        assert_eq!(s.options.mode, Mode::Strict);
+    }
+
+    #[test]
+    fn explains_every_mapping_of_a_token() {
+        let (_, out) = session(":explain k\n");
+        assert!(out.contains("\"k\""));
+        assert!(out.contains("  pragmatic  ᚳ"));
+        assert!(out.contains("ranked for pragmatic:"));
+        assert!(out.contains("0.60 ᛣ"));
+    }
+
+    #[test]
+    fn mapped_tokens_are_saved() {
+        let path = std::env::temp_dir().join(format!("ipa2runes-repl-{}.toml", std::process::id()));
+        let script = format!(
+            ":map ʒ SIGEL ᚷ\n/ʒ/\n:save {}\n:quit\n/ignored/\n",
+            path.display()
+        );
+        let (s, out) = session(&script);
+        assert!(out.contains("  ᛋᚷ\n"), "{out}");
+        assert!(!out.contains("ignored"));
+        assert_eq!(s.mapped, ["ʒ"]);
+
+        let saved = MappingTable::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        let t = transliterate(
+            "/ʒ naɪt/",
+            Options {
+                table: saved,
+                ..Options::default()
+            },
+        );
+        assert_eq!(t.output, "ᛋᚷ᛫ᚾᚪᛁᛏ");
+    }
+
+    #[test]
+    fn bad_commands_do_not_end_the_session() {
+        let (_, out) = session(":mode loud\n:frobnicate\n:map ʒ NOPE\n/naɪt/\n");
+        assert!(out.contains("Error: unknown mode \"loud\"."));
+        assert!(out.contains("Error: unknown command :frobnicate"));
+        assert!(out.contains("has no rune \"NOPE\""));
+        assert!(out.contains("  ᚾᚪᛁᛏ\n"));
+    }
+}
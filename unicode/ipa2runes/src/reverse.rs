@@ -0,0 +1,278 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Reverse transliteration (`--reverse`): runes back to approximate IPA.
+//!
+//! The reading is built from the same mapping table as the forward
+//! direction, inverted: every rune sequence a token can map to (in any
+//! mode) reads back as that token. Sequences are matched greedily, longest
+//! first, so the digraphs the forward mapping writes (CEN + SH for /tʃ/,
+//! AC + IS for /aɪ/) come back as one sound.
+//!
+//! The inversion is lossy. Where one rune sequence stands for several
+//! sounds (THORN for θ and ð, SIGEL for s and z, and every merger of the
+//! Younger Futhark) all of them are kept, the table's default first.
+
+use serde::Serialize;
+
+use crate::table::MappingTable;
+
+/// Runic punctuation and Ogham feather marks, and what they read back as.
+const PUNCTUATION: &[(char, &str)] = &[
+    ('\u{16EB}', " "),
+    ('\u{16EC}', ","),
+    ('\u{16ED}', "."),
+    ('\u{169B}', " "),
+    ('\u{169C}', " "),
+];
+
+/// The result of [`read_back`]. Serializes to the `--reverse --json` trace.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReadBack {
+    pub input: String,
+    pub table: String,
+    pub readings: Vec<Reading>,
+    /// Characters no rune sequence of the table explains.
+    pub unread: Vec<String>,
+    /// The readings rendered as one IPA string, see [`render`].
+    pub output: String,
+}
+
+/// Read runes back as approximate IPA with `table`.
+pub fn read_back(input: &str, table: &MappingTable) -> ReadBack {
+    let readings = ReverseTable::new(table).read(input.trim());
+    let output = render(&readings);
+    let unread = readings
+        .iter()
+        .filter(|r| r.is_unread())
+        .map(|r| r.runes.clone())
+        .collect();
+
+    ReadBack {
+        input: input.to_string(),
+        table: table.name.clone(),
+        readings,
+        unread,
+        output,
+    }
+}
+
+/// The first of `tables` that writes every rune of `input`, so Elder and
+/// Younger Futhark (and Ogham) text is read with its own row; `tables[0]`
+/// otherwise.
+pub fn table_for_runes<'t>(tables: &'t [MappingTable], input: &str) -> &'t MappingTable {
+    tables
+        .iter()
+        .find(|t| ReverseTable::new(t).covers(input))
+        .unwrap_or(&tables[0])
+}
+
+/// One run of the input and the IPA it may stand for.
+#[derive(Debug, Clone, Serialize)]
+pub struct Reading {
+    pub runes: String,
+    /// Byte offsets into the input, end exclusive.
+    pub span: [usize; 2],
+    /// Candidate IPA, most likely first. Empty when the runes could not
+    /// be read; more than one when they are ambiguous.
+    pub ipa: Vec<String>,
+    /// Traditional names of the runes, for the annotations.
+    pub names: Vec<String>,
+}
+
+impl Reading {
+    pub fn is_ambiguous(&self) -> bool {
+        self.ipa.len() > 1
+    }
+
+    pub fn is_unread(&self) -> bool {
+        self.ipa.is_empty()
+    }
+}
+
+/// A mapping table inverted: rune sequence → the IPA tokens it encodes.
+pub struct ReverseTable {
+    /// Longest rune sequence first.
+    entries: Vec<Entry>,
+}
+
+struct Entry {
+    runes: Vec<char>,
+    names: Vec<String>,
+    ipa: Vec<String>,
+}
+
+impl ReverseTable {
+    pub fn new(table: &MappingTable) -> Self {
+        let mut entries: Vec<Entry> = Vec::new();
+
+        // Default mappings first, so they lead each candidate list; then
+        // the strict and readable variants, which add readings such as a
+        // lone CEN for /tʃ/, and the declared alternatives (CALC for /k/).
+        let defaults = table.rules().iter().map(|r| (r, &r.mapping));
+        let strict = table.rules().iter().filter_map(|r| Some((r, r.strict.as_ref()?)));
+        let readable = table.rules().iter().filter_map(|r| Some((r, r.readable.as_ref()?)));
+        let alternatives = table
+            .rules()
+            .iter()
+            .flat_map(|r| r.alternatives.iter().map(move |a| (r, &a.mapping)));
+
+        for (rule, mapping) in defaults.chain(strict).chain(readable).chain(alternatives) {
+            if mapping.runes.is_empty() {
+                continue;
+            }
+            let runes: Vec<char> = mapping.runes.iter().map(|r| r.ch).collect();
+            match entries.iter_mut().find(|e| e.runes == runes) {
+                Some(entry) => {
+                    if !entry.ipa.contains(&rule.ipa) {
+                        entry.ipa.push(rule.ipa.clone());
+                    }
+                }
+                None => entries.push(Entry {
+                    runes,
+                    names: mapping.runes.iter().map(|r| r.name.clone()).collect(),
+                    ipa: vec![rule.ipa.clone()],
+                }),
+            }
+        }
+
+        // Stable sort keeps the default-first order among equal lengths.
+        entries.sort_by_key(|e| std::cmp::Reverse(e.runes.len()));
+        Self { entries }
+    }
+
+    /// True when every rune (or Ogham letter) in `input` is one this table
+    /// writes.
+    pub fn covers(&self, input: &str) -> bool {
+        input
+            .chars()
+            .filter(|&c| is_letter(c) && !PUNCTUATION.iter().any(|(p, _)| *p == c))
+            .all(|c| self.entries.iter().any(|e| e.runes.contains(&c)))
+    }
+
+    /// Read `input` back, one [`Reading`] per matched rune sequence,
+    /// boundary, punctuation mark or unreadable character.
+    pub fn read(&self, input: &str) -> Vec<Reading> {
+        let chars: Vec<(usize, char)> = input.char_indices().collect();
+        let glyphs: Vec<char> = input.chars().collect();
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < chars.len() {
+            let start = chars[i].0;
+            let rest = &glyphs[i..];
+
+            let (len, ipa, names) = if let Some(entry) =
+                self.entries.iter().find(|e| rest.starts_with(&e.runes))
+            {
+                (entry.runes.len(), entry.ipa.clone(), entry.names.clone())
+            } else {
+                let c = rest[0];
+                let ipa = match PUNCTUATION.iter().find(|(p, _)| *p == c) {
+                    Some((_, ipa)) => vec![ipa.to_string()],
+                    None if c.is_whitespace() => vec![" ".to_string()],
+                    None if c.is_ascii_punctuation() => vec![c.to_string()],
+                    None => Vec::new(),
+                };
+                (1, ipa, Vec::new())
+            };
+
+            let end = chars.get(i + len).map_or(input.len(), |&(b, _)| b);
+            out.push(Reading {
+                runes: input[start..end].to_string(),
+                span: [start, end],
+                ipa,
+                names,
+            });
+            i += len;
+        }
+
+        out
+    }
+}
+
+/// A character of the Runic or Ogham block other than the Ogham space mark.
+fn is_letter(c: char) -> bool {
+    ('\u{1681}'..='\u{16FF}').contains(&c)
+}
+
+/// The readings as one IPA string: ambiguous runs as `{θ|ð}`, unreadable
+/// ones as `?`, word boundaries collapsed to one space and punctuation
+/// attached to the word before it.
+pub fn render(readings: &[Reading]) -> String {
+    let mut out = String::new();
+    for reading in readings {
+        match reading.ipa.as_slice() {
+            [] => out.push('?'),
+            [ipa] if ipa == " " => {
+                if !out.is_empty() && !out.ends_with(' ') {
+                    out.push(' ');
+                }
+            }
+            [ipa] if ipa.chars().all(|c| c.is_ascii_punctuation()) => {
+                // "naɪt, ðə" rather than "naɪt ,ðə".
+                if out.ends_with(' ') {
+                    out.pop();
+                }
+                out.push_str(ipa);
+                out.push(' ');
+            }
+            [ipa] => out.push_str(ipa),
+            several => {
+                out.push('{');
+                out.push_str(&several.join("|"));
+                out.push('}');
+            }
+        }
+    }
+    out.trim_end().to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(alphabet: &str, runes: &str) -> String {
+        let table = MappingTable::builtin(alphabet).unwrap();
+        render(&ReverseTable::new(&table).read(runes))
+    }
+
+    #[test]
+    fn digraphs_read_as_one_sound() {
+        // CEN + SH and AC + IS, as the forward mapping writes /tʃ/ and /aɪ/.
+        assert_eq!(read("futhorc", "ᚳᛲ"), "tʃ");
+        assert_eq!(read("futhorc", "ᚾᚪᛁᛏ"), "naɪt");
+    }
+
+    #[test]
+    fn ambiguous_runes_keep_every_reading() {
+        assert_eq!(read("futhorc", "ᚦ"), "{θ|ð}");
+        assert_eq!(read("futhorc", "ᛋ"), "{s|z}");
+        assert_eq!(read("younger-long", "ᛏ"), "{t|d}");
+    }
+
+    #[test]
+    fn punctuation_and_unknowns() {
+        assert_eq!(read("futhorc", "ᚾᚪᛁᛏ᛬ᚾᚪᛁᛏ᛭"), "naɪt, naɪt.");
+        assert_eq!(read("futhorc", "ᚾᚪᛁᛏ᛫ᚾᚪᛁᛏ"), "naɪt naɪt");
+        assert_eq!(read("futhorc", "ᚾxᛏ"), "n?t");
+    }
+
+    #[test]
+    fn coverage_picks_the_row() {
+        let futhorc = ReverseTable::new(&MappingTable::builtin("futhorc").unwrap());
+        let younger = ReverseTable::new(&MappingTable::builtin("younger-short").unwrap());
+        assert!(futhorc.covers("ᚾᚪᛁᛏ᛫"));
+        assert!(!futhorc.covers("ᚿ"));
+        assert!(younger.covers("ᚿ"));
+        assert!(!futhorc.covers("᚛ᚅᚙᚈ᚜"));
+    }
+
+    #[test]
+    fn ogham_reads_back() {
+        assert_eq!(read("ogham", "᚛ᚅᚙᚈ᚜\u{1680}᚛ᚈᚖ᚜"), "naɪt tɔɪ");
+        let tables: Vec<MappingTable> = MappingTable::alphabets()
+            .filter_map(MappingTable::builtin)
+            .collect();
+        assert_eq!(table_for_runes(&tables, "᚛ᚅᚙᚈ᚜").name, "ogham");
+    }
+}
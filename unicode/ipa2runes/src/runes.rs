@@ -0,0 +1,214 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! `ipa2runes runes`: list the rune inventory of each table.
+
+use std::io::{self, Write};
+use std::path::Path;
+
+use ipa2runes::inventory::{self, Entry, Filter};
+use ipa2runes::{MappingTable, Tradition};
+use serde::Serialize;
+
+/// The rune poems, in the order they are printed.
+const POEMS: &[(&str, &str)] = &[
+    ("old-english", "Old English"),
+    ("norwegian", "Norwegian"),
+    ("icelandic", "Icelandic"),
+    ("briatharogam", "Bríatharogam"),
+];
+
+#[derive(Serialize)]
+struct Listing<'a> {
+    table: &'a str,
+    #[serde(skip)]
+    description: &'a str,
+    runes: Vec<&'a Entry>,
+}
+
+fn write_usage(out: &mut dyn Write, prog: &str) -> io::Result<()> {
+    writeln!(out, "Usage:")?;
+    writeln!(out, "  {prog} runes [--alphabet NAME | --mapping FILE] [filters] [--json]")?;
+    writeln!(out, "Filters:")?;
+    writeln!(out, "  --tradition TAG  futhorc-core|anglo-saxon-extension|elder-futhark|")?;
+    writeln!(out, "                   scandinavian-variant|medieval|ogham|forfeda|approximation")?;
+    writeln!(out, "  --aett N         runes of the Nth ætt (or aicme) of their row")?;
+    writeln!(out, "  --name TEXT      runes named TEXT in any tradition (fehu, feoh, fé)")?;
+    writeln!(out, "  --sound IPA      runes that write the IPA token")?;
+    writeln!(out, "  --help, -h       print this help")?;
+    writeln!(out, "Examples:")?;
+    writeln!(out, "  {prog} runes --alphabet younger-long")?;
+    writeln!(out, "  {prog} runes --name tyr")?;
+    writeln!(out, "  {prog} runes --alphabet futhorc --sound k --json")?;
+    Ok(())
+}
+
+fn usage(prog: &str) -> ! {
+    let _ = write_usage(&mut io::stderr(), prog);
+    std::process::exit(2);
+}
+
+fn help(prog: &str) -> ! {
+    let _ = write_usage(&mut io::stdout().lock(), prog);
+    std::process::exit(0);
+}
+
+/// Run the subcommand with the arguments after `runes`.
+pub(crate) fn main(prog: &str, mut args: impl Iterator<Item = String>) {
+    let mut alphabet: Option<String> = None;
+    let mut mapping_path: Option<String> = None;
+    let mut filter = Filter::default();
+    let mut json = false;
+
+    while let Some(a) = args.next() {
+        let mut value = || args.next().unwrap_or_else(|| usage(prog));
+        if a == "--alphabet" {
+            alphabet = Some(value());
+        } else if a == "--mapping" {
+            mapping_path = Some(value());
+        } else if a == "--tradition" {
+            let tag = value();
+            filter.tradition = Some(Tradition::parse(&tag).unwrap_or_else(|| {
+                eprintln!("Error: unknown tradition {tag:?}.");
+                usage(prog);
+            }));
+        } else if a == "--aett" {
+            let n = value();
+            filter.group = Some(
+                n.parse()
+                    .ok()
+                    .filter(|&n: &usize| n > 0)
+                    .unwrap_or_else(|| {
+                        eprintln!("Error: --aett needs a positive number, not {n:?}.");
+                        usage(prog);
+                    }),
+            );
+        } else if a == "--name" {
+            filter.name = Some(value());
+        } else if a == "--sound" {
+            filter.sound = Some(ipa2runes::normalize_ipa(&value()));
+        } else if a == "--json" {
+            json = true;
+        } else if a == "--help" || a == "-h" {
+            help(prog);
+        } else {
+            eprintln!("Error: unexpected argument {a:?}.");
+            usage(prog);
+        }
+    }
+
+    let tables: Vec<MappingTable> = match (&mapping_path, &alphabet) {
+        (Some(_), Some(_)) => {
+            eprintln!("Error: --alphabet and --mapping cannot be combined.");
+            std::process::exit(2);
+        }
+        (Some(path), None) => vec![MappingTable::load(Path::new(path)).unwrap_or_else(|e| {
+            eprintln!("Error: invalid mapping table: {e}");
+            std::process::exit(2);
+        })],
+        (None, Some(name)) => vec![MappingTable::builtin(name).unwrap_or_else(|| {
+            eprintln!("Error: unknown alphabet {name:?}.");
+            usage(prog);
+        })],
+        (None, None) => MappingTable::alphabets()
+            .filter_map(MappingTable::builtin)
+            .collect(),
+    };
+
+    let entries: Vec<Vec<Entry>> = tables.iter().map(inventory::entries).collect();
+    let listings: Vec<Listing> = tables
+        .iter()
+        .zip(&entries)
+        .map(|(table, entries)| Listing {
+            table: &table.name,
+            description: &table.description,
+            runes: entries.iter().filter(|e| filter.matches(e)).collect(),
+        })
+        .filter(|l| !l.runes.is_empty())
+        .collect();
+
+    // `runes --json | head` closes the pipe early: that is a clean exit.
+    match print_listings(&mut io::stdout().lock(), &listings, json) {
+        Err(e) if e.kind() == io::ErrorKind::BrokenPipe => return,
+        Err(e) => {
+            eprintln!("Error: cannot write the listing: {e}");
+            std::process::exit(1);
+        }
+        Ok(()) => {}
+    }
+
+    // Exit code 1: nothing matched the filters.
+    if listings.is_empty() {
+        eprintln!("No runes match.");
+        std::process::exit(1);
+    }
+}
+
+fn print_listings(out: &mut impl Write, listings: &[Listing], json: bool) -> io::Result<()> {
+    if json {
+        let doc = serde_json::to_string_pretty(listings).expect("inventory serializes");
+        writeln!(out, "{doc}")
+    } else {
+        listings.iter().try_for_each(|listing| print_listing(out, listing))
+    }
+}
+
+fn print_listing(out: &mut impl Write, listing: &Listing) -> io::Result<()> {
+    writeln!(out, "{} ({})", listing.table, listing.description)?;
+    // Tables without a row in the lore have no groups to head.
+    let grouped = listing.runes.iter().any(|e| e.place.is_some());
+    if !grouped {
+        writeln!(out)?;
+    }
+    let mut group = None;
+    for e in &listing.runes {
+        let here = e.place.as_ref().map(|p| (p.number, p.group.as_str()));
+        if grouped && group != Some(here) {
+            writeln!(out)?;
+            match here {
+                Some((n, name)) => writeln!(out, "{n}. {name}")?,
+                None => writeln!(out, "Outside the row")?,
+            }
+            group = Some(here);
+        }
+        print_entry(out, e)?;
+    }
+    writeln!(out)
+}
+
+fn print_entry(out: &mut impl Write, e: &Entry) -> io::Result<()> {
+    let position = e
+        .place
+        .as_ref()
+        .map_or(String::new(), |p| p.position.to_string());
+    let tradition = serde_json::to_value(e.rune.tradition).expect("tradition serializes");
+    let mut sounds: Vec<String> = e.sounds.iter().map(|s| format!("/{s}/")).collect();
+    if !e.part_of.is_empty() {
+        let part_of: Vec<String> = e.part_of.iter().map(|s| format!("/{s}/")).collect();
+        sounds.push(format!("(in {})", part_of.join(" ")));
+    }
+    writeln!(
+        out,
+        "  {:>2} {}  U+{:04X}  {:<12} {:<24} {:<22} {}",
+        position,
+        e.rune.ch,
+        e.rune.codepoint,
+        e.key,
+        e.rune.name,
+        tradition.as_str().unwrap_or_default(),
+        sounds.join(" ")
+    )?;
+
+    let names: Vec<String> = e.names.iter().map(|(t, n)| format!("{t} {n}")).collect();
+    match (&e.meaning, names.is_empty()) {
+        (Some(meaning), false) => writeln!(out, "        {} — {meaning}", names.join(" · "))?,
+        (Some(meaning), true) => writeln!(out, "        {meaning}")?,
+        (None, _) => {}
+    }
+    for (key, poem) in POEMS {
+        if let Some(line) = e.poems.get(*key) {
+            writeln!(out, "        {poem:<13} {line}")?;
+        }
+    }
+    Ok(())
+}
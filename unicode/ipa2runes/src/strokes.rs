@@ -0,0 +1,755 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Stroke definitions: every rune the built-in tables write, drawn as
+//! straight line segments (staves and twigs), the way they were cut.
+//!
+//! Coordinates are in units of a glyph cell: y runs down from 0 (top of
+//! the stave) to 8 (its foot), x from 0 to the glyph's width. A rune with
+//! a full-height stave records its x, so two runes can be bound on it.
+//! Ogham letters are drawn across a horizontal stemline at y = 4, which
+//! the renderer extends through each cell.
+
+/// One cut: a line segment, or a dot as on the dotted runes.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Stroke {
+    Line(f32, f32, f32, f32),
+    Dot(f32, f32),
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Glyph {
+    pub ch: char,
+    pub width: f32,
+    /// x of the full-height stave a bind-rune can share.
+    pub stave: Option<f32>,
+    /// Drawn across an Ogham stemline.
+    pub stemline: bool,
+    pub strokes: &'static [Stroke],
+}
+
+/// Height of a glyph cell, in units.
+pub const HEIGHT: f32 = 8.0;
+
+/// y of the Ogham stemline.
+pub const STEMLINE: f32 = 4.0;
+
+const fn l(x1: f32, y1: f32, x2: f32, y2: f32) -> Stroke {
+    Stroke::Line(x1, y1, x2, y2)
+}
+
+const fn d(x: f32, y: f32) -> Stroke {
+    Stroke::Dot(x, y)
+}
+
+/// A full-height stave at `x`.
+const fn st(x: f32) -> Stroke {
+    Stroke::Line(x, 0.0, x, HEIGHT)
+}
+
+const fn rune(ch: char, width: f32, stave: Option<f32>, strokes: &'static [Stroke]) -> Glyph {
+    Glyph {
+        ch,
+        width,
+        stave,
+        stemline: false,
+        strokes,
+    }
+}
+
+const fn ogham(ch: char, width: f32, strokes: &'static [Stroke]) -> Glyph {
+    Glyph {
+        ch,
+        width,
+        stave: None,
+        stemline: true,
+        strokes,
+    }
+}
+
+pub const GLYPHS: &[Glyph] = &[
+    /* -------------------------------------------------------------------
+     * Runic letters, in code point order
+     * -------------------------------------------------------------------
+     */
+    rune(
+        'ᚠ',
+        3.0,
+        Some(0.0),
+        &[st(0.0), l(0.0, 3.0, 3.0, 0.5), l(0.0, 5.5, 3.0, 3.0)],
+    ),
+    rune(
+        'ᚡ',
+        3.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 3.0, 3.0, 0.5),
+            l(0.0, 5.5, 3.0, 3.0),
+            d(1.2, 5.5),
+        ],
+    ),
+    rune(
+        'ᚢ',
+        3.0,
+        Some(0.0),
+        &[st(0.0), l(0.0, 0.0, 3.0, 2.0), l(3.0, 2.0, 3.0, 8.0)],
+    ),
+    rune(
+        'ᚣ',
+        3.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 0.0, 3.0, 2.0),
+            l(3.0, 2.0, 3.0, 8.0),
+            l(1.5, 1.0, 1.5, 8.0),
+        ],
+    ),
+    rune(
+        'ᚤ',
+        3.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 0.0, 3.0, 2.0),
+            l(3.0, 2.0, 3.0, 8.0),
+            d(1.5, 5.0),
+        ],
+    ),
+    rune(
+        'ᚥ',
+        3.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 0.0, 3.0, 2.0),
+            l(3.0, 2.0, 3.0, 8.0),
+            l(0.0, 4.5, 3.0, 2.0),
+        ],
+    ),
+    rune(
+        'ᚦ',
+        2.5,
+        Some(0.0),
+        &[st(0.0), l(0.0, 2.0, 2.5, 4.0), l(2.5, 4.0, 0.0, 6.0)],
+    ),
+    rune(
+        'ᚧ',
+        2.5,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 2.0, 2.5, 4.0),
+            l(2.5, 4.0, 0.0, 6.0),
+            d(1.0, 4.0),
+        ],
+    ),
+    rune(
+        'ᚨ',
+        3.0,
+        Some(0.0),
+        &[st(0.0), l(0.0, 0.0, 3.0, 2.0), l(0.0, 2.5, 3.0, 4.5)],
+    ),
+    rune(
+        'ᚩ',
+        2.5,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 0.0, 2.5, 1.5),
+            l(2.5, 1.5, 2.5, 3.0),
+            l(0.0, 2.5, 2.5, 4.0),
+            l(2.5, 4.0, 2.5, 5.5),
+        ],
+    ),
+    rune(
+        'ᚪ',
+        4.0,
+        Some(1.0),
+        &[
+            st(1.0),
+            l(1.0, 0.0, 4.0, 2.0),
+            l(1.0, 2.5, 4.0, 4.5),
+            l(1.0, 2.5, 0.0, 4.0),
+        ],
+    ),
+    rune(
+        'ᚫ',
+        3.0,
+        Some(0.0),
+        &[st(0.0), l(0.0, 0.0, 3.0, 2.0), l(0.0, 3.0, 3.0, 1.0)],
+    ),
+    rune(
+        'ᚬ',
+        3.0,
+        Some(3.0),
+        &[st(3.0), l(3.0, 0.0, 0.0, 2.0), l(3.0, 2.5, 0.0, 4.5)],
+    ),
+    rune('ᚭ', 1.5, Some(1.5), &[st(1.5), l(1.5, 3.0, 0.0, 1.5)]),
+    rune('ᚮ', 3.0, Some(1.5), &[st(1.5), l(0.0, 1.5, 3.0, 3.5)]),
+    rune(
+        'ᚯ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(0.0, 1.5, 3.0, 3.5), l(0.0, 3.5, 3.0, 5.5)],
+    ),
+    rune(
+        'ᚱ',
+        2.5,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 0.0, 2.5, 2.0),
+            l(2.5, 2.0, 0.0, 4.0),
+            l(0.0, 4.0, 2.5, 8.0),
+        ],
+    ),
+    rune(
+        'ᚲ',
+        2.5,
+        None,
+        &[l(2.5, 2.0, 0.0, 4.0), l(0.0, 4.0, 2.5, 6.0)],
+    ),
+    rune(
+        'ᚳ',
+        2.5,
+        Some(0.0),
+        &[st(0.0), l(0.0, 0.0, 2.5, 1.5), l(2.5, 1.5, 2.5, 3.0)],
+    ),
+    rune('ᚴ', 2.5, Some(0.0), &[st(0.0), l(0.0, 4.0, 2.5, 1.5)]),
+    rune(
+        'ᚵ',
+        2.5,
+        Some(0.0),
+        &[st(0.0), l(0.0, 4.0, 2.5, 1.5), d(1.5, 4.5)],
+    ),
+    rune(
+        'ᚷ',
+        3.0,
+        None,
+        &[l(0.0, 0.0, 3.0, 8.0), l(3.0, 0.0, 0.0, 8.0)],
+    ),
+    rune(
+        'ᚸ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(0.0, 0.0, 3.0, 8.0), l(3.0, 0.0, 0.0, 8.0)],
+    ),
+    rune(
+        'ᚹ',
+        2.0,
+        Some(0.0),
+        &[st(0.0), l(0.0, 0.0, 2.0, 1.5), l(2.0, 1.5, 0.0, 3.0)],
+    ),
+    rune(
+        'ᚺ',
+        3.0,
+        Some(0.0),
+        &[st(0.0), st(3.0), l(0.0, 3.0, 3.0, 5.0)],
+    ),
+    rune(
+        'ᚻ',
+        3.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            st(3.0),
+            l(0.0, 2.5, 3.0, 4.0),
+            l(0.0, 4.0, 3.0, 5.5),
+        ],
+    ),
+    rune(
+        'ᚼ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(0.0, 2.0, 3.0, 6.0), l(3.0, 2.0, 0.0, 6.0)],
+    ),
+    rune('ᚽ', 2.0, Some(1.0), &[st(1.0), l(0.0, 3.5, 2.0, 4.5)]),
+    rune('ᚾ', 3.0, Some(1.5), &[st(1.5), l(0.0, 3.0, 3.0, 5.0)]),
+    rune('ᚿ', 2.0, Some(1.0), &[st(1.0), l(0.0, 4.5, 2.0, 3.5)]),
+    rune('ᛁ', 0.0, Some(0.0), &[st(0.0)]),
+    rune('ᛂ', 0.0, Some(0.0), &[st(0.0), d(0.0, 4.0)]),
+    rune(
+        'ᛃ',
+        3.0,
+        None,
+        &[
+            l(1.0, 1.0, 0.0, 2.5),
+            l(0.0, 2.5, 1.0, 4.0),
+            l(2.0, 4.0, 3.0, 5.5),
+            l(3.0, 5.5, 2.0, 7.0),
+        ],
+    ),
+    rune(
+        'ᛄ',
+        3.0,
+        Some(1.5),
+        &[
+            st(1.5),
+            l(1.5, 2.0, 3.0, 4.0),
+            l(3.0, 4.0, 1.5, 6.0),
+            l(1.5, 6.0, 0.0, 4.0),
+            l(0.0, 4.0, 1.5, 2.0),
+        ],
+    ),
+    rune(
+        'ᛅ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(0.0, 2.0, 1.5, 4.0), l(3.0, 2.0, 1.5, 4.0)],
+    ),
+    rune('ᛆ', 2.5, Some(1.0), &[st(1.0), l(1.0, 2.0, 2.5, 3.5)]),
+    rune(
+        'ᛇ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(1.5, 0.0, 3.0, 1.5), l(1.5, 8.0, 0.0, 6.5)],
+    ),
+    rune(
+        'ᛈ',
+        2.5,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 1.0, 2.5, 2.5),
+            l(2.5, 2.5, 2.5, 0.5),
+            l(0.0, 7.0, 2.5, 5.5),
+            l(2.5, 5.5, 2.5, 7.5),
+        ],
+    ),
+    rune(
+        'ᛉ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(1.5, 3.0, 0.0, 0.0), l(1.5, 3.0, 3.0, 0.0)],
+    ),
+    rune(
+        'ᛊ',
+        2.5,
+        None,
+        &[
+            l(2.0, 0.0, 0.0, 3.0),
+            l(0.0, 3.0, 2.5, 5.0),
+            l(2.5, 5.0, 0.5, 8.0),
+        ],
+    ),
+    rune(
+        'ᛋ',
+        3.0,
+        None,
+        &[
+            l(0.0, 0.0, 0.0, 3.0),
+            l(0.0, 3.0, 3.0, 5.0),
+            l(3.0, 5.0, 3.0, 8.0),
+        ],
+    ),
+    rune('ᛌ', 0.0, None, &[l(0.0, 0.0, 0.0, 4.0)]),
+    rune('ᛍ', 1.0, None, &[l(0.0, 0.0, 0.0, 4.0), d(1.0, 2.0)]),
+    rune(
+        'ᛎ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(1.5, 4.0, 0.0, 2.0), l(1.5, 4.0, 3.0, 2.0)],
+    ),
+    rune(
+        'ᛏ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(1.5, 0.0, 0.0, 2.0), l(1.5, 0.0, 3.0, 2.0)],
+    ),
+    rune('ᛐ', 1.5, Some(1.5), &[st(1.5), l(1.5, 0.0, 0.0, 1.5)]),
+    rune(
+        'ᛑ',
+        3.0,
+        Some(1.5),
+        &[
+            st(1.5),
+            l(1.5, 0.0, 0.0, 2.0),
+            l(1.5, 0.0, 3.0, 2.0),
+            d(2.3, 4.5),
+        ],
+    ),
+    rune(
+        'ᛒ',
+        2.5,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 0.0, 2.5, 2.0),
+            l(2.5, 2.0, 0.0, 4.0),
+            l(0.0, 4.0, 2.5, 6.0),
+            l(2.5, 6.0, 0.0, 8.0),
+        ],
+    ),
+    rune(
+        'ᛓ',
+        1.5,
+        Some(0.0),
+        &[st(0.0), l(0.0, 2.0, 1.5, 3.0), l(1.5, 3.0, 0.0, 4.0)],
+    ),
+    rune(
+        'ᛔ',
+        2.5,
+        Some(0.0),
+        &[
+            st(0.0),
+            l(0.0, 0.0, 2.5, 2.0),
+            l(2.5, 2.0, 0.0, 4.0),
+            l(0.0, 4.0, 2.5, 6.0),
+            l(2.5, 6.0, 0.0, 8.0),
+            d(1.0, 2.0),
+        ],
+    ),
+    rune(
+        'ᛖ',
+        3.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            st(3.0),
+            l(0.0, 0.0, 1.5, 2.0),
+            l(1.5, 2.0, 3.0, 0.0),
+        ],
+    ),
+    rune(
+        'ᛗ',
+        3.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            st(3.0),
+            l(0.0, 0.0, 3.0, 3.0),
+            l(3.0, 0.0, 0.0, 3.0),
+        ],
+    ),
+    rune(
+        'ᛘ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(0.0, 0.0, 1.5, 2.0), l(3.0, 0.0, 1.5, 2.0)],
+    ),
+    rune(
+        'ᛙ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(0.0, 1.0, 1.5, 0.0), l(1.5, 0.0, 3.0, 1.0)],
+    ),
+    rune('ᛚ', 2.5, Some(0.0), &[st(0.0), l(0.0, 0.0, 2.5, 2.0)]),
+    rune(
+        'ᛜ',
+        3.0,
+        None,
+        &[
+            l(1.5, 2.0, 3.0, 4.0),
+            l(3.0, 4.0, 1.5, 6.0),
+            l(1.5, 6.0, 0.0, 4.0),
+            l(0.0, 4.0, 1.5, 2.0),
+        ],
+    ),
+    rune(
+        'ᛝ',
+        3.0,
+        None,
+        &[
+            l(0.0, 1.0, 3.0, 5.0),
+            l(3.0, 1.0, 0.0, 5.0),
+            l(0.0, 3.0, 3.0, 7.0),
+            l(3.0, 3.0, 0.0, 7.0),
+        ],
+    ),
+    rune(
+        'ᛞ',
+        4.0,
+        Some(0.0),
+        &[
+            st(0.0),
+            st(4.0),
+            l(0.0, 0.0, 4.0, 8.0),
+            l(4.0, 0.0, 0.0, 8.0),
+        ],
+    ),
+    rune(
+        'ᛟ',
+        3.0,
+        None,
+        &[
+            l(1.5, 0.0, 3.0, 2.5),
+            l(1.5, 0.0, 0.0, 2.5),
+            l(3.0, 2.5, 0.0, 8.0),
+            l(0.0, 2.5, 3.0, 8.0),
+        ],
+    ),
+    rune(
+        'ᛠ',
+        3.0,
+        Some(1.5),
+        &[
+            st(1.5),
+            l(0.0, 2.0, 1.5, 0.0),
+            l(1.5, 0.0, 3.0, 2.0),
+            l(0.0, 4.0, 1.5, 2.0),
+            l(1.5, 2.0, 3.0, 4.0),
+        ],
+    ),
+    rune(
+        'ᛡ',
+        3.0,
+        Some(1.5),
+        &[
+            st(1.5),
+            l(0.0, 4.0, 3.0, 7.0),
+            l(3.0, 4.0, 0.0, 7.0),
+            l(1.5, 0.0, 3.0, 1.5),
+        ],
+    ),
+    rune(
+        'ᛣ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(1.5, 5.0, 0.0, 8.0), l(1.5, 5.0, 3.0, 8.0)],
+    ),
+    rune(
+        'ᛦ',
+        3.0,
+        Some(1.5),
+        &[st(1.5), l(1.5, 4.5, 0.0, 7.0), l(1.5, 4.5, 3.0, 7.0)],
+    ),
+    rune(
+        'ᛧ',
+        3.0,
+        None,
+        &[
+            l(1.5, 4.0, 1.5, 8.0),
+            l(1.5, 6.0, 0.0, 8.0),
+            l(1.5, 6.0, 3.0, 8.0),
+        ],
+    ),
+    rune(
+        'ᛩ',
+        3.0,
+        Some(1.5),
+        &[
+            st(1.5),
+            l(1.5, 5.0, 0.0, 8.0),
+            l(1.5, 5.0, 3.0, 8.0),
+            l(0.0, 0.0, 3.0, 0.0),
+        ],
+    ),
+    rune(
+        'ᛪ',
+        3.0,
+        None,
+        &[
+            l(0.0, 1.0, 3.0, 7.0),
+            l(3.0, 1.0, 0.0, 7.0),
+            l(0.0, 4.0, 3.0, 4.0),
+        ],
+    ),
+    rune(
+        'ᛲ',
+        3.0,
+        Some(1.5),
+        &[
+            st(1.5),
+            l(0.0, 2.0, 3.0, 3.0),
+            l(3.0, 3.0, 0.0, 5.0),
+            l(0.0, 5.0, 3.0, 6.0),
+        ],
+    ),
+    /* -------------------------------------------------------------------
+     * Runic punctuation
+     * -------------------------------------------------------------------
+     */
+    rune('᛫', 1.0, None, &[d(0.5, 4.0)]),
+    rune('᛬', 1.0, None, &[d(0.5, 2.5), d(0.5, 5.5)]),
+    rune(
+        '᛭',
+        2.0,
+        None,
+        &[l(0.0, 4.0, 2.0, 4.0), l(1.0, 3.0, 1.0, 5.0)],
+    ),
+    /* -------------------------------------------------------------------
+     * Ogham: aicme beithe below the stemline, aicme húatha above,
+     * aicme muine across it slanted, the vowels as notches on it
+     * -------------------------------------------------------------------
+     */
+    ogham('\u{1680}', 2.0, &[]),
+    ogham('ᚁ', 2.0, &[l(1.0, 4.0, 1.0, 7.0)]),
+    ogham('ᚂ', 3.0, &[l(1.0, 4.0, 1.0, 7.0), l(2.0, 4.0, 2.0, 7.0)]),
+    ogham(
+        'ᚃ',
+        4.0,
+        &[
+            l(1.0, 4.0, 1.0, 7.0),
+            l(2.0, 4.0, 2.0, 7.0),
+            l(3.0, 4.0, 3.0, 7.0),
+        ],
+    ),
+    ogham(
+        'ᚄ',
+        5.0,
+        &[
+            l(1.0, 4.0, 1.0, 7.0),
+            l(2.0, 4.0, 2.0, 7.0),
+            l(3.0, 4.0, 3.0, 7.0),
+            l(4.0, 4.0, 4.0, 7.0),
+        ],
+    ),
+    ogham(
+        'ᚅ',
+        6.0,
+        &[
+            l(1.0, 4.0, 1.0, 7.0),
+            l(2.0, 4.0, 2.0, 7.0),
+            l(3.0, 4.0, 3.0, 7.0),
+            l(4.0, 4.0, 4.0, 7.0),
+            l(5.0, 4.0, 5.0, 7.0),
+        ],
+    ),
+    ogham('ᚆ', 2.0, &[l(1.0, 1.0, 1.0, 4.0)]),
+    ogham('ᚇ', 3.0, &[l(1.0, 1.0, 1.0, 4.0), l(2.0, 1.0, 2.0, 4.0)]),
+    ogham(
+        'ᚈ',
+        4.0,
+        &[
+            l(1.0, 1.0, 1.0, 4.0),
+            l(2.0, 1.0, 2.0, 4.0),
+            l(3.0, 1.0, 3.0, 4.0),
+        ],
+    ),
+    ogham(
+        'ᚉ',
+        5.0,
+        &[
+            l(1.0, 1.0, 1.0, 4.0),
+            l(2.0, 1.0, 2.0, 4.0),
+            l(3.0, 1.0, 3.0, 4.0),
+            l(4.0, 1.0, 4.0, 4.0),
+        ],
+    ),
+    ogham(
+        'ᚊ',
+        6.0,
+        &[
+            l(1.0, 1.0, 1.0, 4.0),
+            l(2.0, 1.0, 2.0, 4.0),
+            l(3.0, 1.0, 3.0, 4.0),
+            l(4.0, 1.0, 4.0, 4.0),
+            l(5.0, 1.0, 5.0, 4.0),
+        ],
+    ),
+    ogham('ᚋ', 3.0, &[l(2.2, 1.5, 0.8, 6.5)]),
+    ogham('ᚌ', 4.0, &[l(2.2, 1.5, 0.8, 6.5), l(3.2, 1.5, 1.8, 6.5)]),
+    ogham(
+        'ᚍ',
+        5.0,
+        &[
+            l(2.2, 1.5, 0.8, 6.5),
+            l(3.2, 1.5, 1.8, 6.5),
+            l(4.2, 1.5, 2.8, 6.5),
+        ],
+    ),
+    ogham(
+        'ᚎ',
+        6.0,
+        &[
+            l(2.2, 1.5, 0.8, 6.5),
+            l(3.2, 1.5, 1.8, 6.5),
+            l(4.2, 1.5, 2.8, 6.5),
+            l(5.2, 1.5, 3.8, 6.5),
+        ],
+    ),
+    ogham(
+        'ᚏ',
+        7.0,
+        &[
+            l(2.2, 1.5, 0.8, 6.5),
+            l(3.2, 1.5, 1.8, 6.5),
+            l(4.2, 1.5, 2.8, 6.5),
+            l(5.2, 1.5, 3.8, 6.5),
+            l(6.2, 1.5, 4.8, 6.5),
+        ],
+    ),
+    ogham('ᚐ', 2.0, &[l(1.0, 3.0, 1.0, 5.0)]),
+    ogham('ᚑ', 3.0, &[l(1.0, 3.0, 1.0, 5.0), l(2.0, 3.0, 2.0, 5.0)]),
+    ogham(
+        'ᚒ',
+        4.0,
+        &[
+            l(1.0, 3.0, 1.0, 5.0),
+            l(2.0, 3.0, 2.0, 5.0),
+            l(3.0, 3.0, 3.0, 5.0),
+        ],
+    ),
+    ogham(
+        'ᚓ',
+        5.0,
+        &[
+            l(1.0, 3.0, 1.0, 5.0),
+            l(2.0, 3.0, 2.0, 5.0),
+            l(3.0, 3.0, 3.0, 5.0),
+            l(4.0, 3.0, 4.0, 5.0),
+        ],
+    ),
+    ogham(
+        'ᚔ',
+        6.0,
+        &[
+            l(1.0, 3.0, 1.0, 5.0),
+            l(2.0, 3.0, 2.0, 5.0),
+            l(3.0, 3.0, 3.0, 5.0),
+            l(4.0, 3.0, 4.0, 5.0),
+            l(5.0, 3.0, 5.0, 5.0),
+        ],
+    ),
+    // Forfeda
+    ogham('ᚕ', 4.0, &[l(0.5, 1.5, 3.5, 6.5), l(3.5, 1.5, 0.5, 6.5)]),
+    ogham(
+        'ᚖ',
+        4.0,
+        &[
+            l(2.0, 1.5, 3.5, 4.0),
+            l(3.5, 4.0, 2.0, 6.5),
+            l(2.0, 6.5, 0.5, 4.0),
+            l(0.5, 4.0, 2.0, 1.5),
+        ],
+    ),
+    ogham(
+        'ᚗ',
+        4.0,
+        &[
+            l(0.5, 4.0, 0.5, 1.5),
+            l(0.5, 1.5, 3.0, 1.5),
+            l(3.0, 1.5, 3.0, 4.0),
+        ],
+    ),
+    ogham(
+        'ᚘ',
+        4.0,
+        &[
+            l(0.5, 2.0, 2.5, 6.0),
+            l(1.5, 2.0, 3.5, 6.0),
+            l(2.5, 2.0, 0.5, 6.0),
+            l(3.5, 2.0, 1.5, 6.0),
+        ],
+    ),
+    ogham(
+        'ᚙ',
+        6.0,
+        &[
+            l(0.5, 1.5, 3.0, 6.5),
+            l(3.0, 1.5, 0.5, 6.5),
+            l(3.0, 1.5, 5.5, 6.5),
+            l(5.5, 1.5, 3.0, 6.5),
+        ],
+    ),
+    ogham('ᚚ', 4.0, &[l(0.5, 4.0, 2.0, 7.0), l(2.0, 7.0, 3.5, 4.0)]),
+    ogham('᚛', 2.0, &[l(0.0, 1.5, 2.0, 4.0), l(0.0, 6.5, 2.0, 4.0)]),
+    ogham('᚜', 2.0, &[l(2.0, 1.5, 0.0, 4.0), l(2.0, 6.5, 0.0, 4.0)]),
+];
+
+/// The strokes for `ch`, if it is one of the [`GLYPHS`].
+pub fn glyph(ch: char) -> Option<&'static Glyph> {
+    GLYPHS.iter().find(|g| g.ch == ch)
+}
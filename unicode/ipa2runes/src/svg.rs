@@ -0,0 +1,657 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! SVG rendering: a transliteration drawn from the [`strokes`] of each
+//! rune, so the picture needs no font and looks the same everywhere.
+//!
+//! The runes can run left to right, right to left (mirrored, facing the
+//! way they are read, as on many early stones), boustrophedon (lines
+//! turning back and forth, "as the ox ploughs"), or along a serpent band
+//! in the manner of the Uppland rune stones. With binding, the runes of
+//! one token (the digraphs a table writes with two runes) are cut as a
+//! bind-rune on one shared stave.
+//!
+//! The renderer reads the horizontal output of a [`Transliteration`];
+//! vertical output is laid out by the SVG direction instead.
+//!
+//! [`strokes`]: crate::strokes
+
+use std::f32::consts::PI;
+use std::fmt::Write;
+
+use crate::strokes::{self, Glyph, HEIGHT, STEMLINE, Stroke};
+use crate::{TokenStatus, Transliteration};
+
+/// Space between two glyphs.
+const GAP: f32 = 1.5;
+/// Space between two lines.
+const LEADING: f32 = 4.0;
+/// Width of a plain space.
+const SPACE: f32 = 3.0;
+/// Blank border around the drawing.
+const MARGIN: f32 = 3.0;
+/// Pixels per unit in the width and height attributes.
+const SCALE: f32 = 4.0;
+const STROKE_WIDTH: f32 = 0.6;
+const DOT_RADIUS: f32 = 0.5;
+
+/// Drawn for a character with no stroke definition: a crossed box.
+const UNKNOWN: Glyph = Glyph {
+    ch: '\u{FFFD}',
+    width: 3.0,
+    stave: None,
+    stemline: false,
+    strokes: &[
+        Stroke::Line(0.0, 0.0, 3.0, 0.0),
+        Stroke::Line(3.0, 0.0, 3.0, 8.0),
+        Stroke::Line(3.0, 8.0, 0.0, 8.0),
+        Stroke::Line(0.0, 8.0, 0.0, 0.0),
+        Stroke::Line(0.0, 0.0, 3.0, 8.0),
+    ],
+};
+
+/// The serpent's centreline: a wave of this amplitude and wavelength.
+const AMPLITUDE: f32 = 8.0;
+const WAVELENGTH: f32 = 60.0;
+/// Half the band's width: the glyph plus a margin on each side.
+const BAND: f32 = HEIGHT / 2.0 + 1.5;
+
+/// Which way the runes run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Direction {
+    #[default]
+    LeftToRight,
+    /// Right to left, every glyph mirrored.
+    RightToLeft,
+    /// Alternate lines right to left, mirrored; needs a wrap width.
+    Boustrophedon,
+    /// One line along a winding band, head first.
+    Serpent,
+}
+
+impl Direction {
+    pub fn parse(s: &str) -> Option<Self> {
+        match s {
+            "ltr" => Some(Direction::LeftToRight),
+            "rtl" => Some(Direction::RightToLeft),
+            "boustrophedon" => Some(Direction::Boustrophedon),
+            "serpent" => Some(Direction::Serpent),
+            _ => None,
+        }
+    }
+}
+
+/// How the SVG is drawn.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Drawing {
+    pub direction: Direction,
+    /// Bind the runes of each token on a shared stave.
+    pub bind: bool,
+    /// Glyphs per line, broken at word gaps; 0 keeps one line.
+    /// Boustrophedon without it wraps at [`Drawing::BOUSTROPHEDON_WRAP`].
+    pub wrap: usize,
+}
+
+impl Drawing {
+    pub const BOUSTROPHEDON_WRAP: usize = 12;
+}
+
+impl Default for Drawing {
+    fn default() -> Self {
+        Self {
+            direction: Direction::LeftToRight,
+            bind: false,
+            wrap: 0,
+        }
+    }
+}
+
+/* ===========================================================================
+ * Cells
+ * ===========================================================================
+ */
+
+/// One glyph as placed: a single rune, a bind-rune, or a mark between
+/// words. Strokes are already offset into the cell.
+#[derive(Debug, Clone, PartialEq)]
+struct Cell {
+    strokes: Vec<Stroke>,
+    width: f32,
+    stemline: bool,
+    /// A rune, rather than a separator, punctuation or feather mark.
+    letter: bool,
+}
+
+impl Cell {
+    fn glyph(g: &Glyph, letter: bool) -> Self {
+        Self {
+            strokes: g.strokes.to_vec(),
+            width: g.width,
+            stemline: g.stemline,
+            letter,
+        }
+    }
+
+    fn of(ch: char, letter: bool) -> Self {
+        if ch == ' ' {
+            return Self {
+                strokes: Vec::new(),
+                width: SPACE,
+                stemline: false,
+                letter,
+            };
+        }
+        Self::glyph(strokes::glyph(ch).unwrap_or(&UNKNOWN), letter)
+    }
+
+    /// The glyphs cut on one stave: each shifted so its stave lies on
+    /// the first one's, and a stroke two of them share drawn once.
+    fn bound(glyphs: &[&Glyph]) -> Self {
+        let base = glyphs[0].stave.unwrap_or(0.0);
+        let left = glyphs
+            .iter()
+            .map(|g| base - g.stave.unwrap_or(0.0))
+            .fold(0.0, f32::min);
+        let mut cell = Self {
+            strokes: Vec::new(),
+            width: 0.0,
+            stemline: false,
+            letter: true,
+        };
+        for g in glyphs {
+            let dx = base - g.stave.unwrap_or(0.0) - left;
+            cell.width = cell.width.max(dx + g.width);
+            for s in g.strokes {
+                let s = shift(*s, dx);
+                if !cell.strokes.contains(&s) {
+                    cell.strokes.push(s);
+                }
+            }
+        }
+        cell
+    }
+
+    fn mirrored(&self) -> Self {
+        let w = self.width;
+        let strokes = self
+            .strokes
+            .iter()
+            .map(|s| match *s {
+                Stroke::Line(x1, y1, x2, y2) => Stroke::Line(w - x1, y1, w - x2, y2),
+                Stroke::Dot(x, y) => Stroke::Dot(w - x, y),
+            })
+            .collect();
+        Self { strokes, ..*self }
+    }
+}
+
+fn shift(s: Stroke, dx: f32) -> Stroke {
+    match s {
+        Stroke::Line(x1, y1, x2, y2) => Stroke::Line(x1 + dx, y1, x2 + dx, y2),
+        Stroke::Dot(x, y) => Stroke::Dot(x + dx, y),
+    }
+}
+
+/// The output as cells. The runes are matched back to the tokens that
+/// wrote them, so a token's runes can be bound; every other character
+/// of the output is a mark of its own.
+fn cells(t: &Transliteration, bind: bool) -> Vec<Cell> {
+    let groups: Vec<Vec<char>> = t
+        .tokens
+        .iter()
+        .filter(|tok| tok.status == TokenStatus::Mapped && !tok.runes.is_empty())
+        .map(|tok| tok.runes.iter().map(|r| r.ch).collect())
+        .collect();
+
+    let mut out = Vec::new();
+    let (mut group, mut pos) = (0, 0);
+    for c in t.output.chars().filter(|&c| c != '\n') {
+        let Some(runes) = groups.get(group).filter(|g| g[pos] == c) else {
+            out.push(Cell::of(c, false));
+            continue;
+        };
+        pos += 1;
+        if pos < runes.len() {
+            continue;
+        }
+        let glyphs: Option<Vec<&Glyph>> = runes
+            .iter()
+            .map(|&r| strokes::glyph(r).filter(|g| g.stave.is_some()))
+            .collect();
+        match glyphs {
+            Some(glyphs) if bind && glyphs.len() > 1 => out.push(Cell::bound(&glyphs)),
+            _ => out.extend(runes.iter().map(|&r| Cell::of(r, true))),
+        }
+        group += 1;
+        pos = 0;
+    }
+    out
+}
+
+/// Break the cells into lines of at most `wrap` glyphs, between words
+/// where possible. A word runs up to and including the mark after it.
+fn lines(cells: &[Cell], wrap: usize) -> Vec<&[Cell]> {
+    if wrap == 0 || cells.is_empty() {
+        return vec![cells];
+    }
+    let mut words = Vec::new();
+    let mut start = 0;
+    for (i, cell) in cells.iter().enumerate() {
+        if !cell.letter && cells.get(i + 1).is_none_or(|next| next.letter) {
+            words.push(start..i + 1);
+            start = i + 1;
+        }
+    }
+    if start < cells.len() {
+        words.push(start..cells.len());
+    }
+
+    let mut lines = Vec::new();
+    let mut line = 0..0;
+    for word in words {
+        if line.len() + word.len() > wrap && !line.is_empty() {
+            lines.push(line.clone());
+            line = word.start..word.start;
+        }
+        // A word too long for any line is broken where it overflows.
+        for i in word {
+            if line.len() == wrap {
+                lines.push(line.clone());
+                line = i..i;
+            }
+            line.end = i + 1;
+        }
+    }
+    if !line.is_empty() {
+        lines.push(line);
+    }
+    lines.into_iter().map(|r| &cells[r]).collect()
+}
+
+fn advance(line: &[Cell]) -> f32 {
+    line.iter().map(|c| c.width + GAP).sum::<f32>() - GAP
+}
+
+/* ===========================================================================
+ * Rendering
+ * ===========================================================================
+ */
+
+/// The drawing's extent, grown as glyphs are placed.
+struct Bounds {
+    min: (f32, f32),
+    max: (f32, f32),
+}
+
+impl Bounds {
+    fn new() -> Self {
+        Self {
+            min: (f32::MAX, f32::MAX),
+            max: (f32::MIN, f32::MIN),
+        }
+    }
+
+    fn add(&mut self, x: f32, y: f32) {
+        self.min = (self.min.0.min(x), self.min.1.min(y));
+        self.max = (self.max.0.max(x), self.max.1.max(y));
+    }
+}
+
+/// `t` drawn as a standalone SVG document.
+pub fn render(t: &Transliteration, drawing: &Drawing) -> String {
+    let cells = cells(t, drawing.bind);
+    let mut body = String::new();
+    let mut bounds = Bounds::new();
+    match drawing.direction {
+        Direction::Serpent => serpent(&cells, &mut body, &mut bounds),
+        direction => {
+            let wrap = match (direction, drawing.wrap) {
+                (Direction::Boustrophedon, 0) => Drawing::BOUSTROPHEDON_WRAP,
+                (_, wrap) => wrap,
+            };
+            rows(&cells, direction, wrap, &mut body, &mut bounds)
+        }
+    }
+    if bounds.min.0 > bounds.max.0 {
+        bounds.add(0.0, 0.0);
+        bounds.add(0.0, HEIGHT);
+    }
+
+    let (x, y) = (bounds.min.0 - MARGIN, bounds.min.1 - MARGIN);
+    let (w, h) = (
+        bounds.max.0 - bounds.min.0 + 2.0 * MARGIN,
+        bounds.max.1 - bounds.min.1 + 2.0 * MARGIN,
+    );
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="{} {} {} {}" width="{}" height="{}">"#,
+        num(x),
+        num(y),
+        num(w),
+        num(h),
+        num(w * SCALE),
+        num(h * SCALE)
+    );
+    let _ = writeln!(svg, "<title>{}</title>", escape(&t.output));
+    let _ = writeln!(
+        svg,
+        r#"<g fill="none" stroke="black" stroke-width="{}" stroke-linecap="round" stroke-linejoin="round">"#,
+        num(STROKE_WIDTH)
+    );
+    svg.push_str(&body);
+    svg.push_str("</g>\n</svg>\n");
+    svg
+}
+
+/// Left to right, right to left or boustrophedon, in lines. Right to
+/// left lines are mirrored and set flush right.
+fn rows(cells: &[Cell], direction: Direction, wrap: usize, body: &mut String, bounds: &mut Bounds) {
+    let lines = lines(cells, wrap);
+    let width = lines.iter().map(|l| advance(l)).fold(0.0, f32::max);
+    for (n, line) in lines.iter().enumerate() {
+        let y = n as f32 * (HEIGHT + LEADING);
+        let rtl = match direction {
+            Direction::RightToLeft => true,
+            Direction::Boustrophedon => n % 2 == 1,
+            _ => false,
+        };
+        let mut x = if rtl { width } else { 0.0 };
+        for cell in line.iter() {
+            let (cell, left) = if rtl {
+                x -= cell.width;
+                let left = x;
+                x -= GAP;
+                (cell.mirrored(), left)
+            } else {
+                let left = x;
+                x += cell.width + GAP;
+                (cell.clone(), left)
+            };
+            let _ = writeln!(
+                body,
+                r#"<g transform="translate({} {})">"#,
+                num(left),
+                num(y)
+            );
+            glyph(&cell, body);
+            body.push_str("</g>\n");
+            bounds.add(left, y);
+            bounds.add(left + cell.width, y + HEIGHT);
+        }
+    }
+}
+
+/// The serpent's centreline at distance `x` along the page.
+fn wave(x: f32) -> (f32, f32) {
+    (x, AMPLITUDE * (2.0 * PI * x / WAVELENGTH).sin())
+}
+
+/// Points along the centreline, evenly spaced in arc length.
+struct Path {
+    /// (arc length, point, tangent angle in radians)
+    samples: Vec<(f32, (f32, f32), f32)>,
+}
+
+impl Path {
+    const STEP: f32 = 0.25;
+
+    fn new(length: f32) -> Self {
+        let mut samples = Vec::new();
+        let (mut s, mut x) = (0.0, 0.0);
+        let mut p = wave(x);
+        while s <= length + Self::STEP {
+            let q = wave(x + Self::STEP);
+            let angle = (q.1 - p.1).atan2(q.0 - p.0);
+            samples.push((s, p, angle));
+            s += ((q.0 - p.0).powi(2) + (q.1 - p.1).powi(2)).sqrt();
+            x += Self::STEP;
+            p = q;
+        }
+        Self { samples }
+    }
+
+    /// Point and tangent angle at arc length `s`.
+    fn at(&self, s: f32) -> ((f32, f32), f32) {
+        let i = self
+            .samples
+            .partition_point(|&(len, _, _)| len <= s)
+            .saturating_sub(1);
+        let (_, p, angle) = self.samples[i];
+        (p, angle)
+    }
+
+    /// The point `offset` to the side of the centreline at `s`.
+    fn beside(&self, s: f32, offset: f32) -> (f32, f32) {
+        let ((x, y), angle) = self.at(s);
+        (x - offset * angle.sin(), y + offset * angle.cos())
+    }
+}
+
+/// One line along a winding band: the head at the start, where the
+/// inscription begins, and the band tapering to a tail after it.
+fn serpent(cells: &[Cell], body: &mut String, bounds: &mut Bounds) {
+    const HEAD: f32 = 6.0;
+    const TAIL: f32 = 10.0;
+    let text = advance(cells).max(0.0);
+    let length = HEAD + text + 2.0 * GAP + TAIL;
+    let path = Path::new(length);
+
+    // The band: one edge out to the tail, the other back.
+    let start = HEAD;
+    let end = HEAD + text + 2.0 * GAP;
+    let mut band = Vec::new();
+    let mut s = start;
+    while s < end {
+        band.push(path.beside(s, -BAND));
+        s += 1.0;
+    }
+    band.push(path.beside(end, -BAND));
+    band.push(path.at(length).0);
+    band.push(path.beside(end, BAND));
+    let mut s = end;
+    while s > start {
+        band.push(path.beside(s, BAND));
+        s -= 1.0;
+    }
+    band.push(path.beside(start, BAND));
+    let points: Vec<String> = band
+        .iter()
+        .map(|&(x, y)| {
+            bounds.add(x, y);
+            format!("{},{}", num(x), num(y))
+        })
+        .collect();
+    let _ = writeln!(body, r#"<polygon points="{}"/>"#, points.join(" "));
+
+    // The head, and its eye.
+    let ((hx, hy), angle) = path.at(HEAD / 2.0);
+    let _ = writeln!(
+        body,
+        r#"<ellipse cx="{}" cy="{}" rx="{}" ry="{}" transform="rotate({} {} {})"/>"#,
+        num(hx),
+        num(hy),
+        num(HEAD / 2.0 + 1.0),
+        num(BAND + 0.5),
+        num(angle.to_degrees()),
+        num(hx),
+        num(hy)
+    );
+    let (ex, ey) = path.beside(HEAD / 2.0, -BAND / 2.0);
+    let _ = writeln!(
+        body,
+        r#"<circle cx="{}" cy="{}" r="{}" fill="black" stroke="none"/>"#,
+        num(ex),
+        num(ey),
+        num(DOT_RADIUS)
+    );
+    bounds.add(hx - HEAD / 2.0 - 1.0 - BAND, hy - HEAD / 2.0 - 1.0 - BAND);
+    bounds.add(hx + HEAD / 2.0 + 1.0 + BAND, hy + HEAD / 2.0 + 1.0 + BAND);
+
+    // The glyphs, standing on the band's lower edge.
+    let mut s = HEAD + GAP;
+    for cell in cells {
+        let ((x, y), angle) = path.at(s + cell.width / 2.0);
+        let _ = writeln!(
+            body,
+            r#"<g transform="translate({} {}) rotate({}) translate({} {})">"#,
+            num(x),
+            num(y),
+            num(angle.to_degrees()),
+            num(-cell.width / 2.0),
+            num(-HEIGHT / 2.0)
+        );
+        glyph(cell, body);
+        body.push_str("</g>\n");
+        s += cell.width + GAP;
+    }
+}
+
+/// A cell's strokes, in its own coordinates.
+fn glyph(cell: &Cell, body: &mut String) {
+    if cell.stemline {
+        let _ = writeln!(
+            body,
+            r#"<line x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+            num(-GAP / 2.0),
+            num(STEMLINE),
+            num(cell.width + GAP / 2.0),
+            num(STEMLINE)
+        );
+    }
+    for s in &cell.strokes {
+        let _ = match *s {
+            Stroke::Line(x1, y1, x2, y2) => writeln!(
+                body,
+                r#"<line x1="{}" y1="{}" x2="{}" y2="{}"/>"#,
+                num(x1),
+                num(y1),
+                num(x2),
+                num(y2)
+            ),
+            Stroke::Dot(x, y) => writeln!(
+                body,
+                r#"<circle cx="{}" cy="{}" r="{}" fill="black" stroke="none"/>"#,
+                num(x),
+                num(y),
+                num(DOT_RADIUS)
+            ),
+        };
+    }
+}
+
+/// A coordinate with at most two decimals and no trailing zeros.
+fn num(v: f32) -> String {
+    let s = format!("{v:.2}");
+    let s = s.trim_end_matches('0').trim_end_matches('.');
+    match s {
+        "-0" | "" => "0".to_string(),
+        s => s.to_string(),
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{MappingTable, Options, transliterate};
+
+    fn futhorc(ipa: &str) -> Transliteration {
+        transliterate(ipa, Options::default())
+    }
+
+    #[test]
+    fn every_builtin_rune_has_strokes() {
+        for name in MappingTable::alphabets() {
+            let table = MappingTable::builtin(name).unwrap();
+            for rune in table.runes.values() {
+                assert!(
+                    strokes::glyph(rune.ch).is_some(),
+                    "{name}: no strokes for {} ({})",
+                    rune.ch,
+                    rune.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn one_cell_per_glyph() {
+        // ᚾᚪᛁᛏ᛫ᛏᚩᛁ: seven runes and the separator.
+        let t = futhorc("/naɪt tɔɪ/");
+        let cells = cells(&t, false);
+        assert_eq!(cells.len(), 8);
+        assert_eq!(cells.iter().filter(|c| !c.letter).count(), 1);
+    }
+
+    #[test]
+    fn binding_shares_the_stave() {
+        // /aɪ/ is ᚪᛁ: the ᛁ is ᚪ's stave, so the bind-rune is ᚪ alone.
+        let t = futhorc("/naɪt/");
+        let bound = cells(&t, true);
+        assert_eq!(bound.len(), 3);
+        let a = Cell::of('ᚪ', true);
+        assert_eq!(bound[1].strokes, a.strokes);
+        assert_eq!(bound[1].width, a.width);
+
+        // A rune without a stave is never bound.
+        let x = futhorc("/ks/");
+        assert_eq!(cells(&x, true).len(), cells(&x, false).len());
+    }
+
+    #[test]
+    fn lines_break_between_words() {
+        let t = futhorc("/naɪt tɔɪ naɪt/");
+        let cells = cells(&t, false);
+        let lines = lines(&cells, 5);
+        assert_eq!(lines.len(), 3);
+        assert!(lines.iter().all(|l| l.len() <= 5));
+        assert!(lines[0].last().is_some_and(|c| !c.letter));
+    }
+
+    #[test]
+    fn rtl_mirrors() {
+        let cell = Cell::of('ᚱ', true);
+        let mirrored = cell.mirrored();
+        assert_eq!(mirrored.strokes[0], Stroke::Line(2.5, 0.0, 2.5, 8.0));
+        assert_eq!(mirrored.mirrored(), cell);
+    }
+
+    #[test]
+    fn renders_every_direction() {
+        let t = futhorc("/naɪt tɔɪ naɪt tɔɪ/");
+        for direction in ["ltr", "rtl", "boustrophedon", "serpent"] {
+            let drawing = Drawing {
+                direction: Direction::parse(direction).unwrap(),
+                bind: true,
+                wrap: 0,
+            };
+            let svg = render(&t, &drawing);
+            assert!(svg.starts_with("<svg xmlns="), "{direction}");
+            assert!(svg.ends_with("</svg>\n"), "{direction}");
+            assert!(!svg.contains("NaN"), "{direction}");
+        }
+        let serpent = Drawing {
+            direction: Direction::Serpent,
+            ..Drawing::default()
+        };
+        assert!(render(&t, &serpent).contains("<polygon"));
+    }
+
+    #[test]
+    fn ogham_runs_on_a_stemline() {
+        let options = Options {
+            table: MappingTable::builtin("ogham").unwrap(),
+            ..Options::default()
+        };
+        let t = transliterate("/naɪt/", options);
+        let cells = cells(&t, false);
+        assert!(cells.iter().all(|c| c.stemline));
+        assert!(!render(&t, &Drawing::default()).contains("<polygon"));
+    }
+}
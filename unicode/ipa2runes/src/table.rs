@@ -0,0 +1,723 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Mapping tables: the IPA token list and the rune each token becomes.
+//!
+//! Tables are plain TOML or JSON. The files under `mappings/` are compiled
+//! in, one per target alphabet, with `futhorc.toml` as the default. A file
+//! is read into the raw `TableFile` shape, then validated and resolved into
+//! a [`MappingTable`]: every rune reference must name a defined rune, and
+//! every rune must be an assigned codepoint in the table's Unicode block
+//! (Runic, or Ogham for `script = "ogham"` tables).
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt;
+use std::fs;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+use unicode_normalization::UnicodeNormalization;
+
+use crate::{Alternative, Mapping, Mode, RuneChar, Script, Tradition};
+
+/// Compiled-in tables, selectable with `--alphabet`. The first is the
+/// default when neither `--alphabet` nor `--mapping FILE` is given.
+const BUILTIN: &[(&str, &str)] = &[
+    ("futhorc", include_str!("../mappings/futhorc.toml")),
+    ("elder", include_str!("../mappings/elder.toml")),
+    ("younger-long", include_str!("../mappings/younger-long.toml")),
+    ("younger-short", include_str!("../mappings/younger-short.toml")),
+    ("medieval", include_str!("../mappings/medieval.toml")),
+    ("ogham", include_str!("../mappings/ogham.toml")),
+];
+
+/// Score of another mode's mapping when ranking a token's alternatives;
+/// the current mode's own mapping scores 1.
+const VARIANT_SCORE: f64 = 0.8;
+
+/// Score of a declared alternative that gives none.
+const ALTERNATIVE_SCORE: f64 = 0.5;
+
+/* ===========================================================================
+ * File format
+ * ===========================================================================
+ */
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct TableFile {
+    name: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    description: String,
+    #[serde(default)]
+    script: Script,
+    runes: BTreeMap<String, RuneDef>,
+    tokens: Vec<TokenDef>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct RuneDef {
+    glyph: String,
+    /// Optional "U+16A0" cross-check against `glyph`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    codepoint: Option<String>,
+    name: String,
+    tradition: Tradition,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct TokenDef {
+    ipa: String,
+    #[serde(default, skip_serializing_if = "String::is_empty")]
+    desc: String,
+    runes: Vec<String>,
+    rationale: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    strict: Option<VariantDef>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    readable: Option<VariantDef>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    alternatives: Vec<AlternativeDef>,
+}
+
+/// A mode-specific replacement for a token's default mapping.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct VariantDef {
+    runes: Vec<String>,
+    rationale: String,
+}
+
+/// Another defensible rendering of a token, offered by `--alternatives`
+/// but never chosen by default.
+#[derive(Debug, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+struct AlternativeDef {
+    runes: Vec<String>,
+    rationale: String,
+    /// In (0, 1]; how likely a reader is to prefer it.
+    score: Option<f64>,
+}
+
+/* ===========================================================================
+ * Resolved table
+ * ===========================================================================
+ */
+
+/// One IPA token the tokenizer recognises, with its rune mapping.
+#[derive(Debug, Clone)]
+pub struct TokenRule {
+    pub ipa: String,
+    /// Phonetic description for the tokenizer trace; may be empty.
+    pub desc: String,
+    pub mapping: Mapping,
+    /// Replacements used under `--mode strict` / `--mode readable`.
+    pub strict: Option<Mapping>,
+    pub readable: Option<Mapping>,
+    /// Further renderings the table declares, in file order.
+    pub alternatives: Vec<Alternative>,
+}
+
+impl TokenRule {
+    /// The mapping this token uses in `mode`.
+    pub fn mapping_for(&self, mode: Mode) -> &Mapping {
+        let variant = match mode {
+            Mode::Pragmatic => None,
+            Mode::Strict => self.strict.as_ref(),
+            Mode::Readable => self.readable.as_ref(),
+        };
+        variant.unwrap_or(&self.mapping)
+    }
+
+    /// Every mapping of this token, best first for `mode`: the mode's own
+    /// mapping (score 1), the other modes' mappings, then the declared
+    /// alternatives. A rune sequence is only listed once.
+    pub fn ranked(&self, mode: Mode) -> Vec<Alternative> {
+        let own = Alternative {
+            mapping: self.mapping_for(mode).clone(),
+            score: 1.0,
+        };
+        let variants = [Some(&self.mapping), self.strict.as_ref(), self.readable.as_ref()]
+            .into_iter()
+            .flatten()
+            .map(|m| Alternative {
+                mapping: m.clone(),
+                score: VARIANT_SCORE,
+            });
+
+        let mut ranked = vec![own];
+        for alternative in variants.chain(self.alternatives.iter().cloned()) {
+            if !ranked.iter().any(|a| a.mapping.runes == alternative.mapping.runes) {
+                ranked.push(alternative);
+            }
+        }
+        // Stable sort keeps the mode's own mapping first among equals.
+        ranked.sort_by(|a, b| b.score.total_cmp(&a.score));
+        ranked
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct MappingTable {
+    pub name: String,
+    pub description: String,
+    /// The block the table's runes are in; decides the output layout.
+    pub script: Script,
+    /// Every rune the table defines, by key.
+    pub runes: BTreeMap<String, RuneChar>,
+    /// Longest `ipa` first, so the first prefix match is the greedy one.
+    rules: Vec<TokenRule>,
+    /// Position in `rules` of each token, for [`rule`](Self::rule).
+    index: HashMap<String, usize>,
+}
+
+#[derive(Debug)]
+pub enum TableError {
+    Io { origin: String, source: std::io::Error },
+    Parse { origin: String, message: String },
+    Invalid { origin: String, message: String },
+}
+
+impl fmt::Display for TableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TableError::Io { origin, source } => write!(f, "{origin}: {source}"),
+            TableError::Parse { origin, message } => write!(f, "{origin}: {message}"),
+            TableError::Invalid { origin, message } => write!(f, "{origin}: {message}"),
+        }
+    }
+}
+
+impl std::error::Error for TableError {}
+
+impl MappingTable {
+    /// Names accepted by [`MappingTable::builtin`], default first.
+    pub fn alphabets() -> impl Iterator<Item = &'static str> {
+        BUILTIN.iter().map(|(name, _)| *name)
+    }
+
+    /// A compiled-in table by alphabet name.
+    pub fn builtin(alphabet: &str) -> Option<Self> {
+        let (name, text) = BUILTIN.iter().find(|(name, _)| *name == alphabet)?;
+        let table = Self::from_toml(text, &format!("<built-in {name}>"))
+            .expect("built-in mapping table is valid");
+        Some(table)
+    }
+
+    /// Load a table from disk; `.json` files are JSON, anything else TOML.
+    pub fn load(path: &Path) -> Result<Self, TableError> {
+        let origin = path.display().to_string();
+        let text = fs::read_to_string(path).map_err(|source| TableError::Io {
+            origin: origin.clone(),
+            source,
+        })?;
+
+        let is_json = path
+            .extension()
+            .is_some_and(|e| e.eq_ignore_ascii_case("json"));
+        if is_json {
+            Self::from_json(&text, &origin)
+        } else {
+            Self::from_toml(&text, &origin)
+        }
+    }
+
+    pub fn from_toml(text: &str, origin: &str) -> Result<Self, TableError> {
+        let file: TableFile = toml::from_str(text).map_err(|e| TableError::Parse {
+            origin: origin.to_string(),
+            message: e.to_string(),
+        })?;
+        Self::resolve(file, origin)
+    }
+
+    pub fn from_json(text: &str, origin: &str) -> Result<Self, TableError> {
+        let file: TableFile = serde_json::from_str(text).map_err(|e| TableError::Parse {
+            origin: origin.to_string(),
+            message: e.to_string(),
+        })?;
+        Self::resolve(file, origin)
+    }
+
+    /// The table's rules, longest token first; among tokens of the same
+    /// length, in file order.
+    pub fn rules(&self) -> &[TokenRule] {
+        &self.rules
+    }
+
+    /// The rule for an exact token, if the table has one.
+    pub fn rule(&self, token: &str) -> Option<&TokenRule> {
+        self.index.get(token).map(|&i| &self.rules[i])
+    }
+
+    /// Add `rule`, or replace the rule for the same token in place.
+    pub fn set_rule(&mut self, rule: TokenRule) {
+        if let Some(&i) = self.index.get(&rule.ipa) {
+            self.rules[i] = rule;
+            return;
+        }
+        // After every token as long or longer, as a stable sort puts it.
+        let at = self.rules.partition_point(|r| r.ipa.len() >= rule.ipa.len());
+        for i in self.index.values_mut().filter(|i| **i >= at) {
+            *i += 1;
+        }
+        self.index.insert(rule.ipa.clone(), at);
+        self.rules.insert(at, rule);
+    }
+
+    /// Mapping for an exact token in `mode`, if the table has one.
+    pub fn lookup(&self, token: &str, mode: Mode) -> Option<&Mapping> {
+        self.rule(token).map(|r| r.mapping_for(mode))
+    }
+
+    /// The table as a TOML file [`MappingTable::from_toml`] reads back,
+    /// tokens longest first.
+    pub fn to_toml(&self) -> String {
+        let key = |rune: &RuneChar| {
+            self.runes
+                .iter()
+                .find(|(_, r)| r.ch == rune.ch)
+                .map(|(key, _)| key.clone())
+                .expect("mapped rune is defined")
+        };
+        let keys = |m: &Mapping| m.runes.iter().map(key).collect();
+        let variant = |m: &Mapping| VariantDef {
+            runes: keys(m),
+            rationale: m.rationale.clone(),
+        };
+
+        let file = TableFile {
+            name: self.name.clone(),
+            description: self.description.clone(),
+            script: self.script,
+            runes: self
+                .runes
+                .iter()
+                .map(|(key, r)| {
+                    let def = RuneDef {
+                        glyph: r.ch.to_string(),
+                        codepoint: Some(format!("U+{:04X}", r.codepoint)),
+                        name: r.name.clone(),
+                        tradition: r.tradition,
+                    };
+                    (key.clone(), def)
+                })
+                .collect(),
+            tokens: self
+                .rules
+                .iter()
+                .map(|rule| TokenDef {
+                    ipa: rule.ipa.clone(),
+                    desc: rule.desc.clone(),
+                    runes: keys(&rule.mapping),
+                    rationale: rule.mapping.rationale.clone(),
+                    strict: rule.strict.as_ref().map(variant),
+                    readable: rule.readable.as_ref().map(variant),
+                    alternatives: rule
+                        .alternatives
+                        .iter()
+                        .map(|a| AlternativeDef {
+                            runes: keys(&a.mapping),
+                            rationale: a.mapping.rationale.clone(),
+                            score: Some(a.score),
+                        })
+                        .collect(),
+                })
+                .collect(),
+        };
+        toml::to_string(&file).expect("table serializes")
+    }
+
+    fn resolve(file: TableFile, origin: &str) -> Result<Self, TableError> {
+        let invalid = |message: String| TableError::Invalid {
+            origin: origin.to_string(),
+            message,
+        };
+
+        let mut runes: BTreeMap<String, RuneChar> = BTreeMap::new();
+        for (key, def) in &file.runes {
+            let rune = resolve_rune(def, file.script).map_err(|e| invalid(format!("rune {key}: {e}")))?;
+            runes.insert(key.clone(), rune);
+        }
+
+        let mut seen = HashSet::new();
+        let mut rules = Vec::with_capacity(file.tokens.len());
+        for mut token in file.tokens {
+            // Input is matched in NFD; "ç" in a table must meet "c" + U+0327.
+            token.ipa = token.ipa.nfd().collect();
+            if token.ipa.is_empty() {
+                return Err(invalid("token with empty ipa".to_string()));
+            }
+            if !seen.insert(token.ipa.clone()) {
+                return Err(invalid(format!("token {:?} defined twice", token.ipa)));
+            }
+
+            let resolve = |keys: Vec<String>, rationale: String| {
+                let mut mapped = Vec::with_capacity(keys.len());
+                for key in &keys {
+                    let rune = runes.get(key.as_str()).ok_or_else(|| {
+                        invalid(format!("token {:?} uses undefined rune {key}", token.ipa))
+                    })?;
+                    mapped.push(rune.clone());
+                }
+                Ok(Mapping {
+                    runes: mapped,
+                    rationale,
+                })
+            };
+
+            let mapping = resolve(token.runes, token.rationale)?;
+            let strict = token
+                .strict
+                .map(|v| resolve(v.runes, v.rationale))
+                .transpose()?;
+            let readable = token
+                .readable
+                .map(|v| resolve(v.runes, v.rationale))
+                .transpose()?;
+            let mut alternatives = Vec::with_capacity(token.alternatives.len());
+            for alt in token.alternatives {
+                let score = alt.score.unwrap_or(ALTERNATIVE_SCORE);
+                if !(score > 0.0 && score <= 1.0) {
+                    return Err(invalid(format!(
+                        "token {:?} has an alternative scored {score}, not in (0, 1]",
+                        token.ipa
+                    )));
+                }
+                alternatives.push(Alternative {
+                    mapping: resolve(alt.runes, alt.rationale)?,
+                    score,
+                });
+            }
+
+            rules.push(TokenRule {
+                ipa: token.ipa,
+                desc: token.desc,
+                mapping,
+                strict,
+                readable,
+                alternatives,
+            });
+        }
+
+        // Stable sort keeps file order among tokens of equal length.
+        rules.sort_by_key(|r| std::cmp::Reverse(r.ipa.len()));
+
+        Ok(Self {
+            name: file.name,
+            description: file.description,
+            script: file.script,
+            runes,
+            index: index(&rules),
+            rules,
+        })
+    }
+}
+
+fn index(rules: &[TokenRule]) -> HashMap<String, usize> {
+    rules
+        .iter()
+        .enumerate()
+        .map(|(i, r)| (r.ipa.clone(), i))
+        .collect()
+}
+
+fn resolve_rune(def: &RuneDef, script: Script) -> Result<RuneChar, String> {
+    let mut chars = def.glyph.chars();
+    let (Some(ch), None) = (chars.next(), chars.next()) else {
+        return Err(format!("glyph {:?} is not a single character", def.glyph));
+    };
+
+    let codepoint = ch as u32;
+    if !script.block().contains(&codepoint) {
+        return Err(format!(
+            "glyph {:?} (U+{:04X}) is not in the {} block",
+            def.glyph,
+            codepoint,
+            script.as_str()
+        ));
+    }
+
+    if let Some(declared) = &def.codepoint {
+        let hex = declared
+            .strip_prefix("U+")
+            .or_else(|| declared.strip_prefix("u+"))
+            .unwrap_or(declared);
+        let declared_cp = u32::from_str_radix(hex, 16)
+            .map_err(|_| format!("codepoint {declared:?} is not U+XXXX"))?;
+        if declared_cp != codepoint {
+            return Err(format!(
+                "codepoint {declared} does not match glyph {:?} (U+{:04X})",
+                def.glyph, codepoint
+            ));
+        }
+    }
+
+    Ok(RuneChar {
+        ch,
+        codepoint,
+        name: def.name.clone(),
+        tradition: def.tradition,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// A two-rune table; `extra` is appended after the `[[tokens]]` for
+    /// /f/, and `runes` replaces the rune definitions.
+    fn table(runes: &str, extra: &str) -> Result<MappingTable, TableError> {
+        let text = format!(
+            r#"
+name = "test"
+{runes}
+
+[[tokens]]
+ipa = "f"
+runes = ["FEOH"]
+rationale = "/f/ -> feoh"
+{extra}
+"#
+        );
+        MappingTable::from_toml(&text, "<test>")
+    }
+
+    const RUNES: &str = r#"
+[runes]
+FEOH = { glyph = "ᚠ", codepoint = "U+16A0", name = "feoh", tradition = "futhorc-core" }
+UR   = { glyph = "ᚢ", name = "ur", tradition = "approximation" }
+"#;
+
+    fn error(result: Result<MappingTable, TableError>) -> String {
+        result.expect_err("the table is rejected").to_string()
+    }
+
+    fn runes(mapping: &Mapping) -> String {
+        mapping.runes.iter().map(|r| r.ch).collect()
+    }
+
+    #[test]
+    fn runes_outside_the_block_are_rejected() {
+        let latin = r#"
+[runes]
+FEOH = { glyph = "F", name = "feoh", tradition = "futhorc-core" }
+"#;
+        assert_eq!(
+            error(table(latin, "")),
+            r#"<test>: rune FEOH: glyph "F" (U+0046) is not in the Runic block"#
+        );
+
+        let ogham = r#"
+[runes]
+FEOH = { glyph = "ᚃ", name = "fearn", tradition = "ogham" }
+"#;
+        assert_eq!(
+            error(table(ogham, "")),
+            r#"<test>: rune FEOH: glyph "ᚃ" (U+1683) is not in the Runic block"#
+        );
+
+        let unassigned = r#"
+[runes]
+FEOH = { glyph = "᛹", name = "feoh", tradition = "futhorc-core" }
+"#;
+        assert_eq!(
+            error(table(unassigned, "")),
+            r#"<test>: rune FEOH: glyph "\u{16f9}" (U+16F9) is not in the Runic block"#
+        );
+    }
+
+    #[test]
+    fn glyph_and_codepoint_must_agree() {
+        let two = r#"
+[runes]
+FEOH = { glyph = "ᚠᚢ", name = "feoh", tradition = "futhorc-core" }
+"#;
+        assert_eq!(
+            error(table(two, "")),
+            r#"<test>: rune FEOH: glyph "ᚠᚢ" is not a single character"#
+        );
+
+        let wrong = r#"
+[runes]
+FEOH = { glyph = "ᚠ", codepoint = "U+16A2", name = "feoh", tradition = "futhorc-core" }
+"#;
+        assert_eq!(
+            error(table(wrong, "")),
+            r#"<test>: rune FEOH: codepoint U+16A2 does not match glyph "ᚠ" (U+16A0)"#
+        );
+
+        let malformed = r#"
+[runes]
+FEOH = { glyph = "ᚠ", codepoint = "16A0h", name = "feoh", tradition = "futhorc-core" }
+"#;
+        assert_eq!(
+            error(table(malformed, "")),
+            r#"<test>: rune FEOH: codepoint "16A0h" is not U+XXXX"#
+        );
+    }
+
+    #[test]
+    fn undefined_rune_keys_are_rejected() {
+        let token = r#"
+[[tokens]]
+ipa = "v"
+runes = ["FEOH", "WYNN"]
+rationale = "/v/ -> feoh"
+"#;
+        assert_eq!(
+            error(table(RUNES, token)),
+            r#"<test>: token "v" uses undefined rune WYNN"#
+        );
+
+        // Variants and alternatives are checked the same way.
+        let variant = r#"
+[[tokens]]
+ipa = "v"
+runes = ["FEOH"]
+rationale = "/v/ -> feoh"
+strict = { runes = ["WYNN"], rationale = "no" }
+"#;
+        assert_eq!(
+            error(table(RUNES, variant)),
+            r#"<test>: token "v" uses undefined rune WYNN"#
+        );
+    }
+
+    #[test]
+    fn duplicate_tokens_are_rejected() {
+        let again = r#"
+[[tokens]]
+ipa = "f"
+runes = ["UR"]
+rationale = "again"
+"#;
+        assert_eq!(
+            error(table(RUNES, again)),
+            r#"<test>: token "f" defined twice"#
+        );
+
+        // Tokens are compared in NFD, so a precomposed ç repeats c + U+0327.
+        let cedilla = r#"
+[[tokens]]
+ipa = "ç"
+runes = ["FEOH"]
+rationale = "precomposed"
+
+[[tokens]]
+ipa = "ç"
+runes = ["FEOH"]
+rationale = "decomposed"
+"#;
+        assert_eq!(
+            error(table(RUNES, cedilla)),
+            r#"<test>: token "c\u{327}" defined twice"#
+        );
+    }
+
+    #[test]
+    fn variants_replace_the_mapping_in_their_mode() {
+        let token = r#"
+[[tokens]]
+ipa = "u"
+runes = ["UR"]
+rationale = "/u/ -> ur"
+strict = { runes = ["FEOH", "UR"], rationale = "strict" }
+readable = { runes = [], rationale = "readable" }
+"#;
+        let t = table(RUNES, token).unwrap();
+        let u = t.rule("u").unwrap();
+        assert_eq!(runes(u.mapping_for(Mode::Pragmatic)), "ᚢ");
+        assert_eq!(runes(u.mapping_for(Mode::Strict)), "ᚠᚢ");
+        assert_eq!(u.mapping_for(Mode::Strict).rationale, "strict");
+        assert_eq!(runes(u.mapping_for(Mode::Readable)), "");
+        assert_eq!(t.lookup("u", Mode::Readable).unwrap().rationale, "readable");
+
+        // Without variants every mode uses the token's own mapping.
+        for mode in [Mode::Pragmatic, Mode::Strict, Mode::Readable] {
+            assert_eq!(runes(t.lookup("f", mode).unwrap()), "ᚠ");
+        }
+        assert!(t.lookup("x", Mode::Pragmatic).is_none());
+    }
+
+    #[test]
+    fn set_rule_keeps_the_longest_token_first() {
+        let mut t = MappingTable::builtin("futhorc").unwrap();
+        let mut ks = t.rule("k").unwrap().clone();
+        ks.ipa = "ks".to_string();
+        t.set_rule(ks);
+        let mut k = t.rule("k").unwrap().clone();
+        k.mapping.rationale = "replaced".to_string();
+        t.set_rule(k);
+
+        let lengths: Vec<usize> = t.rules().iter().map(|r| r.ipa.len()).collect();
+        assert!(lengths.windows(2).all(|w| w[0] >= w[1]), "{lengths:?}");
+        assert_eq!(t.rules().iter().filter(|r| r.ipa == "k").count(), 1);
+        for (i, rule) in t.rules().iter().enumerate() {
+            assert!(std::ptr::eq(t.rule(&rule.ipa).unwrap(), &t.rules()[i]));
+        }
+        assert_eq!(t.rule("ks").unwrap().ipa, "ks");
+        assert_eq!(t.rule("k").unwrap().mapping.rationale, "replaced");
+    }
+
+    fn assert_same_rules(a: &MappingTable, b: &MappingTable) {
+        assert_eq!(a.name, b.name);
+        assert_eq!(a.description, b.description);
+        assert_eq!(a.script, b.script);
+        assert_eq!(a.runes, b.runes);
+        assert_eq!(a.rules().len(), b.rules().len(), "{}", a.name);
+        for (x, y) in a.rules().iter().zip(b.rules()) {
+            assert_eq!(x.ipa, y.ipa, "{}", a.name);
+            assert_eq!(x.desc, y.desc, "{}: {}", a.name, x.ipa);
+            assert_eq!(x.mapping, y.mapping, "{}: {}", a.name, x.ipa);
+            assert_eq!(x.strict, y.strict, "{}: {}", a.name, x.ipa);
+            assert_eq!(x.readable, y.readable, "{}: {}", a.name, x.ipa);
+            assert_eq!(x.alternatives, y.alternatives, "{}: {}", a.name, x.ipa);
+        }
+    }
+
+    #[test]
+    fn to_toml_reads_back_as_the_same_table() {
+        for alphabet in MappingTable::alphabets() {
+            let table = MappingTable::builtin(alphabet).unwrap();
+            let again = MappingTable::from_toml(&table.to_toml(), "<again>").unwrap();
+            assert_same_rules(&table, &again);
+        }
+    }
+
+    #[test]
+    fn json_tables_read_like_toml() {
+        let json = r#"{
+            "name": "test",
+            "runes": {
+                "FEOH": { "glyph": "ᚠ", "codepoint": "U+16A0", "name": "feoh", "tradition": "futhorc-core" },
+                "UR": { "glyph": "ᚢ", "name": "ur", "tradition": "approximation" }
+            },
+            "tokens": [
+                { "ipa": "f", "runes": ["FEOH"], "rationale": "/f/ -> feoh" },
+                {
+                    "ipa": "u", "runes": ["UR"], "rationale": "/u/ -> ur",
+                    "alternatives": [{ "runes": ["FEOH"], "rationale": "alt", "score": 0.3 }]
+                }
+            ]
+        }"#;
+        let from_json = MappingTable::from_json(json, "<json>").unwrap();
+        let toml = r#"
+[[tokens]]
+ipa = "u"
+runes = ["UR"]
+rationale = "/u/ -> ur"
+alternatives = [{ runes = ["FEOH"], rationale = "alt", score = 0.3 }]
+"#;
+        assert_same_rules(&from_json, &table(RUNES, toml).unwrap());
+
+        let again = MappingTable::from_toml(&from_json.to_toml(), "<again>").unwrap();
+        assert_same_rules(&from_json, &again);
+
+        assert!(
+            error(MappingTable::from_json("{", "<json>")).starts_with("<json>: "),
+            "parse errors name the origin"
+        );
+    }
+}
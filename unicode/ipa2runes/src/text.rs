@@ -0,0 +1,679 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! English text → IPA front end for `--text`.
+//!
+//! Each word is looked up in the bundled CMU Pronouncing Dictionary
+//! (`dict/cmudict.dict`, ARPAbet) and converted to IPA. Words the
+//! dictionary does not know fall back to a small set of letter-to-sound
+//! rules, which are deliberately simple: they get regular spellings right
+//! and irregular ones wrong, and the trace says which path each word took.
+//! Numbers are read as their English words ("66" is "sixty six").
+//!
+//! The IPA is broad General American, written with the symbols the mapping
+//! tables know: `r` for /ɹ/, `g` for /ɡ/, no stress marks.
+
+use std::collections::HashMap;
+use std::sync::OnceLock;
+
+use serde::Serialize;
+
+/// The bundled dictionary; see `dict/LICENSE-CMUDICT`.
+const BUILTIN_DICT: &str = include_str!("../dict/cmudict.dict");
+
+/// Where a word's IPA came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Dictionary,
+    Rules,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct WordIpa {
+    pub word: String,
+    pub ipa: String,
+    pub source: Source,
+}
+
+/// word (lowercase) → ARPAbet phones, e.g. "night" → "N AY1 T".
+pub struct Dictionary {
+    entries: HashMap<String, String>,
+}
+
+impl Dictionary {
+    pub fn builtin() -> Self {
+        Self::parse(BUILTIN_DICT)
+    }
+
+    /// The built-in dictionary, parsed once on first use.
+    pub fn shared() -> &'static Self {
+        static SHARED: OnceLock<Dictionary> = OnceLock::new();
+        SHARED.get_or_init(Self::builtin)
+    }
+
+    /// Parse CMUdict text. Accepts both the classic upper-case `.txt`
+    /// layout (two spaces after the word) and the lower-case `.dict` one;
+    /// `;;;` lines are comments, and only the first pronunciation of a
+    /// word is kept.
+    pub fn parse(text: &str) -> Self {
+        let mut entries = HashMap::new();
+        for line in text.lines() {
+            if line.starts_with(";;;") {
+                continue;
+            }
+            let line = line.split('#').next().unwrap_or_default();
+            let Some((word, phones)) = line.split_once(' ') else {
+                continue;
+            };
+
+            // "word(2)" is an alternate pronunciation.
+            if word.ends_with(')') {
+                continue;
+            }
+
+            entries
+                .entry(word.to_lowercase())
+                .or_insert_with(|| phones.trim().to_string());
+        }
+        Self { entries }
+    }
+
+    pub fn lookup(&self, word: &str) -> Option<String> {
+        let phones = self.entries.get(&word.to_lowercase())?;
+        arpabet_to_ipa(phones)
+    }
+}
+
+/// Convert ARPAbet phones ("N AY1 T") to IPA ("naɪt").
+/// Returns `None` on a phone outside the CMUdict inventory.
+pub fn arpabet_to_ipa(phones: &str) -> Option<String> {
+    let mut out = String::new();
+    for phone in phones.split_whitespace() {
+        let (base, stress) = match phone.strip_suffix(['0', '1', '2']) {
+            Some(base) => (base, phone.as_bytes()[phone.len() - 1]),
+            None => (phone, b'1'),
+        };
+        let unstressed = stress == b'0';
+
+        let ipa = match base {
+            // Vowels
+            "AA" => "ɑ",
+            "AE" => "æ",
+            "AH" if unstressed => "ə",
+            "AH" => "ʌ",
+            "AO" => "ɔ",
+            "AW" => "aʊ",
+            "AY" => "aɪ",
+            "EH" => "ɛ",
+            "ER" if unstressed => "ər",
+            "ER" => "ɜr",
+            "EY" => "eɪ",
+            "IH" => "ɪ",
+            "IY" => "i",
+            "OW" => "oʊ",
+            "OY" => "ɔɪ",
+            "UH" => "ʊ",
+            "UW" => "u",
+
+            // Consonants
+            "B" => "b",
+            "CH" => "tʃ",
+            "D" => "d",
+            "DH" => "ð",
+            "F" => "f",
+            "G" => "g",
+            "HH" => "h",
+            "JH" => "dʒ",
+            "K" => "k",
+            "L" => "l",
+            "M" => "m",
+            "N" => "n",
+            "NG" => "ŋ",
+            "P" => "p",
+            "R" => "r",
+            "S" => "s",
+            "SH" => "ʃ",
+            "T" => "t",
+            "TH" => "θ",
+            "V" => "v",
+            "W" => "w",
+            "Y" => "j",
+            "Z" => "z",
+            "ZH" => "ʒ",
+            _ => return None,
+        };
+        out.push_str(ipa);
+    }
+    Some(out)
+}
+
+/* ===========================================================================
+ * Letter-to-sound fallback
+ * ===========================================================================
+ */
+
+fn is_vowel(c: char) -> bool {
+    matches!(c, 'a' | 'e' | 'i' | 'o' | 'u' | 'y')
+}
+
+fn is_front_vowel(c: Option<char>) -> bool {
+    matches!(c, Some('e' | 'i' | 'y'))
+}
+
+/// Rough English letter-to-sound rules for words not in the dictionary.
+///
+/// Multi-letter spellings are matched first (`tch`, `igh`, `sh`, `ee`, ...),
+/// then single letters: `c`/`g` soften before e/i/y, a vowel followed by a
+/// single consonant and a final silent `e` is long ("made", "rune"), other
+/// vowels are short, and doubled consonants sound once.
+pub fn letter_to_sound(word: &str) -> String {
+    let w: Vec<char> = word
+        .to_lowercase()
+        .chars()
+        .filter(|c| c.is_ascii_alphabetic())
+        .collect();
+    let n = w.len();
+
+    // Final "e" after a consonant is silent in words longer than "be".
+    let silent_e = n > 2 && w[n - 1] == 'e' && !is_vowel(w[n - 2]);
+    let end = if silent_e { n - 1 } else { n };
+
+    // "made", "rune": vowel + one consonant + silent e.
+    let long_vowel_at =
+        (silent_e && n >= 3 && is_vowel(w[n - 3]) && !(n >= 4 && is_vowel(w[n - 4])))
+            .then(|| n - 3);
+
+    const DIGRAPHS: &[(&str, &str)] = &[
+        ("tion", "ʃən"),
+        ("sion", "ʒən"),
+        ("tch", "tʃ"),
+        ("igh", "aɪ"),
+        ("dge", "dʒ"),
+        ("sh", "ʃ"),
+        ("ch", "tʃ"),
+        ("th", "θ"),
+        ("ph", "f"),
+        ("wh", "w"),
+        ("ck", "k"),
+        ("ng", "ŋ"),
+        ("qu", "kw"),
+        ("gh", ""),
+        ("ee", "i"),
+        ("ea", "i"),
+        ("ie", "i"),
+        ("oo", "u"),
+        ("ou", "aʊ"),
+        ("ow", "oʊ"),
+        ("oa", "oʊ"),
+        ("oi", "ɔɪ"),
+        ("oy", "ɔɪ"),
+        ("ai", "eɪ"),
+        ("ay", "eɪ"),
+        ("au", "ɔ"),
+        ("aw", "ɔ"),
+        ("ew", "u"),
+        ("er", "ər"),
+        ("ar", "ɑr"),
+        ("or", "ɔr"),
+    ];
+
+    let mut out = String::new();
+    let mut i = 0;
+    'outer: while i < end {
+        // Silent initial k/w before n/r: "knight", "write"; initial
+        // "gh" is a plain g: "ghost".
+        if i == 0 && n > 1 {
+            match (w[0], w[1]) {
+                ('k', 'n') | ('w', 'r') => {
+                    i += 1;
+                    continue;
+                }
+                ('g', 'h') => {
+                    out.push('g');
+                    i += 2;
+                    continue;
+                }
+                _ => {}
+            }
+        }
+
+        for (spelling, ipa) in DIGRAPHS {
+            let len = spelling.chars().count();
+            if i + len <= end && w[i..i + len].iter().copied().eq(spelling.chars()) {
+                out.push_str(ipa);
+                i += len;
+                continue 'outer;
+            }
+        }
+
+        let c = w[i];
+        let next = w.get(i + 1).copied();
+
+        // Doubled consonants sound once: "letter", "happy".
+        if !is_vowel(c) && next == Some(c) {
+            i += 1;
+            continue;
+        }
+
+        let ipa = match c {
+            'a' if long_vowel_at == Some(i) => "eɪ",
+            'e' if long_vowel_at == Some(i) => "i",
+            'i' if long_vowel_at == Some(i) => "aɪ",
+            'o' if long_vowel_at == Some(i) => "oʊ",
+            'u' if long_vowel_at == Some(i) => "u",
+            'a' => "æ",
+            'e' => "ɛ",
+            'i' => "ɪ",
+            'o' => "ɑ",
+            'u' => "ʌ",
+            'y' if i == 0 => "j",
+            'y' if i + 1 == end => "i",
+            'y' => "ɪ",
+            'c' if is_front_vowel(next) => "s",
+            'c' => "k",
+            'g' if is_front_vowel(next) => "dʒ",
+            'g' => "g",
+            'j' => "dʒ",
+            'x' if i == 0 => "z",
+            'x' => "ks",
+            'q' => "k",
+            'b' => "b",
+            'd' => "d",
+            'f' => "f",
+            'h' => "h",
+            'k' => "k",
+            'l' => "l",
+            'm' => "m",
+            'n' => "n",
+            'p' => "p",
+            'r' => "r",
+            's' => "s",
+            't' => "t",
+            'v' => "v",
+            'w' => "w",
+            'z' => "z",
+            _ => "",
+        };
+        out.push_str(ipa);
+        i += 1;
+    }
+
+    out
+}
+
+/* ===========================================================================
+ * Text
+ * ===========================================================================
+ */
+
+/// Transcribe each word of `text` (letters and inner apostrophes),
+/// dictionary first, and each number (digits, with optional `,` thousands
+/// groups and a `.` decimal part) as its English words. Returns the IPA
+/// with everything between words (spaces, punctuation) carried over, and
+/// the per-word trace.
+///
+/// Words are always kept apart in the IPA: where only punctuation stands
+/// between two ("hello,world", "well-known", "3pm"), a space follows it,
+/// since normalization drops punctuation inside a word.
+pub fn text_to_ipa(text: &str, dict: &Dictionary) -> (String, Vec<WordIpa>) {
+    let mut ipa = String::new();
+    let mut words = Vec::new();
+
+    // Whether the last word has no whitespace after it yet; the next
+    // word's IPA then starts with a space.
+    let mut joined = false;
+
+    let mut rest = text;
+    while !rest.is_empty() {
+        let number_len = number_len(rest);
+        if number_len > 0 {
+            let (number, tail) = rest.split_at(number_len);
+            rest = tail;
+            let spelled: Vec<WordIpa> = spell_number(number)
+                .into_iter()
+                .map(|word| transcribe(word, dict))
+                .collect();
+            let entry = WordIpa {
+                word: number.to_string(),
+                ipa: spelled
+                    .iter()
+                    .map(|w| w.ipa.as_str())
+                    .collect::<Vec<_>>()
+                    .join(" "),
+                source: if spelled.iter().all(|w| w.source == Source::Dictionary) {
+                    Source::Dictionary
+                } else {
+                    Source::Rules
+                },
+            };
+            if joined {
+                ipa.push(' ');
+            }
+            ipa.push_str(&entry.ipa);
+            words.push(entry);
+            joined = true;
+            continue;
+        }
+
+        let word_len = rest
+            .find(|c: char| !(c.is_alphabetic() || c == '\''))
+            .unwrap_or(rest.len());
+        let (run, tail) = rest.split_at(word_len);
+        rest = tail;
+
+        // Quotes around a word are punctuation, not part of it.
+        let word = run.trim_matches('\'');
+        if word.is_empty() {
+            let gap_len = rest
+                .find(|c: char| c.is_alphabetic() || c.is_ascii_digit())
+                .unwrap_or(rest.len());
+            let (gap, tail) = rest.split_at(gap_len);
+            ipa.push_str(gap);
+            joined &= !gap.contains(char::is_whitespace);
+            rest = tail;
+            continue;
+        }
+
+        let entry = transcribe(word, dict);
+        let lead = run.len() - run.trim_start_matches('\'').len();
+        if joined {
+            ipa.push(' ');
+        }
+        ipa.push_str(&run[..lead]);
+        ipa.push_str(&entry.ipa);
+        ipa.push_str(&run[lead + word.len()..]);
+        words.push(entry);
+        joined = true;
+    }
+
+    (ipa, words)
+}
+
+/// One word's IPA, from the dictionary or else the letter-to-sound rules.
+fn transcribe(word: &str, dict: &Dictionary) -> WordIpa {
+    match dict.lookup(word) {
+        Some(ipa) => WordIpa {
+            word: word.to_string(),
+            ipa,
+            source: Source::Dictionary,
+        },
+        None => WordIpa {
+            word: word.to_string(),
+            ipa: letter_to_sound(word),
+            source: Source::Rules,
+        },
+    }
+}
+
+/* ===========================================================================
+ * Numbers
+ * ===========================================================================
+ */
+
+/// Length of the number at the start of `text`: ASCII digits, then any
+/// `,ddd` thousands groups, then an optional `.` and decimal digits.
+/// 0 if `text` does not start with a digit.
+fn number_len(text: &str) -> usize {
+    let b = text.as_bytes();
+    let digits_from = |i: usize| b[i..].iter().take_while(|c| c.is_ascii_digit()).count();
+
+    let mut len = digits_from(0);
+    if len == 0 {
+        return 0;
+    }
+    while b.get(len) == Some(&b',') && digits_from(len + 1) == 3 {
+        len += 4;
+    }
+    if b.get(len) == Some(&b'.') {
+        let decimals = digits_from(len + 1);
+        if decimals > 0 {
+            len += 1 + decimals;
+        }
+    }
+    len
+}
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+const SCALES: [(u64, &str); 4] = [
+    (1_000_000_000_000, "trillion"),
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+/// The English words for a number [`number_len`] matched: "1,024" is
+/// "one thousand twenty four", "3.14" is "three point one four". Integers
+/// with a leading zero ("007") or past the trillions are read digit by
+/// digit, as codes and long numbers are.
+fn spell_number(number: &str) -> Vec<&'static str> {
+    let digit = |c: char| ONES[c.to_digit(10).unwrap_or_default() as usize];
+    let (integer, decimals) = match number.split_once('.') {
+        Some((integer, decimals)) => (integer, Some(decimals)),
+        None => (number, None),
+    };
+    let integer: String = integer.chars().filter(char::is_ascii_digit).collect();
+
+    let mut words = Vec::new();
+    match integer.parse::<u64>() {
+        Ok(n) if integer.len() <= 15 && !(integer.len() > 1 && integer.starts_with('0')) => {
+            cardinal(n, &mut words)
+        }
+        _ => words.extend(integer.chars().map(digit)),
+    }
+    if let Some(decimals) = decimals {
+        words.push("point");
+        words.extend(decimals.chars().map(digit));
+    }
+    words
+}
+
+fn cardinal(mut n: u64, words: &mut Vec<&'static str>) {
+    if n == 0 {
+        words.push(ONES[0]);
+        return;
+    }
+    for (scale, name) in SCALES {
+        if n >= scale {
+            below_thousand(n / scale, words);
+            words.push(name);
+            n %= scale;
+        }
+    }
+    if n > 0 {
+        below_thousand(n, words);
+    }
+}
+
+fn below_thousand(mut n: u64, words: &mut Vec<&'static str>) {
+    if n >= 100 {
+        words.push(ONES[(n / 100) as usize]);
+        words.push("hundred");
+        n %= 100;
+    }
+    if n >= 20 {
+        words.push(TENS[(n / 10) as usize]);
+        n %= 10;
+        if n > 0 {
+            words.push(ONES[n as usize]);
+        }
+    } else if n > 0 {
+        words.push(ONES[n as usize]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arpabet_phones_become_ipa() {
+        assert_eq!(arpabet_to_ipa("N AY1 T").as_deref(), Some("naɪt"));
+        assert_eq!(arpabet_to_ipa("CH ER1 CH").as_deref(), Some("tʃɜrtʃ"));
+        assert_eq!(arpabet_to_ipa("JH AH1 JH").as_deref(), Some("dʒʌdʒ"));
+        assert_eq!(arpabet_to_ipa("TH IH1 NG K").as_deref(), Some("θɪŋk"));
+        assert_eq!(arpabet_to_ipa("").as_deref(), Some(""));
+    }
+
+    #[test]
+    fn unstressed_ah_and_er_reduce() {
+        // "above": AH0 is schwa, AH1 is the strut vowel.
+        assert_eq!(arpabet_to_ipa("AH0 B AH1 V").as_deref(), Some("əbʌv"));
+        // "murmur": ER1 then ER0.
+        assert_eq!(arpabet_to_ipa("M ER1 M ER0").as_deref(), Some("mɜrmər"));
+        // Secondary stress and unmarked phones count as stressed.
+        assert_eq!(arpabet_to_ipa("AH2 ER2").as_deref(), Some("ʌɜr"));
+        assert_eq!(arpabet_to_ipa("AH").as_deref(), Some("ʌ"));
+    }
+
+    #[test]
+    fn unknown_phones_are_rejected() {
+        assert_eq!(arpabet_to_ipa("N AX0 T"), None);
+        assert_eq!(arpabet_to_ipa("n ay1 t"), None);
+    }
+
+    #[test]
+    fn letter_to_sound_handles_regular_spellings() {
+        for (word, ipa) in [
+            ("made", "meɪd"),
+            ("rune", "run"),
+            ("cat", "kæt"),
+            ("city", "sɪti"),
+            ("gem", "dʒɛm"),
+            ("yes", "jɛs"),
+            ("xenon", "zɛnɑn"),
+            ("box", "bɑks"),
+            ("quick", "kwɪk"),
+            ("nation", "næʃən"),
+            ("church", "tʃʌrtʃ"),
+            ("letter", "lɛtər"),
+            ("RUNE", "run"),
+        ] {
+            assert_eq!(letter_to_sound(word), ipa, "{word}");
+        }
+    }
+
+    #[test]
+    fn letter_to_sound_handles_silent_letters() {
+        assert_eq!(letter_to_sound("knight"), "naɪt");
+        assert_eq!(letter_to_sound("write"), "raɪt");
+        assert_eq!(letter_to_sound("ghost"), "gɑst");
+        // The e of "be" is not silent; "bake" has one long vowel.
+        assert_eq!(letter_to_sound("be"), "bɛ");
+        assert_eq!(letter_to_sound("bake"), "beɪk");
+        assert_eq!(letter_to_sound("don't"), "dɑnt");
+    }
+
+    fn dict() -> Dictionary {
+        Dictionary::parse(
+            ";;; test dictionary\n\
+             hello HH AH0 L OW1\n\
+             world W ER1 L D\n\
+             world(2) W AO1 R L D\n\
+             well W EH1 L # comment\n\
+             known N OW1 N\n",
+        )
+    }
+
+    #[test]
+    fn dictionary_keeps_the_first_pronunciation() {
+        let dict = dict();
+        assert_eq!(dict.lookup("World").as_deref(), Some("wɜrld"));
+        assert_eq!(dict.lookup("well").as_deref(), Some("wɛl"));
+        assert_eq!(dict.lookup("rune"), None);
+    }
+
+    #[test]
+    fn words_stay_apart_across_punctuation() {
+        let dict = dict();
+        assert_eq!(text_to_ipa("hello, world", &dict).0, "həloʊ, wɜrld");
+        assert_eq!(text_to_ipa("hello,world", &dict).0, "həloʊ, wɜrld");
+        assert_eq!(text_to_ipa("well-known", &dict).0, "wɛl- noʊn");
+        assert_eq!(text_to_ipa("\"hello\"world.", &dict).0, "\"həloʊ\" wɜrld.");
+        assert_eq!(
+            crate::normalize_ipa(&text_to_ipa("hello,world", &dict).0),
+            "həloʊ, wɜrld"
+        );
+        assert_eq!(
+            crate::normalize_ipa(&text_to_ipa("well-known", &dict).0),
+            "wɛl noʊn"
+        );
+    }
+
+    #[test]
+    fn words_record_their_source() {
+        let (ipa, words) = text_to_ipa("'hello' rune", &dict());
+        assert_eq!(ipa, "'həloʊ' run");
+        let traced: Vec<_> = words.iter().map(|w| (w.word.as_str(), w.source)).collect();
+        assert_eq!(
+            traced,
+            [("hello", Source::Dictionary), ("rune", Source::Rules)]
+        );
+    }
+
+    #[test]
+    fn numbers_are_spelled_out() {
+        for (number, words) in [
+            ("0", "zero"),
+            ("7", "seven"),
+            ("13", "thirteen"),
+            ("40", "forty"),
+            ("66", "sixty six"),
+            ("100", "one hundred"),
+            ("1,024", "one thousand twenty four"),
+            ("2026", "two thousand twenty six"),
+            ("3000000", "three million"),
+            ("1000000000001", "one trillion one"),
+            ("3.14", "three point one four"),
+            ("007", "zero zero seven"),
+            (
+                "1234567890123456",
+                "one two three four five six seven eight nine zero one two three four five six",
+            ),
+        ] {
+            assert_eq!(number_len(number), number.len(), "{number}");
+            assert_eq!(spell_number(number).join(" "), words, "{number}");
+        }
+    }
+
+    #[test]
+    fn numbers_end_where_their_digits_do() {
+        assert_eq!(number_len("3pm"), 1);
+        assert_eq!(number_len("1, 2"), 1);
+        assert_eq!(number_len("1,2345"), 1);
+        assert_eq!(number_len("3."), 1);
+        assert_eq!(number_len("v2"), 0);
+
+        let (ipa, words) = text_to_ipa("route 66, 3pm", Dictionary::shared());
+        assert_eq!(ipa, "rut sɪksti sɪks, θri piɛm");
+        let traced: Vec<_> = words.iter().map(|w| w.word.as_str()).collect();
+        assert_eq!(traced, ["route", "66", "3", "pm"]);
+        assert!(!ipa.chars().any(|c| c.is_ascii_digit()));
+    }
+}
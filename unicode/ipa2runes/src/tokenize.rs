@@ -0,0 +1,377 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Stage 2: normalized IPA into tokens.
+
+use crate::ipa;
+use crate::table::MappingTable;
+
+/// One token of the normalized input, with its byte span in that string.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Token {
+    /// The input as written, diacritics and tie bars included.
+    pub text: String,
+    pub start: usize,
+    pub end: usize,
+    /// What the token is looked up as: `text` without diacritics or tie
+    /// bars, e.g. "tʃ" for "t͡ʃʰ".
+    pub key: String,
+    /// The symbols a tie bar joined into this token; just `key` otherwise.
+    pub parts: Vec<String>,
+    pub diacritics: Vec<char>,
+    /// Description of the table rule or chart symbol that matched; `None`
+    /// for the single-character fallback.
+    pub desc: Option<String>,
+}
+
+///
+/// Tokenize IPA with greedy longest-match against the table's token list
+/// and the IPA chart. Diacritics after a symbol attach to it, and a tie
+/// bar joins the symbols on either side into one token (t͡s).
+/// Any unknown char becomes its own token so we can explain failures.
+///
+/// The symbols are compiled into a prefix trie once, in [`new`](Self::new),
+/// so matching costs the length of the match rather than the number of
+/// rules; build one tokenizer and reuse it across inputs.
+///
+#[derive(Debug, Clone)]
+pub struct Tokenizer {
+    symbols: Vec<Known>,
+    /// The first `rules` symbols are the table's; the chart's follow.
+    rules: usize,
+    /// The trie; `nodes[0]` is the root.
+    nodes: Vec<Node>,
+}
+
+/// A symbol the trie spells: a table rule or a chart symbol.
+#[derive(Debug, Clone)]
+struct Known {
+    ipa: String,
+    desc: String,
+}
+
+#[derive(Debug, Clone, Default)]
+struct Node {
+    /// Edges out, sorted by char.
+    children: Vec<(char, usize)>,
+    /// The symbol spelled by the path here, if any.
+    symbol: Option<usize>,
+}
+
+impl Tokenizer {
+    pub fn new(table: &MappingTable) -> Self {
+        let rules = table.rules().iter().map(|r| (r.ipa.clone(), r.desc.clone()));
+        let chart = ipa::CHART
+            .iter()
+            .map(|s| (s.ipa.to_string(), format!("{} (IPA chart)", s.name)));
+
+        let mut t = Self {
+            symbols: Vec::new(),
+            rules: 0,
+            nodes: vec![Node::default()],
+        };
+        // Rules go in first: a symbol's index is its rank, and the table
+        // wins a tie, so a table can define any chart symbol itself.
+        for (ipa, desc) in rules {
+            t.insert(ipa, desc);
+        }
+        t.rules = t.symbols.len();
+        for (ipa, desc) in chart {
+            t.insert(ipa, desc);
+        }
+        t
+    }
+
+    fn insert(&mut self, ipa: String, desc: String) {
+        if ipa.is_empty() {
+            return;
+        }
+        let mut node = 0;
+        for c in ipa.chars() {
+            node = match self.child(node, c) {
+                Some(next) => next,
+                None => {
+                    let next = self.nodes.len();
+                    self.nodes.push(Node::default());
+                    let edges = &mut self.nodes[node].children;
+                    let at = edges.partition_point(|&(e, _)| e < c);
+                    edges.insert(at, (c, next));
+                    next
+                }
+            };
+        }
+        if self.nodes[node].symbol.is_none() {
+            self.nodes[node].symbol = Some(self.symbols.len());
+            self.symbols.push(Known { ipa, desc });
+        }
+    }
+
+    fn child(&self, node: usize, c: char) -> Option<usize> {
+        let edges = &self.nodes[node].children;
+        let at = edges.binary_search_by_key(&c, |&(e, _)| e).ok()?;
+        Some(edges[at].1)
+    }
+
+    /// Split normalized IPA (see [`normalize_ipa`](crate::normalize_ipa))
+    /// into tokens. The tokens' texts concatenate back to `ipa`.
+    pub fn tokenize(&self, ipa: &str) -> Vec<Token> {
+        let mut out = Vec::new();
+        let mut i = 0;
+        while i < ipa.len() {
+            let start = i;
+            let mut parts = Vec::new();
+            let mut descs = Vec::new();
+            let mut diacritics = Vec::new();
+
+            loop {
+                let rest = &ipa[i..];
+                if let Some((len, symbol)) = self.match_symbol(rest) {
+                    let known = &self.symbols[symbol];
+                    if !known.desc.is_empty() {
+                        descs.push(known.desc.as_str());
+                    }
+                    parts.push(known.ipa.clone());
+                    i += len;
+                } else {
+                    // fallback: one Unicode scalar
+                    let ch = rest.chars().next().unwrap();
+                    parts.push(ch.to_string());
+                    i += ch.len_utf8();
+                }
+
+                while let Some(c) = ipa[i..].chars().next().filter(|&c| ipa::is_modifier(c)) {
+                    diacritics.push(c);
+                    i += c.len_utf8();
+                }
+
+                match ipa[i..].chars().next() {
+                    Some(c) if ipa::is_tie(c) => {
+                        i += c.len_utf8();
+                        if i < ipa.len() {
+                            continue;
+                        }
+                    }
+                    _ => {}
+                }
+                break;
+            }
+
+            out.push(Token {
+                text: ipa[start..i].to_string(),
+                start,
+                end: i,
+                key: parts.concat(),
+                parts,
+                diacritics,
+                desc: Some(descs.join(" + ")).filter(|d| !d.is_empty()),
+            });
+        }
+
+        out
+    }
+
+    /// The longest table token `rest` starts with, chart symbols aside;
+    /// tie bars are skipped as in [`tokenize`](Self::tokenize).
+    pub fn match_rule(&self, rest: &str) -> Option<&str> {
+        let (_, symbol) = self.longest(rest, self.rules)?;
+        Some(&self.symbols[symbol].ipa)
+    }
+
+    /// Longest table rule or chart symbol at the start of `rest`.
+    fn match_symbol(&self, rest: &str) -> Option<(usize, usize)> {
+        self.longest(rest, self.symbols.len())
+    }
+
+    /// Longest of the first `below` symbols at the start of `rest`, as
+    /// (bytes consumed, symbol index); the lower index wins a tie. Tie
+    /// bars between two letters of a symbol are skipped, as
+    /// [`ipa::match_len`] does, so the walk can branch: into a tie bar the
+    /// symbol spells itself, or past it.
+    fn longest(&self, rest: &str, below: usize) -> Option<(usize, usize)> {
+        let mut best: Option<(usize, usize)> = None;
+        let mut stack = vec![(0, 0)];
+        while let Some((node, at)) = stack.pop() {
+            if let Some(symbol) = self.nodes[node].symbol.filter(|&s| s < below)
+                && best.is_none_or(|(len, s)| at > len || (at == len && symbol < s))
+            {
+                best = Some((at, symbol));
+            }
+            let Some(c) = rest[at..].chars().next() else {
+                continue;
+            };
+            if let Some(next) = self.child(node, c) {
+                stack.push((next, at + c.len_utf8()));
+            }
+            if node != 0 && ipa::is_tie(c) {
+                let skipped = at
+                    + rest[at..]
+                        .chars()
+                        .take_while(|&c| ipa::is_tie(c))
+                        .map(char::len_utf8)
+                        .sum::<usize>();
+                if let Some(c) = rest[skipped..].chars().next()
+                    && let Some(next) = self.child(node, c)
+                {
+                    stack.push((next, skipped + c.len_utf8()));
+                }
+            }
+        }
+        best
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::normalize_ipa;
+    use crate::table::TokenRule;
+    use crate::text::arpabet_to_ipa;
+
+    /// The matcher the trie replaced: every rule and chart symbol tried at
+    /// each position. Kept as the reference the trie must agree with.
+    fn linear_match(table: &MappingTable, rest: &str) -> Option<(usize, String)> {
+        let rules = table
+            .rules()
+            .iter()
+            .filter_map(|r| Some((ipa::match_len(rest, &r.ipa)?, r.ipa.clone())));
+        let chart = ipa::CHART
+            .iter()
+            .filter_map(|s| Some((ipa::match_len(rest, s.ipa)?, s.ipa.to_string())));
+
+        let mut best: Option<(usize, String)> = None;
+        for candidate in rules.chain(chart) {
+            if best.as_ref().is_none_or(|b| candidate.0 > b.0) {
+                best = Some(candidate);
+            }
+        }
+        best
+    }
+
+    /// Every `step`th pronunciation of the bundled dictionary, as
+    /// normalized IPA.
+    fn dictionary_ipa(step: usize) -> Vec<String> {
+        include_str!("../dict/cmudict.dict")
+            .lines()
+            .step_by(step)
+            .filter_map(|line| arpabet_to_ipa(line.split_once(' ')?.1))
+            .map(|ipa| normalize_ipa(&ipa))
+            .collect()
+    }
+
+    /// The futhorc table with `extra` more rules, two chart symbols each.
+    fn big_table(extra: usize) -> MappingTable {
+        let mut table = MappingTable::builtin("futhorc").unwrap();
+        let template = table.rules()[0].clone();
+        let pairs = ipa::CHART
+            .iter()
+            .flat_map(|a| ipa::CHART.iter().map(move |b| format!("{}{}", a.ipa, b.ipa)));
+        for ipa in pairs.take(extra) {
+            table.set_rule(TokenRule {
+                ipa,
+                ..template.clone()
+            });
+        }
+        table
+    }
+
+    fn keys(table: &MappingTable, ipa: &str) -> Vec<String> {
+        Tokenizer::new(table)
+            .tokenize(ipa)
+            .into_iter()
+            .map(|t| t.key)
+            .collect()
+    }
+
+    #[test]
+    fn greedy_longest_match() {
+        let table = MappingTable::builtin("futhorc").unwrap();
+        assert_eq!(keys(&table, "naɪt"), ["n", "aɪ", "t"]);
+        assert_eq!(keys(&table, "tʃɜːtʃ"), ["tʃ", "ɜ", "ː", "tʃ"]);
+        assert_eq!(keys(&table, "ðə naɪt."), ["ð", "ə", " ", "n", "aɪ", "t", "."]);
+    }
+
+    #[test]
+    fn every_table_token_is_one_token() {
+        for alphabet in MappingTable::alphabets() {
+            let table = MappingTable::builtin(alphabet).unwrap();
+            for rule in table.rules() {
+                assert_eq!(keys(&table, &rule.ipa), [rule.ipa.as_str()], "{alphabet}");
+            }
+        }
+    }
+
+    #[test]
+    fn every_chart_symbol_is_one_token() {
+        let table = MappingTable::builtin("futhorc").unwrap();
+        for symbol in ipa::CHART {
+            let tokens = Tokenizer::new(&table).tokenize(symbol.ipa);
+            assert_eq!(tokens.len(), 1, "{} ({})", symbol.ipa, symbol.name);
+            assert_eq!(tokens[0].key, symbol.ipa);
+        }
+    }
+
+    #[test]
+    fn diacritics_attach_to_the_sound_before() {
+        let table = MappingTable::builtin("futhorc").unwrap();
+        for d in ipa::DIACRITICS {
+            let input = format!("a{}t", d.mark);
+            let tokens = Tokenizer::new(&table).tokenize(&input);
+            assert_eq!(tokens.len(), 2, "{:?} ({})", d.mark, d.name);
+            assert_eq!(tokens[0].key, "a");
+            assert_eq!(tokens[0].diacritics, [d.mark]);
+        }
+    }
+
+    #[test]
+    fn tie_bars_join_symbols() {
+        let table = MappingTable::builtin("futhorc").unwrap();
+
+        let tokens = Tokenizer::new(&table).tokenize("t\u{361}ʃa");
+        assert_eq!(tokens.len(), 2);
+        assert_eq!(tokens[0].key, "tʃ");
+        assert_eq!(tokens[0].text, "t\u{361}ʃ");
+        assert_eq!([tokens[0].start, tokens[0].end], [0, "t\u{361}ʃ".len()]);
+
+        // Below-the-line tie, and a pair the table has no rule for.
+        let tokens = Tokenizer::new(&table).tokenize("k\u{35C}p");
+        assert_eq!(tokens.len(), 1);
+        assert_eq!(tokens[0].parts, ["k", "p"]);
+    }
+
+    #[test]
+    fn unknown_characters_fall_back_to_one_token_each() {
+        let table = MappingTable::builtin("futhorc").unwrap();
+        let tokens = Tokenizer::new(&table).tokenize("☃☃");
+        assert_eq!(tokens.len(), 2);
+        assert!(tokens.iter().all(|t| t.key == "☃" && t.desc.is_none()));
+    }
+
+    #[test]
+    fn tokens_concatenate_back_to_the_input() {
+        let table = MappingTable::builtin("futhorc").unwrap();
+        let input = "ðə t\u{361}sʰaɪ, n\u{329} ☃.";
+        let tokens = Tokenizer::new(&table).tokenize(input);
+        let joined: String = tokens.iter().map(|t| t.text.as_str()).collect();
+        assert_eq!(joined, input);
+    }
+
+    #[test]
+    fn trie_agrees_with_the_linear_scan() {
+        let mut inputs = dictionary_ipa(250);
+        inputs.push("t\u{361}ʃ t\u{35C}\u{361}ʃ k\u{361}p ɡ\u{361}\u{361}".to_string());
+        inputs.extend(ipa::CHART.iter().map(|s| format!("{}\u{361}{}", s.ipa, s.ipa)));
+
+        for table in [MappingTable::builtin("futhorc").unwrap(), big_table(3000)] {
+            let tokenizer = Tokenizer::new(&table);
+            for input in &inputs {
+                for (at, _) in input.char_indices() {
+                    let rest = &input[at..];
+                    let trie = tokenizer
+                        .match_symbol(rest)
+                        .map(|(len, s)| (len, tokenizer.symbols[s].ipa.clone()));
+                    assert_eq!(trie, linear_match(&table, rest), "{rest:?}");
+                }
+            }
+        }
+    }
+}
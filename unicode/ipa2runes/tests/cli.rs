@@ -0,0 +1,64 @@
+// SPDX-License-Identifier: MIT
+// Copyright (c) 2026 Jamie Adams (a.k.a, Imodium Operator)
+
+//! Argument handling of the `ipa2runes` binary.
+
+use std::io::Read;
+use std::process::{Command, Output, Stdio};
+
+fn run(args: &[&str]) -> Output {
+    Command::new(env!("CARGO_BIN_EXE_ipa2runes"))
+        .args(args)
+        .output()
+        .unwrap()
+}
+
+#[test]
+fn help_prints_usage_and_succeeds() {
+    for args in [&["--help"][..], &["-h"], &["runes", "--help"]] {
+        let out = run(args);
+        assert_eq!(out.status.code(), Some(0), "{args:?}");
+        assert!(String::from_utf8_lossy(&out.stdout).starts_with("Usage:"), "{args:?}");
+        assert!(out.stderr.is_empty(), "{args:?}");
+    }
+}
+
+#[test]
+fn rejects_unknown_options() {
+    for args in [
+        &["--alphabet=elder", "/naɪt/"][..],
+        &["--alphabt", "elder", "/naɪt/"],
+        &["/naɪt/", "-x"],
+    ] {
+        let out = run(args);
+        assert_eq!(out.status.code(), Some(2), "{args:?}");
+        assert!(out.stdout.is_empty(), "{args:?}");
+        let stderr = String::from_utf8_lossy(&out.stderr);
+        assert!(stderr.starts_with("Error: unknown option"), "{args:?}: {stderr}");
+        assert!(stderr.contains("Usage:"), "{args:?}");
+    }
+}
+
+#[test]
+fn still_transliterates_positional_ipa() {
+    let out = run(&["--plain", "/naɪt/"]);
+    assert_eq!(out.status.code(), Some(0));
+    assert!(!String::from_utf8_lossy(&out.stdout).trim().is_empty());
+}
+
+#[test]
+fn closed_pipe_is_a_clean_exit() {
+    // `ipa2runes runes --json | head -c 100`: the listing is larger than
+    // a pipe buffer, so the reader goes away while it is being written.
+    let mut child = Command::new(env!("CARGO_BIN_EXE_ipa2runes"))
+        .args(["runes", "--json"])
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .unwrap();
+    let mut head = [0; 100];
+    child.stdout.take().unwrap().read_exact(&mut head).unwrap();
+    let out = child.wait_with_output().unwrap();
+    assert_eq!(out.status.code(), Some(0));
+    assert!(out.stderr.is_empty(), "{}", String::from_utf8_lossy(&out.stderr));
+}
@@ -1,62 +1,189 @@
-use slint::{ModelRc, VecModel, SharedString};
+use slint::{Model, ModelRc, ModelTracker, VecModel, SharedString};
 
 mod unicode_blocks;
 use unicode_blocks::*;
 
 slint::include_modules!();
 
-fn main() {
-    let ui = UnicodeGrid::new().unwrap();
+/// Rows of 16 shown at once. The grid makes an element for every cell it
+/// is given, so the big blocks (PUA-A/B: 4,096 rows) are paged.
+const PAGE_ROWS: u32 = 256;
+
+fn usage() -> ! {
+    eprintln!("Usage: unicode-grid [--block NAME|HEX]");
+    eprintln!("  --block NAME|HEX   start on the block with this name (\"runic\",");
+    eprintln!("                     \"latin-extended-e\") or holding this code point");
+    eprintln!("                     (\"16A0\", \"U+1F600\"); Runic by default");
+    eprintln!("Keys: PageDown / PageUp next / previous block, Ctrl+F search blocks,");
+    eprintln!("      Shift+PageDown / Shift+PageUp next / previous page of a big block");
+    std::process::exit(2);
+}
 
-    //let block = &LATIN_EXTENDED_E;
-    //let block = &BASIC_LATIN;
-    //let block = &BOX_DRAWING;
-    let block = &RUNIC;
-    //let block = &BRAILLE_PATTERNS;
-
-    // ---------------- glyphs ----------------
-    let mut glyphs: Vec<SharedString> = Vec::new();
-    let mut missing: Vec<bool> = Vec::new();
-
-    for cp in block.start..=block.end {
-        if let Some(ch) = char::from_u32(cp) {
-            glyphs.push(ch.to_string().into());
-            missing.push(false);
-        } else {
-            glyphs.push("�".into());
-            missing.push(true);
+fn main() {
+    // ---------------- arguments ----------------
+    let mut start = find_block(RUNIC.name).unwrap();
+
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--block" => {
+                let query = args.next().unwrap_or_else(|| usage());
+                start = find_block(&query).unwrap_or_else(|| {
+                    eprintln!("Error: no block named or containing {query:?}.");
+                    usage();
+                });
+            }
+            "-h" | "--help" => usage(),
+            _ => {
+                eprintln!("Error: unexpected argument {arg:?}.");
+                usage();
+            }
         }
     }
 
-    // ---------------- row labels ----------------
-    let total = (block.end - block.start + 1) as usize;
-    let rows = (total + 15) / 16;
+    let ui = UnicodeGrid::new().unwrap();
+    ui.set_font_name("Menlo".into());
 
-    let mut row_labels: Vec<SharedString> = Vec::with_capacity(rows);
+    show_block(&ui, start);
+    list_blocks(&ui, "");
+
+    // ---------------- navigation ----------------
+    let weak = ui.as_weak();
+    ui.on_select_block(move |index| {
+        show_block(&weak.unwrap(), index as usize);
+    });
+
+    let weak = ui.as_weak();
+    ui.on_next_block(move || {
+        let ui = weak.unwrap();
+        let next = (ui.get_current_block() as usize + 1) % ALL_BLOCKS.len();
+        show_block(&ui, next);
+    });
+
+    let weak = ui.as_weak();
+    ui.on_previous_block(move || {
+        let ui = weak.unwrap();
+        let count = ALL_BLOCKS.len();
+        let previous = (ui.get_current_block() as usize + count - 1) % count;
+        show_block(&ui, previous);
+    });
+
+    let weak = ui.as_weak();
+    ui.on_next_page(move || {
+        let ui = weak.unwrap();
+        show_page(&ui, ui.get_current_block() as usize, ui.get_page() as u32 + 1);
+    });
+
+    let weak = ui.as_weak();
+    ui.on_previous_page(move || {
+        let ui = weak.unwrap();
+        let page = (ui.get_page() as u32).saturating_sub(1);
+        show_page(&ui, ui.get_current_block() as usize, page);
+    });
+
+    let weak = ui.as_weak();
+    ui.on_search_changed(move |query| {
+        list_blocks(&weak.unwrap(), &query);
+    });
 
-    for r in 0..rows {
-        let base = block.start + (r as u32) * 16;
-        row_labels.push(format!("0x{:04X}", base).into());
-    }
+    ui.run().unwrap();
+}
+
+/// Show the first page of `ALL_BLOCKS[index]`.
+fn show_block(ui: &UnicodeGrid, index: usize) {
+    show_page(ui, index, 0);
+}
+
+/// Show page `page` of `ALL_BLOCKS[index]` (the last page if it is past
+/// the end): the glyph, missing and row label models and the header.
+fn show_page(ui: &UnicodeGrid, index: usize, page: u32) {
+    let block = &ALL_BLOCKS[index];
+
+    let rows = (block.end - block.start + 1 + 15) / 16;
+    let pages = (rows + PAGE_ROWS - 1) / PAGE_ROWS;
+    let page = page.min(pages - 1);
+
+    let first = block.start + page * PAGE_ROWS * 16;
+    let last = (first + PAGE_ROWS * 16 - 1).min(block.end);
+    let count = (last - first + 1) as usize;
 
     // ---------------- header ----------------
     ui.set_block_name(block.name.into());
+    ui.set_block_range(block_range(block).into());
+    ui.set_current_block(index as i32);
+    ui.set_page(page as i32);
+    ui.set_page_count(pages as i32);
 
-    let range = format!(
-        "0x{:04X} – 0x{:04X}",
-        block.start,
-        block.end
-    );
+    // ---------------- models ----------------
+    ui.set_glyphs(ModelRc::new(Cells {
+        first,
+        step: 1,
+        count,
+        cell: |cp| match char::from_u32(cp) {
+            Some(ch) => SharedString::from(ch.to_string()),
+            None => SharedString::from("�"),
+        },
+    }));
+    ui.set_missing(ModelRc::new(Cells {
+        first,
+        step: 1,
+        count,
+        cell: |cp| char::from_u32(cp).is_none(),
+    }));
+    ui.set_row_labels(ModelRc::new(Cells {
+        first,
+        step: 16,
+        count: (count + 15) / 16,
+        cell: |base| SharedString::from(format!("0x{:04X}", base)),
+    }));
+}
 
-    ui.set_block_range(range.into());
-    ui.set_font_name("Menlo".into());
+/// A model computed by index: row `i` is `cell(first + i * step)`.
+/// Nothing is stored, so a page costs the same in any block.
+struct Cells<T> {
+    first: u32,
+    step: u32,
+    count: usize,
+    cell: fn(u32) -> T,
+}
 
+impl<T> Model for Cells<T> {
+    type Data = T;
 
-    // ---------------- models ----------------
-    ui.set_glyphs(ModelRc::new(VecModel::from(glyphs)));
-    ui.set_missing(ModelRc::new(VecModel::from(missing)));
-    ui.set_row_labels(ModelRc::new(VecModel::from(row_labels)));
+    fn row_count(&self) -> usize {
+        self.count
+    }
 
-    ui.run().unwrap();
+    fn row_data(&self, row: usize) -> Option<T> {
+        (row < self.count).then(|| (self.cell)(self.first + row as u32 * self.step))
+    }
+
+    fn model_tracker(&self) -> &dyn ModelTracker {
+        // The cells never change; another page is another model.
+        &()
+    }
+}
+
+/// Fill the block picker with the blocks matching `query`.
+fn list_blocks(ui: &UnicodeGrid, query: &str) {
+    let items: Vec<BlockItem> = ALL_BLOCKS
+        .iter()
+        .enumerate()
+        .filter(|(_, block)| block_matches(block, query))
+        .map(|(i, block)| BlockItem {
+            index: i as i32,
+            name: block.name.into(),
+            range: block_range(block).into(),
+        })
+        .collect();
+
+    ui.set_block_list(ModelRc::new(VecModel::from(items)));
 }
 
+fn block_range(block: &UnicodeBlock) -> String {
+    format!(
+        "0x{:04X} – 0x{:04X}",
+        block.start,
+        block.end
+    )
+}
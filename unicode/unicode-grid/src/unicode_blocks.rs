@@ -2004,9 +2004,8 @@ pub const SUPPLEMENTARY_PRIVATE_USE_AREA_B: UnicodeBlock = UnicodeBlock {
     end: 0x10FFFF,
 };
 
-
-
-#[allow(unused)]
+/// Every block above, in code point order: the block picker lists them
+/// and next/previous navigation steps through them.
 pub const ALL_BLOCKS: &[UnicodeBlock] = &[
     BASIC_LATIN,
     LATIN_1_SUPPLEMENT,
@@ -2096,7 +2095,241 @@ pub const ALL_BLOCKS: &[UnicodeBlock] = &[
     GEOMETRIC_SHAPES,
     MISCELLANEOUS_SYMBOLS,
     DINGBATS,
-    // (continues — you can extend if desired)
+    MISCELLANEOUS_MATHEMATICAL_SYMBOLS_A,
+    SUPPLEMENTAL_ARROWS_A,
+    BRAILLE_PATTERNS,
+    SUPPLEMENTAL_ARROWS_B,
+    MISCELLANEOUS_MATHEMATICAL_SYMBOLS_B,
+    SUPPLEMENTAL_MATHEMATICAL_OPERATORS,
+    MISCELLANEOUS_SYMBOLS_AND_ARROWS,
+    GLAGOLITIC,
+    LATIN_EXTENDED_C,
+    COPTIC,
+    GEORGIAN_SUPPLEMENT,
+    TIFINAGH,
+    ETHIOPIC_EXTENDED,
+    CYRILLIC_EXTENDED_A,
+    SUPPLEMENTAL_PUNCTUATION,
+    CJK_RADICALS_SUPPLEMENT,
+    KANGXI_RADICALS,
+    IDEOGRAPHIC_DESCRIPTION_CHARACTERS,
+    CJK_SYMBOLS_AND_PUNCTUATION,
+    HIRAGANA,
+    KATAKANA,
+    BOPOMOFO,
+    HANGUL_COMPATIBILITY_JAMO,
+    KANBUN,
+    BOPOMOFO_EXTENDED,
+    CJK_STROKES,
+    KATAKANA_PHONETIC_EXTENSIONS,
+    ENCLOSED_CJK_LETTERS_AND_MONTHS,
+    CJK_COMPATIBILITY,
+    CJK_UNIFIED_IDEOGRAPHS_EXTENSION_A,
+    YIJING_HEXAGRAM_SYMBOLS,
+    CJK_UNIFIED_IDEOGRAPHS,
+    YI_SYLLABLES,
+    YI_RADICALS,
+    LISU,
+    VAI,
+    CYRILLIC_EXTENDED_B,
+    BAMUM,
+    MODIFIER_TONE_LETTERS,
+    LATIN_EXTENDED_D,
+    SYLOTI_NAGRI,
+    COMMON_INDIC_NUMBER_FORMS,
+    PHAGS_PA,
+    SAURASHTRA,
+    DEVANAGARI_EXTENDED,
+    KAYAH_LI,
+    REJANG,
+    HANGUL_JAMO_EXTENDED_A,
+    JAVANESE,
+    MYANMAR_EXTENDED_B,
+    CHAM,
+    MYANMAR_EXTENDED_A,
+    TAI_VIET,
+    MEETEI_MAYEK_EXTENSIONS,
+    ETHIOPIC_EXTENDED_A,
+    LATIN_EXTENDED_E,
+    CHEROKEE_SUPPLEMENT,
+    MEETEI_MAYEK,
+    HANGUL_SYLLABLES,
+    HANGUL_JAMO_EXTENDED_B,
+    HIGH_SURROGATES,
+    HIGH_PRIVATE_USE_SURROGATES,
+    LOW_SURROGATES,
+    PRIVATE_USE_AREA,
+    CJK_COMPATIBILITY_IDEOGRAPHS,
+    ALPHABETIC_PRESENTATION_FORMS,
+    ARABIC_PRESENTATION_FORMS_A,
+    VARIATION_SELECTORS,
+    VERTICAL_FORMS,
+    COMBINING_HALF_MARKS,
+    CJK_COMPATIBILITY_FORMS,
+    SMALL_FORM_VARIANTS,
+    ARABIC_PRESENTATION_FORMS_B,
+    HALFWIDTH_AND_FULLWIDTH_FORMS,
+    SPECIALS,
+    LINEAR_B_SYLLABARY,
+    LINEAR_B_IDEOGRAMS,
+    AEGEAN_NUMBERS,
+    ANCIENT_GREEK_NUMBERS,
+    ANCIENT_SYMBOLS,
+    PHAISTOS_DISC,
+    LYCIAN,
+    CARIAN,
+    COPTIC_EPACT_NUMBERS,
+    OLD_ITALIC,
+    GOTHIC,
+    OLD_PERMIC,
+    UGARITIC,
+    OLD_PERSIAN,
+    DESERET,
+    SHAVIAN,
+    OSMANYA,
+    OSAGE,
+    ELBASAN,
+    CAUCASIAN_ALBANIAN,
+    LINEAR_A,
+    CYPRIOT_SYLLABARY,
+    IMPERIAL_ARAMAIC,
+    PALMYRENE,
+    NABATAEAN,
+    HATRAN,
+    PHOENICIAN,
+    LYDIAN,
+    MEROITIC_HIEROGLYPHS,
+    MEROITIC_CURSIVE,
+    KHAROSHTHI,
+    OLD_SOUTH_ARABIAN,
+    OLD_NORTH_ARABIAN,
+    MANICHAEAN,
+    AVESTAN,
+    INSCRIPTIONAL_PARTHIAN,
+    INSCRIPTIONAL_PAHLAVI,
+    PSALTER_PAHLAVI,
+    OLD_TURKIC,
+    OLD_HUNGARIAN,
+    HANIFI_ROHINGYA,
+    RUMI_NUMERAL_SYMBOLS,
+    YEZIDI,
+    OLD_SOGDIAN,
+    SOGDIAN,
+    CHORASMIAN,
+    ELYMAIC,
+    BRAHMI,
+    KAITHI,
+    SORA_SOMPENG,
+    CHAKMA,
+    MAHAJANI,
+    SHARADA,
+    SINHALA_ARCHAIC_NUMBERS,
+    KHOJKI,
+    MULTANI,
+    KHUDAWADI,
+    GRANTHA,
+    NEWA,
+    TIRHUTA,
+    SIDDHAM,
+    MODI,
+    MONGOLIAN_SUPPLEMENT,
+    TAKRI,
+    AHOM,
+    DOGRA,
+    WARANG_CITI,
+    DIVES_AKURU,
+    NANDINAGARI,
+    ZANABAZAR_SQUARE,
+    SOYOMBO,
+    PAU_CIN_HAU,
+    BHAIKSUKI,
+    MARCHEN,
+    MASARAM_GONDI,
+    GUNJALA_GONDI,
+    MAKASAR,
+    LISU_SUPPLEMENT,
+    TAMIL_SUPPLEMENT,
+    CUNEIFORM,
+    CUNEIFORM_NUMBERS_AND_PUNCTUATION,
+    EARLY_DYNASTIC_CUNEIFORM,
+    EGYPTIAN_HIEROGLYPHS,
+    EGYPTIAN_HIEROGLYPH_FORMAT_CONTROLS,
+    ANATOLIAN_HIEROGLYPHS,
+    BAMUM_SUPPLEMENT,
+    MRO,
+    BASSA_VAH,
+    PAHAWH_HMONG,
+    MEDEFAIDRIN,
+    MIAO,
+    IDEOGRAPHIC_SYMBOLS_AND_PUNCTUATION,
+    TANGUT,
+    TANGUT_COMPONENTS,
+    KHITAN_SMALL_SCRIPT,
+    TANGUT_SUPPLEMENT,
+    KANA_SUPPLEMENT,
+    KANA_EXTENDED_A,
+    SMALL_KANA_EXTENSION,
+    NUSHU,
+    DUPLOYAN,
+    SHORTHAND_FORMAT_CONTROLS,
+    BYZANTINE_MUSICAL_SYMBOLS,
+    MUSICAL_SYMBOLS,
+    ANCIENT_GREEK_MUSICAL_NOTATION,
+    MAYAN_NUMERALS,
+    TAI_XUAN_JING_SYMBOLS,
+    COUNTING_ROD_NUMERALS,
+    MATHEMATICAL_ALPHANUMERIC_SYMBOLS,
+    SUTTON_SIGNWRITING,
+    EMOTICONS,
+    TRANSPORT_AND_MAP_SYMBOLS,
+    SYMBOLS_FOR_LEGACY_COMPUTING,
+    CJK_UNIFIED_IDEOGRAPHS_EXTENSION_B,
+    CJK_UNIFIED_IDEOGRAPHS_EXTENSION_C,
+    CJK_UNIFIED_IDEOGRAPHS_EXTENSION_D,
+    CJK_UNIFIED_IDEOGRAPHS_EXTENSION_E,
+    CJK_UNIFIED_IDEOGRAPHS_EXTENSION_F,
+    TAGS,
+    VARIATION_SELECTORS_SUPPLEMENT,
+    SUPPLEMENTARY_PRIVATE_USE_AREA_A,
+    SUPPLEMENTARY_PRIVATE_USE_AREA_B,
 ];
 
+/// Index in `ALL_BLOCKS` of the block `query` names, as given to `--block`:
+/// its name, ignoring case, spaces, hyphens and underscores ("runic",
+/// "LATIN_EXTENDED_A"), or any code point in it, in hex ("16A0", "0x16A0",
+/// "U+16A0").
+pub fn find_block(query: &str) -> Option<usize> {
+    let key = name_key(query);
+    if let Some(i) = ALL_BLOCKS.iter().position(|b| name_key(b.name) == key) {
+        return Some(i);
+    }
+    let cp = parse_code_point(query)?;
+    ALL_BLOCKS.iter().position(|b| (b.start..=b.end).contains(&cp))
+}
 
+/// Whether `block` belongs in the picker's list when searching for `query`:
+/// part of its name, or a code point in it. An empty query matches all.
+pub fn block_matches(block: &UnicodeBlock, query: &str) -> bool {
+    let key = name_key(query);
+    name_key(block.name).contains(&key)
+        || parse_code_point(query).is_some_and(|cp| (block.start..=block.end).contains(&cp))
+}
+
+fn name_key(name: &str) -> String {
+    name.chars()
+        .filter(|c| !matches!(c, ' ' | '-' | '_'))
+        .flat_map(char::to_lowercase)
+        .collect()
+}
+
+fn parse_code_point(s: &str) -> Option<u32> {
+    let s = s.trim();
+    let hex = ["0x", "0X", "U+", "u+"]
+        .iter()
+        .find_map(|p| s.strip_prefix(p))
+        .unwrap_or(s);
+    if hex.is_empty() || hex.len() > 6 {
+        return None;
+    }
+    u32::from_str_radix(hex, 16).ok().filter(|&cp| cp <= 0x10FFFF)
+}